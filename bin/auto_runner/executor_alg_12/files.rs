@@ -2,7 +2,6 @@
 use super::configs::Config;
 use crate::error::Error;
 use alg_12::encoding::{Chromosome, Context, Cost};
-use ga::encoding::ObjectiveValue;
 use ga::{
     dynamics::Dynamic,
     operators::{Crossover, Mutation},
@@ -97,17 +96,14 @@ impl FilesHelper {
 
     pub fn write_general_csv(
         &self,
-        report: Report<Cost, Context, Chromosome>,
+        report: &Report<Cost, Context, Chromosome>,
     ) -> Result<(), Error> {
-        // Construct path to params.csv
+        // Construct path to general.csv
         let mut general_csv_path = self.run_path.clone();
         general_csv_path.push("general.csv");
 
-        // Create file content
-        let content = Into::<GeneralCsv>::into(report).to_csv()?;
-
-        // Write content to file
-        fs::write(general_csv_path, content)
+        // Write summary of the report to file
+        fs::write(general_csv_path, report.summary_csv())
             .map_err(|e| Error::GeneralCsv(format!("Writing file: {e}")))?;
 
         Ok(())
@@ -115,17 +111,14 @@ impl FilesHelper {
 
     pub fn write_generations_csv(
         &self,
-        report: Report<Cost, Context, Chromosome>,
+        report: &Report<Cost, Context, Chromosome>,
     ) -> Result<(), Error> {
-        // Construct path to params.csv
+        // Construct path to generations.csv
         let mut generations_csv_path = self.run_path.clone();
         generations_csv_path.push("generations.csv");
 
-        // Create file content
-        let content = Into::<GenerationsCsv>::into(report).to_csv()?;
-
-        // Write content to file
-        fs::write(generations_csv_path, content)
+        // Write log of the report to file
+        fs::write(generations_csv_path, report.log_csv())
             .map_err(|e| Error::GenerationsCsv(format!("Writing file: {e}")))?;
 
         Ok(())
@@ -145,7 +138,7 @@ impl FilesHelper {
             &format!("run_{}", time),
             "biwecka",
             "GAX (GA for XHSTT)",
-            Some(report.runtime),
+            Some((report.runtime_ms / 1000) as usize),
             solution_events,
         );
 
@@ -219,7 +212,9 @@ impl From<Config> for ParamsCsv {
         let crossover = c.params.crossover.identifier();
         let mutation = c.params.mutation.identifier();
         let replacement =
-            <Replace as Replacement<usize>>::identifier(&c.params.replacement);
+            <Replace as Replacement<(Chromosome, Cost)>>::identifier(
+                &c.params.replacement,
+            );
 
         let dynamics = c
             .dynamics
@@ -242,113 +237,4 @@ impl From<Config> for ParamsCsv {
     }
 }
 
-// General CSV /////////////////////////////////////////////////////////////////
-#[derive(Clone, serde::Serialize)]
-struct GeneralCsv {
-    success: bool,
-    ov: usize,
-    gen: usize,
-    runtime: usize,
-}
-
-impl GeneralCsv {
-    pub fn to_csv(&self) -> Result<String, Error> {
-        let mut writer =
-            csv::WriterBuilder::new().delimiter(b';').from_writer(vec![]);
-
-        writer
-            .serialize(self.clone())
-            .map_err(|e| Error::GeneralCsv(format!("Serializing data: {e}")))?;
-
-        writer
-            .flush()
-            .map_err(|e| Error::GeneralCsv(format!("Flushing writer: {e}")))?;
-
-        // Result
-        let inner = writer
-            .into_inner()
-            .map_err(|e| Error::GeneralCsv(format!("Extract writer: {e}")))?;
-
-        String::from_utf8(inner)
-            .map_err(|e| Error::GeneralCsv(format!("CSV to string: {e}")))
-    }
-}
-
-impl From<Report<Cost, Context, Chromosome>> for GeneralCsv {
-    fn from(r: Report<Cost, Context, Chromosome>) -> Self {
-        let ov = r.population.first().unwrap().1.to_usize();
-        let success = ov == 0;
-        let gen = r.generation;
-        let runtime = r.runtime;
-
-        Self { success, ov, gen, runtime }
-    }
-}
-
-// Generations CSV /////////////////////////////////////////////////////////////
-#[derive(Clone)]
-struct GenerationsCsv {
-    list: Vec<GenCsv>,
-}
-
-#[derive(Clone, serde::Serialize)]
-struct GenCsv {
-    pub gen: usize,
-    pub best: usize,
-    pub worst: usize,
-
-    pub mean: f64,
-    pub median: f64,
-    pub variance: f64,
-    pub std_dev: f64,
-
-    pub diversity: f64,
-}
-
-impl GenerationsCsv {
-    pub fn to_csv(&self) -> Result<String, Error> {
-        let mut writer =
-            csv::WriterBuilder::new().delimiter(b';').from_writer(vec![]);
-
-        for gen in &self.list {
-            writer.serialize(gen.clone()).map_err(|e| {
-                Error::GenerationsCsv(format!("Serializing data: {e}"))
-            })?;
-        }
-
-        writer.flush().map_err(|e| {
-            Error::GenerationsCsv(format!("Flushing writer: {e}"))
-        })?;
-
-        // Result
-        let inner = writer.into_inner().map_err(|e| {
-            Error::GenerationsCsv(format!("Extract writer: {e}"))
-        })?;
-
-        String::from_utf8(inner)
-            .map_err(|e| Error::GenerationsCsv(format!("CSV to string: {e}")))
-    }
-}
-
-impl From<Report<Cost, Context, Chromosome>> for GenerationsCsv {
-    fn from(r: Report<Cost, Context, Chromosome>) -> Self {
-        let list = r
-            .log
-            .into_iter()
-            .map(|log| GenCsv {
-                gen: log.generation,
-                best: log.best,
-                worst: log.worst,
-                mean: log.mean,
-                median: log.median,
-                variance: log.variance,
-                std_dev: log.std_dev,
-                diversity: log.diversity,
-            })
-            .collect::<Vec<_>>();
-
-        Self { list }
-    }
-}
-
 ////////////////////////////////////////////////////////////////////////////////
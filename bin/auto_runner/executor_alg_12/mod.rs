@@ -71,10 +71,10 @@ impl ExecutorAlg12 {
         fh.write_params_csv(cfg.clone())?;
 
         // 3) Write run_x/general.csv
-        fh.write_general_csv(report.clone())?;
+        fh.write_general_csv(&report)?;
 
         // 4) Write run_x/generations.csv
-        fh.write_generations_csv(report.clone())?;
+        fh.write_generations_csv(&report)?;
 
         // 5) Write solution.xml
         fh.write_solution_xml(i.0, i.1, time, events, report)?;
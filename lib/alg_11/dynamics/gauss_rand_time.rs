@@ -31,7 +31,9 @@ pub fn exec(
     #[cfg(feature = "ga_log_dynamics")] rerun_logger: &RerunLogger,
 
     target_success_rate: f32,
-) {
+) -> bool {
+    let sd = context.gauss_rand_time_sd;
+
     if rtd.success_rate_pt1 < target_success_rate {
         // Calculate difference
         let diff = target_success_rate - rtd.success_rate_pt1;
@@ -64,6 +66,9 @@ pub fn exec(
             context.gauss_rand_time_sd,
         );
     };
+
+    // Modified, if the std_dev changed
+    sd != context.gauss_rand_time_sd
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,17 +1,17 @@
 // Modules /////////////////////////////////////////////////////////////////////
 mod gauss_rand_event;
 mod gauss_rand_time;
-mod inc_lin_rnk_sel_pressure;
-mod mut_rate_cos;
 mod state_machine;
 mod var_mut_rate_target_mean_sin;
 
 // Imports /////////////////////////////////////////////////////////////////////
 use ga::{
+    dynamics::Standard,
     process::{
         rejection::Reject, replacement::Replace, selection::Select,
         termination::Terminate,
     },
+    rng::GaRng,
     runtime_data::RuntimeData,
 };
 
@@ -77,6 +77,40 @@ pub enum Dynamic {
 
     /// State Machine
     StateMachine,
+
+    /// Generic dynamic of the `ga` crate.
+    Standard(Standard),
+}
+
+impl Dynamic {
+    /// Returns the generic equivalent of this dynamic (see
+    /// [`ga::dynamics::Standard`]), if there is one.
+    fn standard(&self) -> Option<Standard> {
+        match self {
+            Self::MutRateCos(base, amplitude, wavelength, bounds) => {
+                Some(Standard::MutRateCos {
+                    base: *base,
+                    amplitude: *amplitude,
+                    wavelength: *wavelength,
+                    bounds: *bounds,
+                })
+            }
+            Self::IncLinearRankSelectionPressure(
+                interval,
+                step,
+                max,
+                reset,
+            ) => Some(Standard::PressureOnStagnation {
+                initial: 1.,
+                interval: *interval,
+                step: *step as f64,
+                max: *max as f64,
+                reset: *reset,
+            }),
+            Self::Standard(standard) => Some(standard.clone()),
+            _ => None,
+        }
+    }
 }
 
 #[rustfmt::skip]
@@ -99,6 +133,8 @@ impl ga::dynamics::Dynamic<Cost,Context,Chromosome,Crossover,Mutation,usize,Sele
                 format!("inc-lin-rnk-sel-pressure-{a}-{b}-{c}-{d}"),
 
             Self::StateMachine => "state-machine".into(),
+
+            Self::Standard(standard) => standard.identifier(),
         }
     }
 
@@ -107,11 +143,18 @@ impl ga::dynamics::Dynamic<Cost,Context,Chromosome,Crossover,Mutation,usize,Sele
         rtd: &mut RuntimeData<Cost,Context,Chromosome,Crossover,Mutation,usize,Select,Reject,Replace,Terminate<Cost>>,
         parameters: &mut ga::parameters::Parameters<Cost,Context,Chromosome,Crossover,Mutation,usize,Select,Reject,Replace,Terminate<Cost>>,
         context: &mut Context,
+        rng: &mut GaRng,
     ) {
-        match self {
-            Self::MutRateCos(dmr, _, _, _) =>
-                mut_rate_cos::setup(rtd, parameters, context, *dmr),
+        // The selection pressure is increased for the linear rank selection
+        if let Self::IncLinearRankSelectionPressure(..) = self {
+            parameters.selection = Select::LinearRank(1.0);
+        }
 
+        if let Some(standard) = self.standard() {
+            return standard.setup(rtd, parameters, context, rng);
+        }
+
+        match self {
             Self::GaussRandTime(_) =>
                 gauss_rand_time::setup(rtd, parameters, context),
 
@@ -123,11 +166,13 @@ impl ga::dynamics::Dynamic<Cost,Context,Chromosome,Crossover,Mutation,usize,Sele
                     rtd, parameters, context, *g
                 ),
 
-            Self::IncLinearRankSelectionPressure(_, _, _, _) =>
-                inc_lin_rnk_sel_pressure::setup(rtd, parameters, context),
-
             Self::StateMachine =>
                 state_machine::setup(rtd, parameters, context),
+
+            // Generic dynamics (see above)
+            Self::MutRateCos(..)
+            | Self::IncLinearRankSelectionPressure(..)
+            | Self::Standard(_) => {}
         }
     }
 
@@ -136,15 +181,17 @@ impl ga::dynamics::Dynamic<Cost,Context,Chromosome,Crossover,Mutation,usize,Sele
         rtd: &RuntimeData<Cost,Context,Chromosome,Crossover,Mutation,usize,Select,Reject,Replace,Terminate<Cost>>,
         parameters: &mut ga::parameters::Parameters<Cost,Context,Chromosome,Crossover,Mutation,usize,Select,Reject,Replace,Terminate<Cost>>,
         context: &mut Context,
+        rng: &mut GaRng,
         #[cfg(feature = "ga_log_dynamics")] rerun_logger: &RerunLogger,
-    ) {
-        match self {
-            Self::MutRateCos(dmr, a, w, mm) => mut_rate_cos::exec(
-                rtd, parameters, context,
+    ) -> bool {
+        if let Some(standard) = self.standard() {
+            return standard.exec(
+                rtd, parameters, context, rng,
                 #[cfg(feature = "ga_log_dynamics")] rerun_logger,
-                *dmr, *a, *w, *mm
-            ),
+            );
+        }
 
+        match self {
             Self::GaussRandTime(tsr) => gauss_rand_time::exec(
                 rtd, parameters, context,
                 #[cfg(feature = "ga_log_dynamics")] rerun_logger,
@@ -164,19 +211,15 @@ impl ga::dynamics::Dynamic<Cost,Context,Chromosome,Crossover,Mutation,usize,Sele
                     *avg_dv, *a, *w
                 ),
 
-            Self::IncLinearRankSelectionPressure(
-                    step_gen, step_val, max_sp, reset
-            ) =>
-                inc_lin_rnk_sel_pressure::exec(
-                    rtd, parameters, context,
-                    #[cfg(feature = "ga_log_dynamics")] rerun_logger,
-                    *step_gen, *step_val, *max_sp, *reset
-                ),
-
             Self::StateMachine => state_machine::exec(
                 rtd, parameters, context,
                 #[cfg(feature = "ga_log_dynamics")] rerun_logger,
             ),
+
+            // Generic dynamics (see above)
+            Self::MutRateCos(..)
+            | Self::IncLinearRankSelectionPressure(..)
+            | Self::Standard(_) => false,
         }
     }
 }
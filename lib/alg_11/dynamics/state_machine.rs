@@ -38,19 +38,25 @@ pub fn exec(
     parameters: &mut ga::parameters::Parameters<Cost,Context,Chromosome,Crossover,Mutation,usize,Select,Reject,Replace,Terminate<Cost>>,
     context: &mut Context,
     #[cfg(feature = "ga_log_dynamics")] rerun_logger: &RerunLogger,
-) {
+) -> bool {
     context.state_machine.last_state_change += 1;
+    let mut modified = false;
 
     // State changes
     match context.state_machine.state {
         State::Broad => {
             if context.state_machine.last_state_change > 50 {
+                modified |= !matches!(
+                    parameters.replacement,
+                    Replace::EliteAbsolute(1)
+                );
                 parameters.replacement = Replace::EliteAbsolute(1);
             }
 
             // After 500 generations, switch to focus state
             if context.state_machine.last_state_change > 200 {
                 context.state_machine.last_state_change = 0;
+                modified = true;
 
                 context.state_machine.state = State::Focus;
 
@@ -73,6 +79,7 @@ pub fn exec(
             // After 5_000 generations, switch to focus state
             if context.state_machine.last_state_change > 1_800 {
                 context.state_machine.last_state_change = 0;
+                modified = true;
 
                 context.state_machine.state = State::Finish;
 
@@ -95,6 +102,7 @@ pub fn exec(
             // After 3_000 generations, switch to focus state
             if context.state_machine.last_state_change > 3_000 {
                 context.state_machine.last_state_change = 0;
+                modified = true;
 
                 context.state_machine.state = State::Broad;
 
@@ -124,6 +132,8 @@ pub fn exec(
             }
         }
     }
+
+    modified
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,8 +1,10 @@
 // Imports /////////////////////////////////////////////////////////////////////
+use bits::Bits32;
 use itertools::Itertools;
 use xhstt::db::constraints::{
     assign_time_constraint::AssignTimeConstraint,
-    avoid_clashes_constraint::AvoidClashesConstraint, Constraint,
+    avoid_clashes_constraint::AvoidClashesConstraint, utils::CostFunction,
+    Constraint,
 };
 
 use super::{Context, Phenotype};
@@ -83,4 +85,45 @@ pub fn avoid_clashes(
     (params.weight as usize) * params.cost_function.calc(deviation)
 }
 
+/// Change of the `avoid_clashes` cost between the time allocations of a parent
+/// and a child, which differ only in the given (sorted, unique) events. Only
+/// pairs of events with at least one changed event are recalculated. Returns
+/// `None` for non-linear cost functions.
+pub fn avoid_clashes_delta(
+    phenotype: &Phenotype, // blueprint (resources)
+    parent: &[Bits32],
+    child: &[Bits32],
+    params: &AvoidClashesConstraint,
+    resource_idxs: &[usize], // E_spec
+    changed: &[usize],
+) -> Option<isize> {
+    if !matches!(params.cost_function, CostFunction::Linear) {
+        return None;
+    }
+
+    let clashes = |times: &[Bits32], e0: usize, e1: usize| {
+        (times[e0] & times[e1]).ones().count() as isize
+    };
+
+    let mut delta: isize = 0;
+
+    for res_idx in resource_idxs {
+        let events = &phenotype.resources[*res_idx];
+
+        for e0 in changed.iter().filter(|e| events.get(**e as u64)) {
+            for e1 in events.ones().map(|e| e as usize) {
+                // Pairs of two changed events are counted once
+                if e1 == *e0 || (e1 < *e0 && changed.binary_search(&e1).is_ok())
+                {
+                    continue;
+                }
+
+                delta += clashes(child, *e0, e1) - clashes(parent, *e0, e1);
+            }
+        }
+    }
+
+    Some(params.weight as isize * delta)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,29 +1,44 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use bits::Bits32;
+use ga::{rng::GaRng, self_adaptation::Strategy};
 use hashbrown::HashMap;
-use rand_distr::Distribution;
+use rand_distr::{Distribution, Normal};
 use std::ops::AddAssign;
 
 use super::Context;
 
 // Genotype ////////////////////////////////////////////////////////////////////
+/// With self-adaptation (see [`ga::self_adaptation`]), the chromosome also
+/// carries its strategy parameters, which are attached by the algorithm.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Chromosome(pub Vec<Bits32>);
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chromosome(pub Vec<Bits32>, Option<Strategy>);
 
 impl From<Vec<Bits32>> for Chromosome {
     fn from(value: Vec<Bits32>) -> Self {
-        Self(value)
+        Self(value, None)
+    }
+}
+
+impl Chromosome {
+    /// Normal distribution of the Gauss mutations: the self-adapted standard
+    /// deviation (at least 1, as the sampled offsets are rounded), if the
+    /// chromosome carries strategy parameters, or the one of the context.
+    pub fn gauss(&self, context: &Normal<f32>) -> Normal<f32> {
+        self.1
+            .and_then(|s| Normal::new(0., (s.sigma as f32).max(1.)).ok())
+            .unwrap_or(*context)
     }
 }
 
 impl ga::encoding::Genotype<Context> for Chromosome {
-    fn generate(amount: usize, ctx: &Context) -> Vec<Self> {
+    fn generate(amount: usize, ctx: &Context, rng: &mut GaRng) -> Vec<Self> {
         let mut chromosomes: Vec<Self> = Vec::with_capacity(amount);
 
         for _ in 0..amount {
             let mut genes: Vec<Bits32> = Vec::with_capacity(ctx.num_events);
             for event_idx in 0..ctx.num_events {
-                genes.push(Bits32::generate(ctx.durations[event_idx], ctx))
+                genes.push(Bits32::generate(ctx.durations[event_idx], ctx, rng))
             }
 
             chromosomes.push(genes.into());
@@ -47,24 +62,41 @@ impl ga::encoding::Genotype<Context> for Chromosome {
         // Return
         arr.into_iter().map(|(_, x)| x).collect()
     }
+
+    fn distance(&self, other: &Self) -> Option<f64> {
+        // Hamming distance of the time slot bits of all events
+        let distance: usize = (self.0.iter())
+            .zip(other.0.iter())
+            .map(|(a, b)| (*a ^ *b).ones().count())
+            .sum();
+
+        Some(distance as f64)
+    }
+
+    fn strategy(&self) -> Option<&Strategy> {
+        self.1.as_ref()
+    }
+
+    fn strategy_mut(&mut self) -> Option<&mut Strategy> {
+        // The strategy parameters are attached on demand (only the
+        // self-adaptation requests them)
+        Some(self.1.get_or_insert(Strategy { rate: 0., sigma: 1. }))
+    }
 }
 
 trait Gene {
-    fn generate(duration: u8, ctx: &Context) -> Self;
+    fn generate(duration: u8, ctx: &Context, rng: &mut GaRng) -> Self;
 }
 
 impl Gene for Bits32 {
-    fn generate(duration: u8, ctx: &Context) -> Self {
-        // Get source of randomness
-        let mut rng = rand::thread_rng();
-
+    fn generate(duration: u8, ctx: &Context, rng: &mut GaRng) -> Self {
         // Generate `duration` amount of random indices in (0..num_times).
         let mut indices: Vec<u32> = Vec::with_capacity(duration as usize);
 
         // Ensure that the indices are UNIQUE. This is very important and makes
         // the encoding always fulfill the "AssignTimeConstraint" !!!
         while indices.len() < duration as usize {
-            let index = ctx.rand_time.sample(&mut rng);
+            let index = ctx.rand_time.sample(rng);
 
             if !indices.contains(&index) {
                 indices.push(index);
@@ -2,6 +2,7 @@
 
 /// Cost only represents hard constraint cost.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cost(usize);
 
 impl ga::encoding::ObjectiveValue for Cost {
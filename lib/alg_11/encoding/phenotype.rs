@@ -134,6 +134,58 @@ impl ga::encoding::Phenotype<Cost, Context, Chromosome> for Phenotype {
         // Return
         total_cost.into()
     }
+
+    fn evaluate_batch(
+        &self,
+        chromosomes: &[Chromosome],
+        ctx: &Context,
+    ) -> Vec<Cost> {
+        // Clone the blueprint once and overwrite the time allocations
+        let mut phenotype = self.clone();
+
+        chromosomes
+            .iter()
+            .map(|chromosome| {
+                phenotype.times.clone_from(&chromosome.0);
+                phenotype.evaluate(ctx)
+            })
+            .collect()
+    }
+
+    fn evaluate_delta(
+        &self,
+        parent: &Chromosome,
+        parent_ov: &Cost,
+        chromosome: &Chromosome,
+        changed: &[usize],
+        ctx: &Context,
+    ) -> Option<Cost> {
+        let mut changed = changed.to_vec();
+        changed.sort_unstable();
+        changed.dedup();
+
+        let mut delta = 0;
+
+        for (constraint, indices) in &ctx.constraints {
+            #[allow(clippy::single_match)]
+            match constraint {
+                Constraint::AvoidClashesConstraint(params) => {
+                    delta += constraints::avoid_clashes_delta(
+                        self,
+                        &parent.0,
+                        &chromosome.0,
+                        params,
+                        indices,
+                        &changed,
+                    )?;
+                }
+                _ => {}
+            }
+        }
+
+        let cost = usize::from(parent_ov.clone()) as isize + delta;
+        Some(Cost::from(cost as usize))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
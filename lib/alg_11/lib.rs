@@ -177,4 +177,249 @@ pub fn auto_run(
     (timetable.to_solution_events(&db, &ctx), report)
 }
 
+// Tests ///////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::{
+        encoding::{Chromosome, Context, Phenotype},
+        operators::Mutation,
+    };
+    use ga::{
+        encoding::{Genotype, Phenotype as _},
+        operators::Mutation as _,
+        rng::Seeder,
+    };
+    use xhstt::{
+        db::Database,
+        xml::{Archives, X2014a},
+    };
+
+    /// Context and blueprint phenotype of the instance "hdtt4".
+    fn hdtt4() -> (Context, Phenotype) {
+        let xml = Archives::X2014a(X2014a::Hdtt4).xml();
+        let instance = xhstt::parse(&xml).instance().unwrap();
+        let db = Database::init(&instance).unwrap();
+
+        let ctx = Context::init(&db);
+        let ph = Phenotype::blueprint(&db, &ctx);
+
+        (ctx, ph)
+    }
+
+    #[test]
+    fn evaluate_delta() {
+        let (ctx, ph) = hdtt4();
+        let mut rng = Seeder::new(Some(0)).main();
+
+        for mutation in [
+            Mutation::MoveSubEvent,
+            Mutation::MoveSingleTimeAlloc,
+            Mutation::GaussMoveSingleTimeAlloc,
+            Mutation::Trade,
+            Mutation::GaussTrade,
+            Mutation::None,
+        ] {
+            let mut improved_or_worsened = 0;
+
+            for mut parent in Chromosome::generate(10, &ctx, &mut rng) {
+                let mut parent_ov = ph.derive(&parent, &ctx).evaluate(&ctx);
+
+                // Successive mutations carry the incrementally evaluated cost
+                // forward, so any drift accumulates
+                for _ in 0..20 {
+                    let mut child = parent.clone();
+                    let changed = mutation
+                        .exec_tracked(&mut child, 0.1, &mut rng, &ctx)
+                        .unwrap();
+
+                    // The incremental evaluation must match the evaluation
+                    // from scratch
+                    let child_ov = ph.evaluate_delta(
+                        &parent, &parent_ov, &child, &changed, &ctx,
+                    );
+                    assert_eq!(
+                        child_ov,
+                        Some(ph.derive(&child, &ctx).evaluate(&ctx)),
+                        "mutation: {}",
+                        mutation.identifier()
+                    );
+
+                    let child_ov = child_ov.unwrap();
+                    improved_or_worsened += (child_ov != parent_ov) as usize;
+                    (parent, parent_ov) = (child, child_ov);
+                }
+            }
+
+            // The mutations must actually change the cost
+            if !matches!(mutation, Mutation::None) {
+                assert!(
+                    improved_or_worsened > 0,
+                    "mutation: {}",
+                    mutation.identifier()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn self_adapted_gauss_mutation() {
+        use crate::operators::Crossover;
+        use ga::{
+            process::{
+                rejection::Reject, replacement::Replace, selection::Select,
+                termination::Terminate,
+            },
+            self_adaptation::{SelfAdaptation, Strategy},
+        };
+
+        let (ctx, ph) = hdtt4();
+        let mut rng = Seeder::new(Some(0)).main();
+        let mut chromosome = Chromosome::generate(1, &ctx, &mut rng).remove(0);
+
+        // Without strategy parameters, the distribution of the context is used
+        let sd = |c: &Chromosome| c.gauss(&ctx.gauss_rand_event).std_dev();
+        assert!(chromosome.strategy().is_none());
+        assert_eq!(sd(&chromosome), ctx.gauss_rand_event_sd);
+
+        *chromosome.strategy_mut().unwrap() = Strategy { rate: 0.1, sigma: 4. };
+        assert_eq!(sd(&chromosome), 4.);
+        chromosome.strategy_mut().unwrap().sigma = 0.1;
+        assert_eq!(sd(&chromosome), 1.);
+
+        // The algorithm attaches the strategy parameters to all individuals
+        let encoding = ga::encoding::Builder::new()
+            .set_context(ctx)
+            .set_phenotype(ph)
+            .build();
+
+        let parameters = ga::parameters::Builder::for_encoding(&encoding)
+            .set_population_size(20)
+            .set_crossover_rate(None)
+            .set_mutation_rate(0.05)
+            .set_selection(Select::Tournament(2))
+            .set_crossover(Crossover::Trade(1))
+            .set_mutation(Mutation::GaussTrade)
+            .set_rejection(Reject::None)
+            .set_replacement(Replace::EliteAbsolute(1))
+            .set_termination(Terminate::Generations(5))
+            .set_self_adaptation(SelfAdaptation::for_length(100))
+            .set_seed(1)
+            .build();
+
+        let report = ga::Builder::new()
+            .set_encoding(encoding)
+            .set_parameters(parameters)
+            .set_dynamics::<crate::dynamics::Dynamic>(None)
+            .set_custom_logger::<()>(None)
+            .build()
+            .run();
+
+        assert!(report.population.iter().all(|(c, _)| c.strategy().is_some()));
+        assert!(report.log.last().unwrap().strategy.is_some());
+    }
+
+    #[test]
+    fn context_dynamics_notify_observers() {
+        use crate::{dynamics::Dynamic, operators::Crossover};
+        use ga::process::{
+            rejection::Reject, replacement::Replace, selection::Select,
+            termination::Terminate,
+        };
+
+        let path = std::env::temp_dir()
+            .join(format!("alg-11-observer-{}.jsonl", std::process::id()));
+
+        let (ctx, ph) = hdtt4();
+        let encoding = ga::encoding::Builder::new()
+            .set_context(ctx)
+            .set_phenotype(ph)
+            .build();
+
+        let parameters = ga::parameters::Builder::for_encoding(&encoding)
+            .set_population_size(50)
+            .set_crossover_rate(None)
+            .set_mutation_rate(0.01)
+            .set_selection(Select::LinearRank(2.0))
+            .set_crossover(Crossover::Trade(1))
+            .set_mutation(Mutation::GaussTrade)
+            .set_rejection(Reject::None)
+            .set_replacement(Replace::EliteAbsolute(1))
+            .set_termination(Terminate::Generations(10))
+            .set_seed(3)
+            .build();
+
+        // The `GaussRandEvent` dynamic only modifies the context
+        let dynamics = ga::dynamics::Builder::for_parameters(&parameters)
+            .set(vec![Dynamic::GaussRandEvent(1.0)])
+            .build();
+
+        ga::Builder::new()
+            .set_encoding(encoding)
+            .set_parameters(parameters)
+            .set_dynamics(Some(dynamics))
+            .set_custom_logger::<()>(None)
+            .add_observer(ga::observer::JsonLines::create(&path).unwrap())
+            .build()
+            .run();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(log.lines().any(|l| l.contains("\"event\":\"dynamic\"")));
+    }
+
+    #[cfg(feature = "ga_serde")]
+    #[test]
+    fn resume() {
+        use crate::{dynamics::Dynamic, operators::Crossover};
+        use ga::process::{
+            rejection::Reject, replacement::Replace, selection::Select,
+            termination::Terminate,
+        };
+
+        let path = std::env::temp_dir()
+            .join(format!("alg-11-checkpoint-{}.json", std::process::id()));
+
+        // The `GaussRandEvent` dynamic modifies the context, which must be
+        // restored from the checkpoint
+        let build = || {
+            let (ctx, ph) = hdtt4();
+            let encoding = ga::encoding::Builder::new()
+                .set_context(ctx)
+                .set_phenotype(ph)
+                .build();
+
+            let parameters = ga::parameters::Builder::for_encoding(&encoding)
+                .set_population_size(50)
+                .set_crossover_rate(None)
+                .set_mutation_rate(0.01)
+                .set_selection(Select::LinearRank(2.0))
+                .set_crossover(Crossover::Trade(1))
+                .set_mutation(Mutation::GaussTrade)
+                .set_rejection(Reject::None)
+                .set_replacement(Replace::EliteAbsolute(1))
+                .set_termination(Terminate::Generations(25))
+                .set_seed(3)
+                .build();
+
+            let dynamics = ga::dynamics::Builder::for_parameters(&parameters)
+                .set(vec![Dynamic::GaussRandEvent(0.5)])
+                .build();
+
+            ga::Builder::new()
+                .set_encoding(encoding)
+                .set_parameters(parameters)
+                .set_dynamics(Some(dynamics))
+                .set_custom_logger::<()>(None)
+                .build()
+        };
+
+        let a = build().run_with_checkpoints(&path, 10).unwrap();
+        let b = build().resume(&path, 0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(a.population, b.population);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,5 +1,6 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use bits::Bits32;
+use ga::rng::GaRng;
 use rand::{seq::SliceRandom, Rng};
 
 use crate::encoding::{Chromosome, Context};
@@ -7,6 +8,7 @@ use crate::encoding::{Chromosome, Context};
 // Crossover ///////////////////////////////////////////////////////////////////
 #[allow(unused)]
 #[derive(Clone)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Crossover {
     /// Uniform crossover simply exchanges genes (single Bit32 structs) between
     /// chromosomes at the same position.
@@ -31,7 +33,7 @@ impl ga::operators::Crossover<Context, Chromosome> for Crossover {
         parent_0: &Chromosome,
         parent_1: &Chromosome,
         rate: Option<f32>,
-        rng: &mut rand::prelude::ThreadRng,
+        rng: &mut GaRng,
         _context: &Context,
     ) -> (Chromosome, Chromosome) {
         match self {
@@ -46,7 +48,7 @@ fn uniform(
     p0: &Chromosome,
     p1: &Chromosome,
     rate: Option<f32>,
-    rng: &mut rand::prelude::ThreadRng,
+    rng: &mut GaRng,
 ) -> (Chromosome, Chromosome) {
     let (c0, c1) = ga::operators::crossover::uniform(&p0.0, &p1.0, rate, rng);
 
@@ -61,7 +63,7 @@ fn trade(
     p0: &Chromosome,
     p1: &Chromosome,
     rate: Option<f32>,
-    rng: &mut rand::prelude::ThreadRng,
+    rng: &mut GaRng,
 ) -> (Chromosome, Chromosome) {
     // Decide whether the crossover should be performed or not.
     if let Some(rate) = rate {
@@ -98,7 +100,7 @@ fn trade(
 
                 // Perform trades from e0 to e1
                 let mut trade_indices = trade_0_to_1.ones().collect::<Vec<_>>();
-                trade_indices.shuffle(&mut rand::thread_rng());
+                trade_indices.shuffle(rng);
 
                 for i in trade_indices.into_iter().take(num_trades) {
                     y_e0.unset(i);
@@ -107,7 +109,7 @@ fn trade(
 
                 // Perform trades from e_1 to e_0
                 let mut trade_indices = trade_1_to_0.ones().collect::<Vec<_>>();
-                trade_indices.shuffle(&mut rand::thread_rng());
+                trade_indices.shuffle(rng);
 
                 for i in trade_indices.into_iter().take(num_trades) {
                     y_e1.unset(i);
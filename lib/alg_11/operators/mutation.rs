@@ -1,12 +1,14 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use crate::encoding::{Chromosome, Context};
-use rand::{rngs::ThreadRng, seq::IteratorRandom, Rng};
+use ga::rng::GaRng;
+use rand::{seq::IteratorRandom, Rng};
 use rand_distr::Distribution;
 
 // Mutation ////////////////////////////////////////////////////////////////////
 
 #[allow(unused)]
 #[derive(Clone)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mutation {
     /// Moves a sub event to new, random (fitting) starting time
     MoveSubEvent,
@@ -16,7 +18,8 @@ pub enum Mutation {
 
     /// Same as `MoveSingleTimeAlloc`, but this version utilizes a gaussian
     /// normal distribution as probability-density-function for the random
-    /// number generator.
+    /// number generator (with the self-adapted standard deviation of the
+    /// chromosome, if enabled).
     GaussMoveSingleTimeAlloc,
 
     /// Trade single time allocation
@@ -24,7 +27,8 @@ pub enum Mutation {
 
     /// Same as `Trade`, but this version utilizes a gaussian normal
     /// distribution as probability-density-function for the random number
-    /// generator.
+    /// generator (with the self-adapted standard deviation of the chromosome,
+    /// if enabled).
     GaussTrade,
 
     /// No mutation.
@@ -47,21 +51,36 @@ impl ga::operators::Mutation<Context, Chromosome> for Mutation {
         &self,
         c: &mut Chromosome,
         rate: f32,
-        rng: &mut ThreadRng,
+        rng: &mut GaRng,
         ctx: &Context,
     ) {
+        self.exec_tracked(c, rate, rng, ctx);
+    }
+
+    fn exec_tracked(
+        &self,
+        c: &mut Chromosome,
+        rate: f32,
+        rng: &mut GaRng,
+        ctx: &Context,
+    ) -> Option<Vec<usize>> {
+        let mut changed = vec![];
+        let ch = &mut changed;
+
         match self {
-            Self::MoveSubEvent => move_sub_event(c, rate, rng, ctx),
+            Self::MoveSubEvent => move_sub_event(c, rate, rng, ctx, ch),
             Self::MoveSingleTimeAlloc => {
-                move_single_time_alloc(c, rate, rng, ctx)
+                move_single_time_alloc(c, rate, rng, ctx, ch)
             }
             Self::GaussMoveSingleTimeAlloc => {
-                gauss_move_single_time_alloc(c, rate, rng, ctx);
+                gauss_move_single_time_alloc(c, rate, rng, ctx, ch);
             }
-            Self::Trade => trade(c, rate, rng, ctx),
-            Self::GaussTrade => gauss_trade(c, rate, rng, ctx),
+            Self::Trade => trade(c, rate, rng, ctx, ch),
+            Self::GaussTrade => gauss_trade(c, rate, rng, ctx, ch),
             Self::None => {}
         }
+
+        Some(changed)
     }
 }
 
@@ -69,11 +88,12 @@ impl ga::operators::Mutation<Context, Chromosome> for Mutation {
 fn move_sub_event(
     c: &mut Chromosome,
     rate: f32,
-    rng: &mut ThreadRng,
+    rng: &mut GaRng,
     _ctx: &Context,
+    changed: &mut Vec<usize>,
 ) {
     // Iterate over all genes
-    for bits in c.0.iter_mut() {
+    for (i_event, bits) in c.0.iter_mut().enumerate() {
         // Decide wether to mutate or not
         if rng.gen::<f32>() > rate {
             continue;
@@ -100,17 +120,19 @@ fn move_sub_event(
         // Unset the old index, and set the new index
         bits.unset(i);
         bits.set_block(new_index, d as u32);
+        changed.push(i_event);
     }
 }
 
 fn move_single_time_alloc(
     c: &mut Chromosome,
     rate: f32,
-    rng: &mut ThreadRng,
+    rng: &mut GaRng,
     _ctx: &Context,
+    changed: &mut Vec<usize>,
 ) {
     // Iterate over all genes (events)
-    for bits in c.0.iter_mut() {
+    for (i_event, bits) in c.0.iter_mut().enumerate() {
         // Decide wether to mutate or not
         if rng.gen::<f32>() > rate {
             continue;
@@ -124,17 +146,21 @@ fn move_single_time_alloc(
 
         bits.unset(alloc);
         bits.set(free);
+        changed.push(i_event);
     }
 }
 
 fn gauss_move_single_time_alloc(
     c: &mut Chromosome,
     rate: f32,
-    rng: &mut ThreadRng,
+    rng: &mut GaRng,
     ctx: &Context,
+    changed: &mut Vec<usize>,
 ) {
+    let gauss = c.gauss(&ctx.gauss_rand_time);
+
     // Iterate over all genes (events)
-    'outer: for bits in c.0.iter_mut() {
+    'outer: for (i_event, bits) in c.0.iter_mut().enumerate() {
         // Decide wether to mutate or not
         if rng.gen::<f32>() > rate {
             continue;
@@ -147,7 +173,7 @@ fn gauss_move_single_time_alloc(
         let free = bits.zeros().collect::<Vec<_>>();
 
         // Calculate the destination index.
-        let mut offset = ctx.gauss_rand_time.sample(rng).round() as i32;
+        let mut offset = gauss.sample(rng).round() as i32;
         let mut new_index = alloc as i32 + offset;
         let mut counter = 0;
 
@@ -156,7 +182,7 @@ fn gauss_move_single_time_alloc(
             || new_index >= ctx.num_times as i32
             || !free.contains(&(new_index as u32))
         {
-            offset = ctx.gauss_rand_time.sample(rng).round() as i32;
+            offset = gauss.sample(rng).round() as i32;
             new_index = alloc as i32 + offset;
 
             counter += 1;
@@ -169,10 +195,17 @@ fn gauss_move_single_time_alloc(
         // Move time allocation
         bits.unset(alloc);
         bits.set(new_index as u32);
+        changed.push(i_event);
     }
 }
 
-fn trade(c: &mut Chromosome, rate: f32, rng: &mut ThreadRng, ctx: &Context) {
+fn trade(
+    c: &mut Chromosome,
+    rate: f32,
+    rng: &mut GaRng,
+    ctx: &Context,
+    changed: &mut Vec<usize>,
+) {
     // Iterate over all genes (events)
     for i0 in 0..c.0.len() {
         // Decide wether to mutate or not
@@ -222,15 +255,19 @@ fn trade(c: &mut Chromosome, rate: f32, rng: &mut ThreadRng, ctx: &Context) {
         // Apply the changed bits to the chromosome again
         c.0[i0] = b0;
         c.0[i1] = b1;
+        changed.extend([i0, i1]);
     }
 }
 
 fn gauss_trade(
     c: &mut Chromosome,
     rate: f32,
-    rng: &mut ThreadRng,
+    rng: &mut GaRng,
     ctx: &Context,
+    changed: &mut Vec<usize>,
 ) {
+    let gauss = c.gauss(&ctx.gauss_rand_event);
+
     // Iterate over all genes (events)
     for i0 in 0..c.0.len() {
         // Decide wether to mutate or not
@@ -239,10 +276,10 @@ fn gauss_trade(
         }
 
         // Randomly choose a trade partner (index)
-        let mut offset = ctx.gauss_rand_event.sample(rng).round() as i32;
+        let mut offset = gauss.sample(rng).round() as i32;
         let mut i1_tmp = i0 as i32 + offset;
         while offset == 0 || i1_tmp < 0 || i1_tmp >= ctx.num_events as i32 {
-            offset = ctx.gauss_rand_event.sample(rng).round() as i32;
+            offset = gauss.sample(rng).round() as i32;
             i1_tmp = i0 as i32 + offset;
         }
 
@@ -287,6 +324,7 @@ fn gauss_trade(
         // Apply the changed bits to the chromosome again
         c.0[i0] = b0;
         c.0[i1] = b1;
+        changed.extend([i0, i1]);
     }
 }
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,7 +1,5 @@
 // Modules /////////////////////////////////////////////////////////////////////
 mod gauss_rand_event;
-mod inc_lin_rnk_sel_pressure;
-mod mut_rate_cos;
 mod state_machine;
 mod var_mut_rate_target_mean_sin;
 
@@ -10,9 +8,13 @@ use crate::{
     encoding::{Chromosome, Context, Cost},
     operators::{Crossover, Mutation},
 };
-use ga::process::{
-    rejection::Reject, replacement::Replace, selection::Select,
-    termination::Terminate,
+use ga::{
+    dynamics::{Standard, StateMachine},
+    process::{
+        rejection::Reject, replacement::Replace, selection::Select,
+        termination::Terminate,
+    },
+    rng::GaRng,
 };
 
 // Dynamic Enum ////////////////////////////////////////////////////////////////
@@ -60,6 +62,44 @@ pub enum Dynamic {
 
     /// State Machine
     StateMachine,
+
+    /// Declarative state machine of the `ga` crate (see
+    /// [`ga::dynamics::StateMachine`]).
+    Machine(StateMachine<Select, Crossover, Mutation, Replace>),
+
+    /// Generic dynamic of the `ga` crate.
+    Standard(Standard),
+}
+
+impl Dynamic {
+    /// Returns the generic equivalent of this dynamic (see
+    /// [`ga::dynamics::Standard`]), if there is one.
+    fn standard(&self) -> Option<Standard> {
+        match self {
+            Self::MutRateCos(base, amplitude, wavelength, bounds) => {
+                Some(Standard::MutRateCos {
+                    base: *base,
+                    amplitude: *amplitude,
+                    wavelength: *wavelength,
+                    bounds: *bounds,
+                })
+            }
+            Self::IncLinearRankSelectionPressure(
+                interval,
+                step,
+                max,
+                reset,
+            ) => Some(Standard::PressureOnStagnation {
+                initial: 1.,
+                interval: *interval,
+                step: *step as f64,
+                max: *max as f64,
+                reset: *reset,
+            }),
+            Self::Standard(standard) => Some(standard.clone()),
+            _ => None,
+        }
+    }
 }
 
 #[rustfmt::skip]
@@ -79,6 +119,34 @@ impl ga::dynamics::Dynamic<Cost, Context, Chromosome, Crossover, Mutation, usize
                 format!("inc-lin-rnk-sel-pressure-{a}-{b}-{c}-{d}"),
 
             Self::StateMachine => "state-machine".into(),
+
+            Self::Machine(machine) =>
+                machine.identifier::<Cost, Context, Chromosome, usize>(),
+
+            Self::Standard(standard) => standard.identifier(),
+        }
+    }
+
+    fn take_events(&self) -> Vec<String> {
+        match self {
+            Self::Machine(machine) => machine.take_events(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "ga_serde")]
+    fn save_state(&self) -> serde_json::Value {
+        match self {
+            Self::Machine(machine) => machine.save_state(),
+            _ => serde_json::Value::Null,
+        }
+    }
+
+    #[cfg(feature = "ga_serde")]
+    fn restore_state(&self, state: serde_json::Value) -> Result<(), ga::checkpoint::Error> {
+        match self {
+            Self::Machine(machine) => machine.restore_state(state),
+            _ => Ok(()),
         }
     }
 
@@ -87,11 +155,18 @@ impl ga::dynamics::Dynamic<Cost, Context, Chromosome, Crossover, Mutation, usize
         rtd: &mut ga::runtime_data::RuntimeData<Cost, Context, Chromosome, Crossover, Mutation, usize, Select, Reject, Replace, Terminate<Cost>>,
         parameters: &mut ga::parameters::Parameters<Cost, Context, Chromosome, Crossover, Mutation, usize, Select, Reject, Replace, Terminate<Cost>>,
         context: &mut Context,
+        rng: &mut GaRng,
     ) {
-        match self {
-            Self::MutRateCos(dmr, _, _, _) =>
-                mut_rate_cos::setup(rtd, parameters, context, *dmr),
+        // The selection pressure is increased for the linear rank selection
+        if let Self::IncLinearRankSelectionPressure(..) = self {
+            parameters.selection = Select::LinearRank(1.0);
+        }
+
+        if let Some(standard) = self.standard() {
+            return standard.setup(rtd, parameters, context, rng);
+        }
 
+        match self {
             Self::GaussRandEvent(_) =>
                 gauss_rand_event::setup(rtd, parameters, context),
 
@@ -100,11 +175,16 @@ impl ga::dynamics::Dynamic<Cost, Context, Chromosome, Crossover, Mutation, usize
                     rtd, parameters, context, *g
                 ),
 
-            Self::IncLinearRankSelectionPressure(_, _, _, _) =>
-                inc_lin_rnk_sel_pressure::setup(rtd, parameters, context),
-
             Self::StateMachine =>
                 state_machine::setup(rtd, parameters, context),
+
+            Self::Machine(machine) =>
+                machine.setup(rtd, parameters, context, rng),
+
+            // Generic dynamics (see above)
+            Self::MutRateCos(..)
+            | Self::IncLinearRankSelectionPressure(..)
+            | Self::Standard(_) => {}
         }
     }
 
@@ -113,15 +193,17 @@ impl ga::dynamics::Dynamic<Cost, Context, Chromosome, Crossover, Mutation, usize
         rtd: &ga::runtime_data::RuntimeData<Cost, Context, Chromosome, Crossover, Mutation, usize, Select, Reject, Replace, Terminate<Cost>>,
         parameters: &mut ga::parameters::Parameters<Cost, Context, Chromosome, Crossover, Mutation, usize, Select, Reject, Replace, Terminate<Cost>>,
         context: &mut Context,
+        rng: &mut GaRng,
         #[cfg(feature = "ga_log_dynamics")] rerun_logger: &ga::tools::rerun_logger::RerunLogger,
-    ) {
-        match self {
-            Self::MutRateCos(dmr, a, w, mm) => mut_rate_cos::exec(
-                rtd, parameters, context,
+    ) -> bool {
+        if let Some(standard) = self.standard() {
+            return standard.exec(
+                rtd, parameters, context, rng,
                 #[cfg(feature = "ga_log_dynamics")] rerun_logger,
-                *dmr, *a, *w, *mm
-            ),
+            );
+        }
 
+        match self {
             Self::GaussRandEvent(tsr) => gauss_rand_event::exec(
                 rtd, parameters, context,
                 #[cfg(feature = "ga_log_dynamics")] rerun_logger,
@@ -135,19 +217,20 @@ impl ga::dynamics::Dynamic<Cost, Context, Chromosome, Crossover, Mutation, usize
                     *avg_dv, *a, *w
                 ),
 
-            Self::IncLinearRankSelectionPressure(
-                step_gen, step_val, max_sp, reset
-            ) =>
-                inc_lin_rnk_sel_pressure::exec(
-                    rtd, parameters, context,
-                    #[cfg(feature = "ga_log_dynamics")] rerun_logger,
-                    *step_gen, *step_val, *max_sp, *reset
-                ),
-
             Self::StateMachine => state_machine::exec(
                 rtd, parameters, context,
                 #[cfg(feature = "ga_log_dynamics")] rerun_logger
             ),
+
+            Self::Machine(machine) => machine.exec(
+                rtd, parameters, context, rng,
+                #[cfg(feature = "ga_log_dynamics")] rerun_logger
+            ),
+
+            // Generic dynamics (see above)
+            Self::MutRateCos(..)
+            | Self::IncLinearRankSelectionPressure(..)
+            | Self::Standard(_) => false,
         }
     }
 }
@@ -38,7 +38,7 @@ pub fn exec(
     avg_deviation: f64, // average deviation from current mean
     a: f32,             // amplitude of the sine function
     w: usize,           // wavelength of the sine function (in generations)
-) {
+) -> bool {
     // Calculate the currently targeted deviation (based on the sine function).
     let wavelength = 2. * std::f32::consts::PI / (w as f32);
     let cos_val = a * (wavelength * rtd.generation as f32).sin();
@@ -67,6 +67,9 @@ pub fn exec(
         rerun_logger
             .log_mutation_rate(rtd.generation, parameters.mutation_rate);
     };
+
+    // The PT2 block of the context is updated in every generation
+    true
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -147,8 +147,48 @@ impl Context {
     }
 }
 
+// Context State ///////////////////////////////////////////////////////////////
+
+/// Parts of the context, which are modified by the dynamics (stored in
+/// checkpoints).
+#[cfg(feature = "ga_serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DynamicState {
+    gauss_rand_event: Normal<f32>,
+    gauss_rand_event_sd: f32,
+    pt2: PT2,
+    state_machine: StateMachine,
+}
+
+#[cfg(feature = "ga_serde")]
+impl ga::checkpoint::ContextState for Context {
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(DynamicState {
+            gauss_rand_event: self.gauss_rand_event,
+            gauss_rand_event_sd: self.gauss_rand_event_sd,
+            pt2: self.pt2.clone(),
+            state_machine: self.state_machine.clone(),
+        })
+        .unwrap()
+    }
+
+    fn restore_state(
+        &mut self,
+        state: serde_json::Value,
+    ) -> Result<(), ga::checkpoint::Error> {
+        let state: DynamicState = serde_json::from_value(state)?;
+
+        self.gauss_rand_event = state.gauss_rand_event;
+        self.gauss_rand_event_sd = state.gauss_rand_event_sd;
+        self.pt2 = state.pt2;
+        self.state_machine = state.state_machine;
+        Ok(())
+    }
+}
+
 // State Machine ///////////////////////////////////////////////////////////////
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateMachine {
     pub last_state_change: usize,
     pub focus_without_success: usize,
@@ -156,6 +196,7 @@ pub struct StateMachine {
 }
 
 #[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum State {
     #[default]
     Broad,
@@ -1,6 +1,8 @@
 // Imports /////////////////////////////////////////////////////////////////////
+use ga::{rng::GaRng, self_adaptation::Strategy};
 use hashbrown::HashMap;
 use rand::seq::SliceRandom;
+use rand_distr::Normal;
 use std::ops::AddAssign;
 
 use super::Context;
@@ -10,12 +12,16 @@ use super::Context;
 /// indices in the chromosome defines the order in which the corresponding
 /// events are scheduled. The values of the vector fields are event indices.
 /// Therefore, the encoding is a "permutation encoding".
+///
+/// With self-adaptation (see [`ga::self_adaptation`]), the chromosome also
+/// carries its strategy parameters, which are attached by the algorithm.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Chromosome(pub Vec<u8>);
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chromosome(pub Vec<u8>, Option<Strategy>);
 
 impl From<Vec<u8>> for Chromosome {
     fn from(value: Vec<u8>) -> Self {
-        Self(value)
+        Self(value, None)
     }
 }
 
@@ -37,22 +43,30 @@ impl Chromosome {
     pub fn to_vec(&self) -> Vec<u8> {
         self.0.clone()
     }
+
+    /// Normal distribution of the Gauss mutation: the self-adapted standard
+    /// deviation (at least 1, as the sampled offsets are rounded), if the
+    /// chromosome carries strategy parameters, or the one of the context.
+    pub fn gauss(&self, context: &Normal<f32>) -> Normal<f32> {
+        self.1
+            .and_then(|s| Normal::new(0., (s.sigma as f32).max(1.)).ok())
+            .unwrap_or(*context)
+    }
 }
 
 impl From<Vec<&u8>> for Chromosome {
     fn from(value: Vec<&u8>) -> Self {
-        Self(value.into_iter().cloned().collect())
+        Self(value.into_iter().cloned().collect(), None)
     }
 }
 
 impl ga::encoding::Genotype<Context> for Chromosome {
-    fn generate(amount: usize, ctx: &Context) -> Vec<Self> {
-        let mut rng = rand::thread_rng();
+    fn generate(amount: usize, ctx: &Context, rng: &mut GaRng) -> Vec<Self> {
         let mut chromosomes: Vec<Self> = Vec::with_capacity(amount);
 
         for _ in 0..amount {
             let mut chromosome = (0..ctx.num_events as u8).collect::<Vec<u8>>();
-            chromosome.shuffle(&mut rng);
+            chromosome.shuffle(rng);
 
             chromosomes.push(chromosome.into());
         }
@@ -75,6 +89,31 @@ impl ga::encoding::Genotype<Context> for Chromosome {
         // Return
         arr.into_iter().map(|(_, x)| x).collect()
     }
+
+    fn distance(&self, other: &Self) -> Option<f64> {
+        // Position distance (Spearman's footrule) of the event permutations:
+        // sum of the differences of the positions of every event
+        let mut positions = vec![0usize; self.0.len()];
+        for (i, event) in self.0.iter().enumerate() {
+            positions[*event as usize] = i;
+        }
+
+        let distance: usize = (other.0.iter().enumerate())
+            .map(|(i, event)| i.abs_diff(positions[*event as usize]))
+            .sum();
+
+        Some(distance as f64)
+    }
+
+    fn strategy(&self) -> Option<&Strategy> {
+        self.1.as_ref()
+    }
+
+    fn strategy_mut(&mut self) -> Option<&mut Strategy> {
+        // The strategy parameters are attached on demand (only the
+        // self-adaptation requests them)
+        Some(self.1.get_or_insert(Strategy { rate: 0., sigma: 1. }))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
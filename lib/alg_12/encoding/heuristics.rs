@@ -0,0 +1,94 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use super::{Chromosome, Context};
+use ga::rng::GaRng;
+use rand::seq::SliceRandom;
+
+// Heuristics //////////////////////////////////////////////////////////////////
+// Event orderings of graph coloring heuristics, which are used to warm-start
+// the genetic algorithm. Two events are adjacent in the conflict graph, if
+// they share a resource (see `Context::resource_relations`). Ties are broken
+// randomly, so each call yields different chromosomes.
+
+/// Largest-degree-first: events with more conflicting events are scheduled
+/// first.
+pub fn largest_degree_first(
+    amount: usize,
+    ctx: &Context,
+    rng: &mut GaRng,
+) -> Vec<Chromosome> {
+    let degrees = degrees(ctx);
+
+    (0..amount)
+        .map(|_| {
+            let mut events = (0..ctx.num_events as u8).collect::<Vec<u8>>();
+            events.shuffle(rng);
+
+            // Stable sort keeps the random order of events with equal degree
+            events.sort_by_key(|e| std::cmp::Reverse(degrees[*e as usize]));
+            events.into()
+        })
+        .collect()
+}
+
+/// DSatur: the event with the most distinct colors (timeslots) among its
+/// already colored neighbors is scheduled next. Ties are broken by the degree.
+/// Colors are assigned greedily (smallest color not used by a neighbor).
+pub fn dsatur(
+    amount: usize,
+    ctx: &Context,
+    rng: &mut GaRng,
+) -> Vec<Chromosome> {
+    let degrees = degrees(ctx);
+
+    (0..amount)
+        .map(|_| {
+            let mut uncolored = (0..ctx.num_events).collect::<Vec<usize>>();
+            uncolored.shuffle(rng);
+
+            let mut colors: Vec<Option<usize>> = vec![None; ctx.num_events];
+            let mut order: Vec<u8> = Vec::with_capacity(ctx.num_events);
+
+            while !uncolored.is_empty() {
+                // Colors of the neighbors of the given event
+                let neighbor_colors = |e: usize| {
+                    let mut nc: Vec<usize> = ctx.resource_relations[e]
+                        .ones()
+                        .filter_map(|n| colors[n as usize])
+                        .collect();
+                    nc.sort_unstable();
+                    nc.dedup();
+                    nc
+                };
+
+                // First maximum in the (shuffled) list of uncolored events
+                let (i, _) = uncolored
+                    .iter()
+                    .map(|e| (neighbor_colors(*e).len(), degrees[*e]))
+                    .enumerate()
+                    .fold((0, (0, 0)), |best, (i, key)| {
+                        if i == 0 || key > best.1 {
+                            (i, key)
+                        } else {
+                            best
+                        }
+                    });
+
+                let event = uncolored.remove(i);
+                let used = neighbor_colors(event);
+                colors[event] = (0..).find(|c| used.binary_search(c).is_err());
+                order.push(event as u8);
+            }
+
+            order.into()
+        })
+        .collect()
+}
+
+// Helper Functions ////////////////////////////////////////////////////////////
+
+/// Number of conflicting events of each event.
+fn degrees(ctx: &Context) -> Vec<usize> {
+    ctx.resource_relations.iter().map(|rr| rr.ones().count()).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -2,6 +2,7 @@
 mod constraints;
 mod context;
 mod genotype;
+pub mod heuristics;
 mod objective_value;
 mod phenotype;
 
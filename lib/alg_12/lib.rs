@@ -23,16 +23,21 @@ pub mod operators;
 
 // Imports /////////////////////////////////////////////////////////////////////
 use dynamics::Dynamic;
-use encoding::{Chromosome, Context, Cost, Phenotype};
+use encoding::{heuristics, Chromosome, Context, Cost, Phenotype};
 use ga::{
     encoding::Phenotype as _,
+    initializer::Mix,
+    operators::local_search::{Mode, Policy},
     process::{
-        rejection::Reject, replacement::Replace, selection::Select,
+        rejection::Reject,
+        replacement::{Replace, Replacement},
+        selection::{Select, Selection},
         termination::Terminate,
     },
     report::Report,
+    tuning::{Configuration, Race, Ranking, Space, Tuner},
 };
-use operators::{Crossover, Mutation};
+use operators::{Crossover, LocalSearch, Mutation};
 use xhstt::{
     db::Database,
     parser::{instances::Instance, solution_groups::solution::events::Event},
@@ -63,6 +68,7 @@ pub fn run(instance: Instance) -> Vec<Event> {
         .set_rejection(Reject::None)
         .set_replacement(Replace::EliteAbsolute(10))
         .set_termination(Terminate::GenOrOv(100_000, 0.into()))
+        .set_local_search(Policy::Elite, Mode::Lamarckian)
         .build();
 
     let dynamics = ga::dynamics::Builder::for_parameters(&parameters)
@@ -74,12 +80,19 @@ pub fn run(instance: Instance) -> Vec<Event> {
         ])
         .build();
 
+    // Warm-start with event orderings of graph coloring heuristics
+    let initializer = Mix::new()
+        .add("ldf", 0.1, heuristics::largest_degree_first)
+        .add("dsatur", 0.1, heuristics::dsatur);
+
     // Create algorithm and let it run!
     let alg = ga::Builder::new()
         .set_encoding(encoding)
         .set_parameters(parameters)
         .set_dynamics(Some(dynamics))
         .set_custom_logger::<()>(None)
+        .set_local_search(LocalSearch::EventSwapHillClimb(20))
+        .set_initializer(initializer)
         .build();
 
     let report = alg.run();
@@ -106,9 +119,96 @@ pub fn auto_run(
     instance: Instance,
     params: AutoRunParameters,
     dynamics: Option<Vec<Dynamic>>,
+) -> (Vec<Event>, Report<Cost, Context, Chromosome>) {
+    execute(
+        &instance,
+        params,
+        dynamics,
+        Terminate::GenOrOv(100_000, 0.into()),
+        None,
+    )
+}
+
+/// Tunes the parameters of the auto-runner (without dynamics) on the given
+/// instances with the iterated racing procedure of [`ga::tuning`]. Every run
+/// is terminated after the given amount of generations, its cost is the best
+/// objective value.
+pub fn tune(
+    instances: &[Instance],
+    generations: usize,
+    race: &Race,
+    seed: u64,
+) -> Ranking {
+    // The labels of the categorical parameters are the operator identifiers
+    let selections = [
+        Select::Tournament(2),
+        Select::Tournament(8),
+        Select::LinearRank(1.5),
+        Select::RouletteWheel,
+    ];
+    let crossovers = [
+        Crossover::VariableSinglePoint,
+        Crossover::VariableNPoint(2),
+        Crossover::Pmx,
+        Crossover::Ordered,
+    ];
+    let mutations = [Mutation::UniformSwap, Mutation::GaussSwap];
+    let replacements = [Replace::Full, Replace::EliteAbsolute(10)];
+
+    let space = Space::new()
+        .add_integer("population_size", 100, 2_000)
+        .add_log_real("mutation_rate", 0.001, 0.1)
+        .add_categorical(
+            "selection",
+            selections
+                .iter()
+                .map(Selection::<Cost, Context, Chromosome, usize>::identifier),
+        )
+        .add_categorical(
+            "crossover",
+            crossovers.iter().map(ga::operators::Crossover::identifier),
+        )
+        .add_categorical(
+            "mutation",
+            mutations.iter().map(ga::operators::Mutation::identifier),
+        )
+        .add_categorical(
+            "replacement",
+            replacements
+                .iter()
+                .map(Replacement::<(Chromosome, Cost)>::identifier),
+        );
+
+    let evaluate = |c: &Configuration, instance: &Instance, seed: u64| {
+        let params = AutoRunParameters {
+            population_size: c.integer("population_size") as usize,
+            mutation_rate: c.real("mutation_rate") as f32,
+            selection: selections[c.index("selection")].clone(),
+            crossover: crossovers[c.index("crossover")].clone(),
+            mutation: mutations[c.index("mutation")].clone(),
+            replacement: replacements[c.index("replacement")].clone(),
+        };
+
+        let termination = Terminate::GenOrOv(generations, 0.into());
+        let (_, report) =
+            execute(instance, params, None, termination, Some(seed));
+        let best = report.population.first().unwrap().1.clone();
+
+        usize::from(best) as f64
+    };
+
+    Tuner::new(space, instances, evaluate).set_seed(seed).race(race)
+}
+
+fn execute(
+    instance: &Instance,
+    params: AutoRunParameters,
+    dynamics: Option<Vec<Dynamic>>,
+    termination: Terminate<Cost>,
+    seed: Option<u64>,
 ) -> (Vec<Event>, Report<Cost, Context, Chromosome>) {
     // Create an XHSTT database of the problem instance
-    let db = Database::init(&instance).unwrap();
+    let db = Database::init(instance).unwrap();
 
     // Initialize context and phenotype
     let ctx = Context::init(&db);
@@ -129,8 +229,11 @@ pub fn auto_run(
         .set_mutation(params.mutation)
         .set_rejection(Reject::None)
         .set_replacement(params.replacement)
-        .set_termination(Terminate::GenOrOv(100_000, 0.into()))
-        .build();
+        .set_termination(termination);
+    let parameters = match seed {
+        Some(seed) => parameters.set_seed(seed).build(),
+        None => parameters.build(),
+    };
 
     // Create algorithm and let it run!
     let report = match dynamics {
@@ -1,11 +1,11 @@
-use rand::rngs::ThreadRng;
-
 // Imports /////////////////////////////////////////////////////////////////////
 use crate::encoding::{Chromosome, Context};
+use ga::rng::GaRng;
 
 // Crossover ///////////////////////////////////////////////////////////////////
 #[allow(unused)]
 #[derive(Clone)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Crossover {
     /// Variable single-point crossover takes one argument:
     VariableSinglePoint,
@@ -36,7 +36,7 @@ impl ga::operators::Crossover<Context, Chromosome> for Crossover {
         parent_0: &Chromosome,
         parent_1: &Chromosome,
         rate: Option<f32>,
-        rng: &mut ThreadRng,
+        rng: &mut GaRng,
         _context: &Context,
     ) -> (Chromosome, Chromosome) {
         match self {
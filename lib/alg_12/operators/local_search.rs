@@ -0,0 +1,62 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::encoding::{Chromosome, Context, Cost};
+use ga::{operators::local_search::Evaluator, rng::GaRng};
+use rand::prelude::Distribution;
+
+// Local Search ////////////////////////////////////////////////////////////////
+#[allow(unused)]
+#[derive(Clone)]
+pub enum LocalSearch {
+    /// Hill climbing over swaps of two random events in the scheduling order.
+    /// A swap is kept, if it reduces the cost. Takes one argument:
+    /// 1) usize    representing the amount of swaps (evaluations)
+    EventSwapHillClimb(usize),
+}
+
+impl ga::operators::LocalSearch<Cost, Context, Chromosome> for LocalSearch {
+    fn identifier(&self) -> String {
+        match self {
+            Self::EventSwapHillClimb(n) => format!("ev-sw-hc-{n}"),
+        }
+    }
+
+    fn exec(
+        &self,
+        chromosome: &Chromosome,
+        objective_value: &Cost,
+        evaluator: &mut Evaluator<Chromosome, Cost>,
+        rng: &mut GaRng,
+        ctx: &Context,
+    ) -> (Chromosome, Cost) {
+        match self {
+            Self::EventSwapHillClimb(steps) => {
+                let mut best = (chromosome.clone(), objective_value.clone());
+
+                for _ in 0..*steps {
+                    // No clashes left
+                    if best.1 == 0.into() {
+                        break;
+                    }
+
+                    let a = ctx.rand_event.sample(rng) as usize;
+                    let b = ctx.rand_event.sample(rng) as usize;
+                    if a == b {
+                        continue;
+                    }
+
+                    let mut candidate = best.0.clone();
+                    candidate.as_mut_slice().swap(a, b);
+
+                    let cost = evaluator.evaluate(&candidate);
+                    if cost < best.1 {
+                        best = (candidate, cost);
+                    }
+                }
+
+                best
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -1,9 +1,11 @@
 // Modules /////////////////////////////////////////////////////////////////////
 mod crossover;
+mod local_search;
 mod mutation;
 
 // Re-Exports //////////////////////////////////////////////////////////////////
 pub use crossover::*;
+pub use local_search::*;
 pub use mutation::*;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,15 +1,17 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use crate::encoding::{Chromosome, Context};
-use rand::rngs::ThreadRng;
+use ga::rng::GaRng;
 
 // Mutation ////////////////////////////////////////////////////////////////////
 #[allow(unused)]
 #[derive(Clone)]
+#[cfg_attr(feature = "ga_serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mutation {
     /// Swaps to random genes.
     UniformSwap,
 
-    /// Non-uniform random values based on normal-distribution.
+    /// Non-uniform random values based on normal-distribution (with the
+    /// self-adapted standard deviation of the chromosome, if enabled).
     GaussSwap,
 }
 
@@ -25,7 +27,7 @@ impl ga::operators::Mutation<Context, Chromosome> for Mutation {
         &self,
         chromosome: &mut Chromosome,
         rate: f32,
-        rng: &mut ThreadRng,
+        rng: &mut GaRng,
         ctx: &Context,
     ) {
         match self {
@@ -39,10 +41,11 @@ impl ga::operators::Mutation<Context, Chromosome> for Mutation {
             }
 
             Mutation::GaussSwap => {
+                let gauss = chromosome.gauss(&ctx.gauss_rand_event);
                 ga::operators::mutation::swap_normal_dist_u8(
                     chromosome.as_mut_slice(),
                     rate,
-                    &ctx.gauss_rand_event,
+                    &gauss,
                     rng,
                 )
             }
@@ -13,6 +13,10 @@ macro_rules! generate_bits {
 
         /// Bits struct
         #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
         $vis struct $name {
             len         : $ty,
             len_mask    : $ty,
@@ -1,4 +1,5 @@
 // PT1 control circuit, commonly used as lowpass-filter.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PT1 {
     t: f64,  // Time constant T
     k: f64,  // Gain
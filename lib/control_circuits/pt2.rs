@@ -1,5 +1,6 @@
 /// Represents a PT2 control circuit.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PT2 {
     /// Time Constant T1:
     /// This parameter defines how quickly the system reacts to changes. A
@@ -0,0 +1,348 @@
+//! Statistical comparison of configurations across repeated runs.
+//!
+//! The reports of repeated algorithm executions (e.g. loaded with
+//! [`load_all`](crate::report::load_all)) are grouped by their parameter and
+//! dynamics identifiers. For every group, the success rate (the best
+//! objective value reached the target), the median and interquartile range of
+//! the final cost and of the time-to-target (of the successful runs) are
+//! calculated. All groups are compared pairwise by their final costs with the
+//! Mann-Whitney U test, the p-values are adjusted with the Holm correction
+//! and the Vargha-Delaney A12 effect size is reported (see
+//! [`crate::statistics`]).
+//!
+//! Example:
+//! ```rust ,ignore
+//! let reports: Vec<Report<Cost, Context, Chromosome>> = load_all("reports")?;
+//! let comparison = ga::analysis::compare(&reports, 0, 0.05);
+//!
+//! // Comparison table (groups and pairwise tests)
+//! println!("{comparison}");
+//! std::fs::write("pairs.csv", comparison.pairs_csv())?;
+//! ```
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    report::{opt, Report},
+    statistics::{
+        effect_magnitude, holm, mann_whitney_u, quantile, vargha_delaney_a12,
+        TestResult,
+    },
+};
+use std::fmt::Write;
+
+// Quartiles ///////////////////////////////////////////////////////////////////
+
+/// First quartile, median and third quartile of a sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quartiles {
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+}
+
+impl Quartiles {
+    /// Returns the quartiles of the values, or `None` if there are no values.
+    pub fn of(values: &[f64]) -> Option<Self> {
+        Some(Self {
+            q1: quantile(values, 0.25)?,
+            median: quantile(values, 0.5)?,
+            q3: quantile(values, 0.75)?,
+        })
+    }
+
+    /// Interquartile range.
+    pub fn iqr(&self) -> f64 {
+        self.q3 - self.q1
+    }
+}
+
+// Group ///////////////////////////////////////////////////////////////////////
+
+/// Runs with the same parameter and dynamics identifier.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group {
+    pub parameter_identifier: String,
+    pub dynamics_identifier: Option<String>,
+
+    /// Final costs (best objective value, see [`ObjectiveValue::to_usize`]) of
+    /// the runs in the order of the reports.
+    pub costs: Vec<f64>,
+
+    /// Times-to-target of the successful runs in MILLISECONDS.
+    pub times_to_target_ms: Vec<f64>,
+
+    /// Share of runs which reached the target (0 to 1).
+    pub success_rate: f64,
+
+    /// Quartiles of the final costs (`None` if the group contains no run with
+    /// a population).
+    pub cost: Option<Quartiles>,
+
+    /// Quartiles of the times-to-target (`None` if no run was successful).
+    pub time_to_target_ms: Option<Quartiles>,
+}
+
+impl Group {
+    /// Returns the parameter identifier, followed by the dynamics identifier
+    /// (if there is one).
+    pub fn identifier(&self) -> String {
+        match &self.dynamics_identifier {
+            Some(dynamics) => {
+                format!("{} + {dynamics}", self.parameter_identifier)
+            }
+            None => self.parameter_identifier.clone(),
+        }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.costs.len()
+    }
+}
+
+// Pair ////////////////////////////////////////////////////////////////////////
+
+/// Pairwise comparison of the final costs of two groups.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pair {
+    /// Indices of the groups.
+    pub a: usize,
+    pub b: usize,
+
+    /// Mann-Whitney U test (the statistic is positive if the costs of `a`
+    /// tend to be larger).
+    pub test: TestResult,
+
+    /// Holm-adjusted p-value.
+    pub p_holm: f64,
+
+    /// Probability that a run of `a` has a larger cost than a run of `b`
+    /// (below 0.5: `a` tends to be better).
+    pub a12: f64,
+}
+
+impl Pair {
+    pub fn is_significant(&self, alpha: f64) -> bool {
+        self.p_holm < alpha
+    }
+
+    /// Index of the better group, if the difference is significant.
+    pub fn winner(&self, alpha: f64) -> Option<usize> {
+        match self.is_significant(alpha) {
+            true if self.a12 < 0.5 => Some(self.a),
+            true if self.a12 > 0.5 => Some(self.b),
+            _ => None,
+        }
+    }
+}
+
+// Comparison //////////////////////////////////////////////////////////////////
+
+/// Comparison of all groups (see the module documentation).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Comparison {
+    /// Objective value (see [`ObjectiveValue::to_usize`]) which counts as
+    /// success.
+    pub target: usize,
+
+    /// Significance level of the (Holm-adjusted) tests.
+    pub alpha: f64,
+
+    /// Groups in the order of their first report.
+    pub groups: Vec<Group>,
+
+    /// Pairwise comparisons of all groups.
+    pub pairs: Vec<Pair>,
+}
+
+impl Comparison {
+    /// Returns one row per group (`;`-separated).
+    pub fn groups_csv(&self) -> String {
+        let mut csv = String::from(
+            "group;runs;success_rate;cost_median;cost_iqr;\
+            time_to_target_ms_median;time_to_target_ms_iqr\n",
+        );
+
+        for group in &self.groups {
+            let _ = writeln!(
+                csv,
+                "{};{};{};{};{};{};{}",
+                group.identifier(),
+                group.runs(),
+                group.success_rate,
+                opt(group.cost.map(|q| q.median)),
+                opt(group.cost.map(|q| q.iqr())),
+                opt(group.time_to_target_ms.map(|q| q.median)),
+                opt(group.time_to_target_ms.map(|q| q.iqr())),
+            );
+        }
+
+        csv
+    }
+
+    /// Returns one row per pairwise comparison (`;`-separated).
+    pub fn pairs_csv(&self) -> String {
+        let mut csv = String::from(
+            "a;b;statistic;p_value;p_holm;a12;effect;significant\n",
+        );
+
+        for pair in &self.pairs {
+            let _ = writeln!(
+                csv,
+                "{};{};{};{};{};{};{};{}",
+                self.groups[pair.a].identifier(),
+                self.groups[pair.b].identifier(),
+                pair.test.statistic,
+                pair.test.p_value,
+                pair.p_holm,
+                pair.a12,
+                effect_magnitude(pair.a12),
+                pair.is_significant(self.alpha),
+            );
+        }
+
+        csv
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    /// Formats the comparison table: one line per group, followed by one line
+    /// per pairwise comparison (groups are referenced by their index).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>3}  {:>5}  {:>7}  {:>12}  {:>12}  {:>12}  {:>12}  group",
+            "#",
+            "runs",
+            "success",
+            "cost med",
+            "cost iqr",
+            "ttt med",
+            "ttt iqr",
+        )?;
+        for (i, group) in self.groups.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>3}  {:>5}  {:>6.1}%  {:>12}  {:>12}  {:>12}  {:>12}  {}",
+                i,
+                group.runs(),
+                group.success_rate * 100.,
+                opt(group.cost.map(|q| q.median)),
+                opt(group.cost.map(|q| q.iqr())),
+                opt(group.time_to_target_ms.map(|q| q.median)),
+                opt(group.time_to_target_ms.map(|q| q.iqr())),
+                group.identifier(),
+            )?;
+        }
+
+        writeln!(f)?;
+        write!(
+            f,
+            "{:>3}  {:>3}  {:>9}  {:>9}  {:>5}  {:<10}  winner",
+            "a", "b", "p", "p holm", "a12", "effect",
+        )?;
+        for pair in &self.pairs {
+            write!(
+                f,
+                "\n{:>3}  {:>3}  {:>9.2e}  {:>9.2e}  {:>5.2}  {:<10}  {}",
+                pair.a,
+                pair.b,
+                pair.test.p_value,
+                pair.p_holm,
+                pair.a12,
+                effect_magnitude(pair.a12),
+                opt(pair.winner(self.alpha)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Groups the reports and compares the groups (see the module
+/// documentation). A run is successful, if its best objective value reached
+/// the `target`.
+pub fn compare<Ov, Ctx, Ge>(
+    reports: &[Report<Ov, Ctx, Ge>],
+    target: usize,
+    alpha: f64,
+) -> Comparison
+where
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+{
+    // Group the reports by their identifiers
+    let mut groups: Vec<Group> = Vec::new();
+    let mut successes: Vec<usize> = Vec::new();
+    for report in reports {
+        let index = groups
+            .iter()
+            .position(|g| {
+                g.parameter_identifier == report.parameter_identifier
+                    && g.dynamics_identifier == report.dynamics_identifier
+            })
+            .unwrap_or_else(|| {
+                groups.push(Group {
+                    parameter_identifier: report.parameter_identifier.clone(),
+                    dynamics_identifier: report.dynamics_identifier.clone(),
+                    costs: Vec::new(),
+                    times_to_target_ms: Vec::new(),
+                    success_rate: 0.,
+                    cost: None,
+                    time_to_target_ms: None,
+                });
+                successes.push(0);
+                groups.len() - 1
+            });
+
+        let Some((_, best)) = report.population.first() else {
+            continue;
+        };
+        groups[index].costs.push(best.to_usize() as f64);
+
+        if best.to_usize() <= target {
+            successes[index] += 1;
+            let time = report
+                .time_to_target_ms(target)
+                .unwrap_or(report.time_to_best_ms);
+            groups[index].times_to_target_ms.push(time as f64);
+        }
+    }
+
+    for (group, successes) in groups.iter_mut().zip(successes) {
+        group.success_rate = successes as f64 / group.runs().max(1) as f64;
+        group.cost = Quartiles::of(&group.costs);
+        group.time_to_target_ms = Quartiles::of(&group.times_to_target_ms);
+    }
+
+    // Compare all pairs of groups
+    let mut pairs: Vec<Pair> = Vec::new();
+    for a in 0..groups.len() {
+        for b in a + 1..groups.len() {
+            let test = mann_whitney_u(&groups[a].costs, &groups[b].costs);
+            pairs.push(Pair {
+                a,
+                b,
+                test,
+                p_holm: test.p_value,
+                a12: vargha_delaney_a12(&groups[a].costs, &groups[b].costs),
+            });
+        }
+    }
+
+    let p_values: Vec<f64> = pairs.iter().map(|p| p.test.p_value).collect();
+    for (pair, p_holm) in pairs.iter_mut().zip(holm(&p_values)) {
+        pair.p_holm = p_holm;
+    }
+
+    Comparison { target, alpha, groups, pairs }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,102 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkId, Criterion,
+};
+use ga::{process::selection::AliasTable, rng::Seeder};
+use rand::{prelude::Distribution, Rng};
+
+// Reference Implementations ///////////////////////////////////////////////////
+
+/// Cumulative, normalized weights (the "roulette wheel").
+fn accumulate(weights: &[f64]) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+
+    let mut acc = 0.;
+    let mut roulette_wheel: Vec<f64> = weights
+        .iter()
+        .map(|w| {
+            acc += w / total;
+            acc
+        })
+        .collect();
+
+    *roulette_wheel.last_mut().unwrap() = 1.;
+    roulette_wheel
+}
+
+/// Previous implementation: linear scan of the roulette wheel per spin.
+fn linear_scan(
+    weights: &[f64],
+    amount: usize,
+    rng: &mut impl Rng,
+) -> Vec<usize> {
+    let roulette_wheel = accumulate(weights);
+    let interval = rand::distributions::Uniform::new_inclusive(0., 1.);
+
+    (0..amount)
+        .map(|_| {
+            let value = interval.sample(rng);
+            roulette_wheel.iter().position(|s| value <= *s).unwrap()
+        })
+        .collect()
+}
+
+/// Binary search of the roulette wheel per spin.
+fn binary_search(
+    weights: &[f64],
+    amount: usize,
+    rng: &mut impl Rng,
+) -> Vec<usize> {
+    let roulette_wheel = accumulate(weights);
+    let interval = rand::distributions::Uniform::new_inclusive(0., 1.);
+
+    (0..amount)
+        .map(|_| {
+            let value = interval.sample(rng);
+            roulette_wheel.partition_point(|s| *s < value)
+        })
+        .collect()
+}
+
+// Benchmarks //////////////////////////////////////////////////////////////////
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("roulette");
+
+    for n in [100, 1_000, 10_000] {
+        // Linear rank weights (selection pressure 2)
+        let weights: Vec<f64> = (0..n).map(|r| (n - r) as f64).collect();
+
+        group.bench_with_input(BenchmarkId::new("linear", n), &n, |b, n| {
+            let mut rng = Seeder::new(Some(0)).main();
+            b.iter(|| black_box(linear_scan(&weights, *n, &mut rng)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("binary", n), &n, |b, n| {
+            let mut rng = Seeder::new(Some(0)).main();
+            b.iter(|| black_box(binary_search(&weights, *n, &mut rng)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("alias", n), &n, |b, n| {
+            let mut rng = Seeder::new(Some(0)).main();
+            b.iter(|| {
+                let table = AliasTable::new(&weights);
+                black_box((0..*n).map(|_| table.sample(&mut rng)).count())
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("alias-par", n), &n, |b, n| {
+            let mut rng = Seeder::new(Some(0)).main();
+            b.iter(|| {
+                black_box(AliasTable::new(&weights).sample_many(*n, &mut rng))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+// Main ////////////////////////////////////////////////////////////////////////
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
+
+////////////////////////////////////////////////////////////////////////////////
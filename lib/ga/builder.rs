@@ -1,14 +1,15 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use std::marker::PhantomData;
-#[cfg(feature = "cache")]
-use hashbrown::HashMap;
 
 use crate::{
-    dynamics::{Dynamic, Dynamics}, encoding::{Context, Encoding, Genotype, ObjectiveValue, Phenotype}, operators::{Crossover, Mutation}, parameters::Parameters, process::{
+    dynamics::{Dynamic, Dynamics}, encoding::{Context, Encoding, Genotype, ObjectiveValue, Phenotype}, initializer::{self, Initializer}, operators::{adaptive::Portfolio, Crossover, LocalSearch, Mutation}, parameters::Parameters, process::{
         rejection::Rejection, replacement::Replacement, selection::Selection, termination::Termination
-    }, Algorithm
+    }, observer::Observer, Algorithm
 };
 
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+
 // #[cfg(feature = "rerun_logger")]
 use crate::tools::rerun_logger::CustomLogger;
 
@@ -39,6 +40,14 @@ pub struct Builder<
     parameters: TsPa,
     dynamics: TsDy,
     custom_logger: TsCl,
+    observers: Vec<Box<dyn Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>>>,
+    local_search: Option<Box<dyn LocalSearch<Ov, Ctx, Ge>>>,
+    operator_portfolio: Option<Portfolio<Cr, Mu>>,
+    initializer: Box<dyn Initializer<Ctx, Ge>>,
+    seed_chromosomes: Vec<Ge>,
+
+    #[cfg(feature = "cache")]
+    cache: Cache<Ge, Ov>,
 
     // PhantomData
     objective_value: PhantomData<Ov>,
@@ -82,6 +91,14 @@ impl<
             parameters: (),
             dynamics: (),
             custom_logger: (),
+            observers: vec![],
+            local_search: None,
+            operator_portfolio: None,
+            initializer: Box::new(initializer::Random),
+            seed_chromosomes: vec![],
+
+            #[cfg(feature = "cache")]
+            cache: Cache::default(),
 
             // PhantomData
             objective_value: PhantomData,
@@ -149,26 +166,113 @@ impl<
     W_CustomLogger<Ov, Ctx, Ge, Cl>
 > {
     pub fn build(
-        self
+        #[allow(unused_mut)] mut self
     ) -> Algorithm<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl> {
+        // Print progress to stdout, if no other observers are added.
+        #[cfg(not(feature = "no_stdout_log"))]
+        if self.observers.is_empty() {
+            self.observers.push(Box::new(crate::observer::Console));
+        }
+
         Algorithm {
             encoding: self.encoding.0,
             params: self.parameters.0,
             dynamics: self.dynamics.0,
             custom_logger: self.custom_logger.0,
+            observers: self.observers,
+            local_search: self.local_search,
+            operator_portfolio: self.operator_portfolio,
+            initializer: self.initializer,
+            seed_chromosomes: self.seed_chromosomes,
+            state: None,
 
             #[cfg(feature = "cache")]
-            cache: HashMap::<Ge, Ov>::new(),
+            cache: self.cache,
 
             #[cfg(feature = "rerun_logger")]
             rerun_logger: crate::tools::rerun_logger::RerunLogger::connect("ga"),
+        }
+    }
+}
 
-            #[cfg(feature = "log_runtimes")]
-            runtime_reference: std::time::Instant::now(),
+// add_observer ----------------------------------------------------------------
+impl<
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Ph: Phenotype<Ov, Ctx, Ge>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T: From<Ov>,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+    //
+    TsEn: TS_Encoding,
+    TsPa: TS_Parameters,
+    TsDy: TS_Dynamics,
+    TsCl: TS_CustomLogger,
+> Builder<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, TsEn, TsPa, TsDy, TsCl> {
+    /// Adds an observer (see [`crate::observer`]). This method can be called
+    /// multiple times to add several observers.
+    pub fn add_observer<
+        Ob: Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> + 'static
+    >(
+        mut self,
+        observer: Ob,
+    ) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
 
-            #[cfg(feature = "log_runtimes")]
-            runtimes: vec![],
-        }
+    /// Sets the local search operator (see
+    /// [`crate::operators::local_search`]). It is only executed, if the
+    /// parameters define a local search policy.
+    pub fn set_local_search<Ls: LocalSearch<Ov, Ctx, Ge> + 'static>(
+        mut self,
+        local_search: Ls,
+    ) -> Self {
+        self.local_search = Some(Box::new(local_search));
+        self
+    }
+
+    /// Sets the portfolio of crossover and mutation operators, which are
+    /// chosen adaptively instead of the operators of the parameters (see
+    /// [`crate::operators::adaptive`]).
+    pub fn set_operator_portfolio(
+        mut self,
+        portfolio: Portfolio<Cr, Mu>,
+    ) -> Self {
+        self.operator_portfolio = Some(portfolio);
+        self
+    }
+
+    /// Sets the initializer, which creates the initial population (see
+    /// [`crate::initializer`]). By default, [`initializer::Random`] is used.
+    pub fn set_initializer<In: Initializer<Ctx, Ge> + 'static>(
+        mut self,
+        initializer: In,
+    ) -> Self {
+        self.initializer = Box::new(initializer);
+        self
+    }
+
+    /// Adds seed chromosomes (e.g. the best chromosomes of a previous run),
+    /// which are part of the initial population. This method can be called
+    /// multiple times. Seed chromosomes exceeding the population size are
+    /// ignored.
+    pub fn add_seed_chromosomes(mut self, chromosomes: Vec<Ge>) -> Self {
+        self.seed_chromosomes.extend(chromosomes);
+        self
+    }
+
+    /// Sets the cache for objective values (see [`crate::cache`]). By default,
+    /// a cache with [`crate::cache::DEFAULT_CAPACITY`] is used.
+    #[cfg(feature = "cache")]
+    pub fn set_cache(mut self, cache: Cache<Ge, Ov>) -> Self {
+        self.cache = cache;
+        self
     }
 }
 
@@ -205,6 +309,14 @@ impl<
             parameters: self.parameters,
             dynamics: self.dynamics,
             custom_logger: self.custom_logger,
+            observers: self.observers,
+            local_search: self.local_search,
+            operator_portfolio: self.operator_portfolio,
+            initializer: self.initializer,
+            seed_chromosomes: self.seed_chromosomes,
+
+            #[cfg(feature = "cache")]
+            cache: self.cache,
 
             // PhantomData
             objective_value: PhantomData,
@@ -255,6 +367,14 @@ impl<
             parameters: parameters.into(),
             dynamics: self.dynamics,
             custom_logger: self.custom_logger,
+            observers: self.observers,
+            local_search: self.local_search,
+            operator_portfolio: self.operator_portfolio,
+            initializer: self.initializer,
+            seed_chromosomes: self.seed_chromosomes,
+
+            #[cfg(feature = "cache")]
+            cache: self.cache,
 
             // PhantomData
             objective_value: PhantomData,
@@ -300,6 +420,14 @@ impl<
             parameters: self.parameters,
             dynamics: dynamics.into(),
             custom_logger: self.custom_logger,
+            observers: self.observers,
+            local_search: self.local_search,
+            operator_portfolio: self.operator_portfolio,
+            initializer: self.initializer,
+            seed_chromosomes: self.seed_chromosomes,
+
+            #[cfg(feature = "cache")]
+            cache: self.cache,
 
             // PhantomData
             objective_value: PhantomData,
@@ -345,6 +473,14 @@ impl<
             parameters: self.parameters,
             dynamics: self.dynamics,
             custom_logger: custom_logger.into(),
+            observers: self.observers,
+            local_search: self.local_search,
+            operator_portfolio: self.operator_portfolio,
+            initializer: self.initializer,
+            seed_chromosomes: self.seed_chromosomes,
+
+            #[cfg(feature = "cache")]
+            cache: self.cache,
 
             // PhantomData
             objective_value: PhantomData,
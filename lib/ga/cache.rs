@@ -0,0 +1,221 @@
+//! Bounded cache for objective values, which is used by the algorithm if the
+//! `cache` feature is enabled. If the same chromosome is "discovered" multiple
+//! times, the cache allows skipping its evaluation.
+//!
+//! Entries are keyed by a 64-bit fingerprint of the genotype. By default, the
+//! genotype is stored alongside the objective value to verify cache hits
+//! (which guards against fingerprint collisions). Disabling verification
+//! reduces the memory usage of the cache to the fingerprint and the objective
+//! value of each entry.
+//!
+//! The number of entries is bounded by the capacity of the cache. If the cache
+//! is full, entries are evicted with the CLOCK algorithm (an approximation of
+//! "least recently used").
+//!
+//! Lookups ([`Cache::get`]) only need a shared reference, so the cache can be
+//! used concurrently (e.g. from within parallel iterators).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use hashbrown::HashMap;
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+// Constants ///////////////////////////////////////////////////////////////////
+/// Default capacity (number of entries) of the cache.
+pub const DEFAULT_CAPACITY: usize = 1 << 16;
+
+// Statistics //////////////////////////////////////////////////////////////////
+
+/// Statistics of the cache since the last call of [`Cache::take_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+
+    /// Number of entries currently stored in the cache.
+    pub size: usize,
+}
+
+// Cache ///////////////////////////////////////////////////////////////////////
+
+struct Entry<Ge, Ov> {
+    fingerprint: u64,
+    genotype: Option<Ge>,
+    objective_value: Ov,
+
+    /// Reference bit of the CLOCK algorithm.
+    referenced: AtomicBool,
+}
+
+pub struct Cache<Ge, Ov> {
+    capacity: usize,
+    verify: bool,
+
+    /// Maps fingerprints to indices of `entries`.
+    index: HashMap<u64, usize>,
+    entries: Vec<Entry<Ge, Ov>>,
+
+    /// Position of the CLOCK hand in `entries`.
+    hand: usize,
+
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    evictions: usize,
+}
+
+impl<Ge: Hash + Eq + Clone, Ov: Clone> Cache<Ge, Ov> {
+    /// Creates an empty cache, which stores at most `capacity` entries and
+    /// verifies cache hits.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            verify: true,
+            index: HashMap::new(),
+            entries: vec![],
+            hand: 0,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            evictions: 0,
+        }
+    }
+
+    /// Enables or disables the verification of cache hits. Without
+    /// verification, genotypes are not stored in the cache, and a fingerprint
+    /// collision results in a wrong objective value.
+    pub fn verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Returns the capacity of the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true`, if the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the cached objective value of the given genotype.
+    pub fn get(&self, genotype: &Ge) -> Option<Ov> {
+        let entry = self
+            .index
+            .get(&fingerprint(genotype))
+            .map(|i| &self.entries[*i])
+            .filter(|e| e.genotype.as_ref().is_none_or(|ge| ge == genotype));
+
+        match entry {
+            Some(entry) => {
+                entry.referenced.store(true, Ordering::Relaxed);
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(entry.objective_value.clone())
+            }
+
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Inserts the objective value of the given genotype. If the cache is
+    /// full, an entry is evicted.
+    pub fn insert(&mut self, genotype: &Ge, objective_value: &Ov) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let fingerprint = fingerprint(genotype);
+        let entry = Entry {
+            fingerprint,
+            genotype: self.verify.then(|| genotype.clone()),
+            objective_value: objective_value.clone(),
+            referenced: AtomicBool::new(false),
+        };
+
+        // Known fingerprint: replace the entry (it is either equal, or the
+        // genotypes collide).
+        if let Some(i) = self.index.get(&fingerprint) {
+            let entry_ref = &mut self.entries[*i];
+            if entry_ref.genotype.as_ref() != entry.genotype.as_ref() {
+                *entry_ref = entry;
+            }
+
+            entry_ref.referenced.store(true, Ordering::Relaxed);
+            return;
+        }
+
+        // Free space available
+        if self.entries.len() < self.capacity {
+            self.index.insert(fingerprint, self.entries.len());
+            self.entries.push(entry);
+            return;
+        }
+
+        // Advance the clock hand to the first entry, which was not referenced
+        // since the last pass, and evict it.
+        loop {
+            let candidate = &mut self.entries[self.hand];
+            if !*candidate.referenced.get_mut() {
+                break;
+            }
+
+            *candidate.referenced.get_mut() = false;
+            self.hand = (self.hand + 1) % self.capacity;
+        }
+
+        self.index.remove(&self.entries[self.hand].fingerprint);
+        self.index.insert(fingerprint, self.hand);
+        self.entries[self.hand] = entry;
+
+        self.hand = (self.hand + 1) % self.capacity;
+        self.evictions += 1;
+    }
+
+    /// Removes all entries from the cache and resets the statistics.
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.entries.clear();
+        self.hand = 0;
+        self.take_stats();
+    }
+
+    /// Returns the statistics since the last call of this method and resets
+    /// them.
+    pub fn take_stats(&mut self) -> CacheStats {
+        CacheStats {
+            hits: std::mem::take(self.hits.get_mut()),
+            misses: std::mem::take(self.misses.get_mut()),
+            evictions: std::mem::take(&mut self.evictions),
+            size: self.entries.len(),
+        }
+    }
+}
+
+impl<Ge: Hash + Eq + Clone, Ov: Clone> Default for Cache<Ge, Ov> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Calculates the 64-bit fingerprint of a genotype. The fingerprint does not
+/// depend on the process (unlike the randomly seeded default hasher of hash
+/// maps).
+pub fn fingerprint<Ge: Hash>(genotype: &Ge) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    genotype.hash(&mut hasher);
+    hasher.finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,335 @@
+//! Checkpoints allow long-running algorithm executions to be interrupted and
+//! resumed later on. A checkpoint is a snapshot of the complete state of an
+//! algorithm execution:
+//! -   the current population (incl. objective values),
+//! -   the runtime data,
+//! -   the current parameters (which might have been modified by dynamics),
+//! -   the report log,
+//! -   the state of the random number generator,
+//! -   the (dynamic) state of the context (see [`ContextState`]) and
+//! -   the internal state of the dynamics (see [`Dynamic::save_state`]).
+//!
+//! Resuming an algorithm from a checkpoint yields the same final report as an
+//! uninterrupted execution would have (except for the measured runtime and
+//! cache hits).
+//!
+//! Checkpoints are stored as JSON files and require the objective value,
+//! genotype, operators and processes of the algorithm to implement
+//! [`serde::Serialize`] and [`serde::Deserialize`].
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    dynamics::Dynamic,
+    encoding::{Context, Genotype, ObjectiveValue, Phenotype},
+    operators::{Crossover, Mutation},
+    parameters::Parameters,
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    report::{Report, ReportLog},
+    rng::{GaRng, Seeder},
+    runtime_data::RuntimeData,
+    state::State,
+    tools::rerun_logger::CustomLogger,
+    Algorithm,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{path::Path, time::Duration};
+
+// Error ///////////////////////////////////////////////////////////////////////
+/// Errors which can occur while writing or reading checkpoints (or reports,
+/// see [`Report::save`]).
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("IO Error: {0}")]
+    IO(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[error("Checkpoint mismatch: {0}")]
+    Mismatch(String),
+}
+
+// Context State ///////////////////////////////////////////////////////////////
+
+/// Contexts are usually initialized once and not modified afterwards. However,
+/// dynamics are allowed to modify the context (e.g. changing the standard
+/// deviation of a random number distribution). To resume an algorithm
+/// execution correctly, this dynamic part of the context must be stored in
+/// checkpoints as well.
+///
+/// Contexts which are not modified during the algorithm execution can simply
+/// use the default implementation of this trait:
+/// ```rust ,ignore
+/// impl ga::checkpoint::ContextState for Context {}
+/// ```
+pub trait ContextState {
+    /// Returns the dynamic state of the context.
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    /// Restores the dynamic state of the context, which was previously
+    /// returned by [`ContextState::save_state`]. Returns an error if the state
+    /// doesn't match the context.
+    fn restore_state(
+        &mut self,
+        _state: serde_json::Value,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+// Checkpoint //////////////////////////////////////////////////////////////////
+
+/// Snapshot of an algorithm execution, as stored in a checkpoint file.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "Ov: DeserializeOwned, Ge: DeserializeOwned, \
+        Cr: DeserializeOwned, Mu: DeserializeOwned, Se: DeserializeOwned, \
+        Re: DeserializeOwned, Rp: DeserializeOwned, Te: DeserializeOwned"))]
+pub struct Checkpoint<
+    Ov: ObjectiveValue + Into<T>,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+> {
+    pub seed: u64,
+    pub rng: GaRng,
+
+    pub population: Vec<(Ge, Ov)>,
+    pub runtime_data: RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    pub parameters: Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    pub log: Vec<ReportLog>,
+    pub context: serde_json::Value,
+
+    /// Internal states of the dynamics (in the order of the dynamics).
+    #[serde(default)]
+    pub dynamics: Vec<serde_json::Value>,
+
+    pub parameter_identifier: String,
+    pub dynamics_identifier: Option<String>,
+
+    /// Runtime of the algorithm until the checkpoint was written.
+    pub runtime: Duration,
+}
+
+impl<
+        Ov: ObjectiveValue + Into<T> + DeserializeOwned,
+        Ctx: Context,
+        Ge: Genotype<Ctx> + DeserializeOwned,
+        Cr: Crossover<Ctx, Ge> + DeserializeOwned,
+        Mu: Mutation<Ctx, Ge> + DeserializeOwned,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T> + DeserializeOwned,
+        Re: Rejection<Ov, Ctx, Ge> + DeserializeOwned,
+        Rp: Replacement<(Ge, Ov)> + DeserializeOwned,
+        Te: Termination<Ov> + DeserializeOwned,
+    > Checkpoint<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>
+{
+    /// Reads a checkpoint from the given file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = std::fs::File::open(path)?;
+        let reader = std::io::BufReader::new(file);
+
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+/// Borrowed counterpart of [`Checkpoint`], which is used for writing
+/// checkpoints without cloning the state of the algorithm execution.
+#[derive(Serialize)]
+#[serde(bound(serialize = "Ov: Serialize, Ge: Serialize, Cr: Serialize, \
+        Mu: Serialize, Se: Serialize, Re: Serialize, Rp: Serialize, \
+        Te: Serialize"))]
+struct CheckpointRef<
+    'a,
+    Ov: ObjectiveValue + Into<T>,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+> {
+    seed: u64,
+    rng: &'a GaRng,
+
+    population: &'a [(Ge, Ov)],
+    runtime_data: &'a RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    parameters: &'a Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    log: &'a [ReportLog],
+    context: serde_json::Value,
+    dynamics: Vec<serde_json::Value>,
+
+    parameter_identifier: &'a str,
+    dynamics_identifier: Option<&'a str>,
+
+    runtime: Duration,
+}
+
+// Algorithm ///////////////////////////////////////////////////////////////////
+
+impl<
+        Ov: ObjectiveValue + Serialize + DeserializeOwned,
+        Ctx: Context + ContextState,
+        Ge: Genotype<Ctx> + Serialize + DeserializeOwned,
+        Ph: Phenotype<Ov, Ctx, Ge>,
+        Cr: Crossover<Ctx, Ge> + Serialize + DeserializeOwned,
+        Mu: Mutation<Ctx, Ge> + Serialize + DeserializeOwned,
+        T: From<Ov>,
+        Se: Selection<Ov, Ctx, Ge, T> + Serialize + DeserializeOwned,
+        Re: Rejection<Ov, Ctx, Ge> + Serialize + DeserializeOwned,
+        Rp: Replacement<(Ge, Ov)> + Serialize + DeserializeOwned,
+        Te: Termination<Ov> + Serialize + DeserializeOwned,
+        Dy: Dynamic<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        Cl: CustomLogger<Ov, Ctx, Ge>,
+    > Algorithm<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl>
+{
+    /// Executes the algorithm like [`Algorithm::run`] does, but additionally
+    /// writes a checkpoint to `path` every `interval` generations. An existing
+    /// checkpoint file is overwritten.
+    pub fn run_with_checkpoints(
+        mut self,
+        path: impl AsRef<Path>,
+        interval: usize,
+    ) -> Result<Report<Ov, Ctx, Ge>, Error> {
+        self.init();
+        self.run_checkpointed(path.as_ref(), interval)
+    }
+
+    /// Resumes the algorithm execution from the checkpoint stored at `path`
+    /// and continues writing checkpoints to this file every `interval`
+    /// generations.
+    ///
+    /// The algorithm must be built with the same encoding and dynamics as the
+    /// interrupted one. Its parameters are replaced by the parameters stored
+    /// in the checkpoint.
+    pub fn resume(
+        mut self,
+        path: impl AsRef<Path>,
+        interval: usize,
+    ) -> Result<Report<Ov, Ctx, Ge>, Error> {
+        self.init_from_checkpoint(path.as_ref())?;
+        self.run_checkpointed(path.as_ref(), interval)
+    }
+
+    fn run_checkpointed(
+        mut self,
+        path: &Path,
+        interval: usize,
+    ) -> Result<Report<Ov, Ctx, Ge>, Error> {
+        while !self.is_terminated() {
+            self.step();
+
+            if interval > 0
+                && self.state().generation().is_multiple_of(interval)
+            {
+                self.save_checkpoint(path)?;
+            }
+        }
+
+        Ok(self.finish())
+    }
+
+    /// Initializes the algorithm execution with the state stored in the
+    /// checkpoint at `path` (instead of creating an initial population like
+    /// [`Algorithm::init`] does). Afterwards, the execution can be continued
+    /// with [`Algorithm::step`].
+    pub fn init_from_checkpoint(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let checkpoint: Checkpoint<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> =
+            Checkpoint::load(path)?;
+
+        self.params = checkpoint.parameters;
+        self.encoding.context.restore_state(checkpoint.context)?;
+
+        let list = self.dynamics.as_ref().map_or(&[][..], |d| &d.list[..]);
+        if checkpoint.dynamics.len() != list.len() {
+            return Err(Error::Mismatch(format!(
+                "the checkpoint contains {} dynamics, but {} are configured",
+                checkpoint.dynamics.len(),
+                list.len()
+            )));
+        }
+        for (dyn_exe, state) in list.iter().zip(checkpoint.dynamics) {
+            dyn_exe.restore_state(state)?;
+        }
+
+        // Re-populate cache
+        #[cfg(feature = "cache")]
+        {
+            self.cache.clear();
+            checkpoint.population.iter().for_each(|(ge, ov)| {
+                self.cache.insert(ge, ov);
+            });
+        };
+
+        self.state = Some(State {
+            population: checkpoint.population,
+            rtd: checkpoint.runtime_data,
+            report_log: checkpoint.log,
+            seeder: Seeder::new(Some(checkpoint.seed)),
+            rng: checkpoint.rng,
+            parameter_identifier: checkpoint.parameter_identifier,
+            dynamics_identifier: checkpoint.dynamics_identifier,
+            start: std::time::Instant::now(),
+            elapsed: checkpoint.runtime,
+        });
+
+        Ok(())
+    }
+
+    /// Writes a checkpoint of the current state. The checkpoint is written to
+    /// a temporary file first, which then replaces the file at `path`. This
+    /// ensures that an existing checkpoint is not lost, if the process is
+    /// killed while writing.
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub fn save_checkpoint(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let state = self.state();
+
+        let checkpoint = CheckpointRef {
+            seed: state.seeder.seed(),
+            rng: &state.rng,
+            population: &state.population,
+            runtime_data: &state.rtd,
+            parameters: &self.params,
+            log: &state.report_log,
+            context: self.encoding.context.save_state(),
+            dynamics: self.dynamics.as_ref().map_or(vec![], |dynamics| {
+                dynamics.list.iter().map(|d| d.save_state()).collect()
+            }),
+            parameter_identifier: &state.parameter_identifier,
+            dynamics_identifier: state.dynamics_identifier.as_deref(),
+            runtime: state.runtime(),
+        };
+
+        let tmp = path.with_extension("tmp");
+        {
+            let file = std::fs::File::create(&tmp)?;
+            let mut writer = std::io::BufWriter::new(file);
+            serde_json::to_writer(&mut writer, &checkpoint)?;
+            std::io::Write::flush(&mut writer)?;
+        }
+        std::fs::rename(tmp, path)?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -1,12 +1,17 @@
 // Modules /////////////////////////////////////////////////////////////////////
 #[rustfmt::skip] mod builder;
 pub use builder::*;
+mod standard;
+pub use standard::*;
+mod state_machine;
+pub use state_machine::*;
 
 // Imports /////////////////////////////////////////////////////////////////////
 use crate::{
     encoding::{Context, Genotype, ObjectiveValue},
     operators::{Crossover, Mutation},
     parameters::Parameters,
+    rng::GaRng,
     runtime_data::RuntimeData,
 };
 
@@ -22,6 +27,8 @@ use crate::tools::rerun_logger::RerunLogger;
 
 /// This trait is usually implemented by enums, which represent a set of
 /// self-parameterization methods.
+/// Dynamics which make random decisions must draw their random numbers from
+/// the passed `rng`, to keep algorithm executions reproducible.
 pub trait Dynamic<
     Ov: ObjectiveValue + Into<T>,
     Ctx: Context,
@@ -41,8 +48,12 @@ pub trait Dynamic<
         rtd: &mut RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
         parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
         context: &mut Ctx,
+        rng: &mut GaRng,
     );
 
+    /// Executes the dynamic and returns whether it modified the parameters
+    /// or the context (observers are notified with
+    /// [`Event::Dynamic`](crate::observer::Event::Dynamic) in this case).
     fn exec(
         &self,
         // Input
@@ -51,10 +62,39 @@ pub trait Dynamic<
         // "Output"
         parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
         context: &mut Ctx,
+        rng: &mut GaRng,
 
         // Logger
         #[cfg(feature = "log_dynamics")] rerun_logger: &RerunLogger,
-    );
+    ) -> bool;
+
+    /// Returns (and clears) the events of the last execution (e.g. the
+    /// transitions of a [`StateMachine`]), which are recorded in the report
+    /// log of the current generation.
+    fn take_events(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the internal state of the dynamic (e.g. the current state of a
+    /// [`StateMachine`]), which is stored in checkpoints (see
+    /// [`crate::checkpoint`]). Dynamics without internal state can use the
+    /// default implementation.
+    #[cfg(feature = "serde")]
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    /// Restores the internal state of the dynamic, which was previously
+    /// returned by [`Dynamic::save_state`]. On resume, it is called instead
+    /// of [`Dynamic::setup`]. Returns an error if the state doesn't match the
+    /// dynamic.
+    #[cfg(feature = "serde")]
+    fn restore_state(
+        &self,
+        _state: serde_json::Value,
+    ) -> Result<(), crate::checkpoint::Error> {
+        Ok(())
+    }
 
     fn identifier(&self) -> String;
 }
@@ -90,6 +130,7 @@ impl<
         _rtd: &mut RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
         _parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
         _context: &mut Ctx,
+        _rng: &mut GaRng,
     ) {
     }
 
@@ -102,10 +143,12 @@ impl<
         // "Output"
         _parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
         _context: &mut Ctx,
+        _rng: &mut GaRng,
 
         // Logger
         #[cfg(feature = "log_dynamics")] _rerun_logger: &RerunLogger,
-    ) {
+    ) -> bool {
+        false
     }
 
     fn identifier(&self) -> String {
@@ -0,0 +1,372 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use super::Dynamic;
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::{Crossover, Mutation},
+    parameters::Parameters,
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    rng::GaRng,
+    runtime_data::RuntimeData,
+};
+
+#[cfg(feature = "log_dynamics")]
+use crate::tools::rerun_logger::RerunLogger;
+
+// Parameter ///////////////////////////////////////////////////////////////////
+
+/// Parameter, which is controlled by a [`Standard::PiecewiseLinear`]
+/// schedule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Parameter {
+    MutationRate,
+    CrossoverRate,
+
+    /// See [`Selection::pressure`].
+    SelectionPressure,
+}
+
+impl Parameter {
+    fn identifier(&self) -> &'static str {
+        match self {
+            Self::MutationRate => "mut-rate",
+            Self::CrossoverRate => "cx-rate",
+            Self::SelectionPressure => "sel-pressure",
+        }
+    }
+}
+
+// Standard ////////////////////////////////////////////////////////////////////
+
+/// Generic dynamics, which only modify the [`Parameters`] based on the
+/// [`RuntimeData`]. Therefore, they can be used with any encoding and any
+/// operators. Algorithms with their own dynamics can compose them by adding a
+/// variant which wraps `Standard` and delegates to it:
+/// ```rust ,ignore
+/// enum Dynamic {
+///     Standard(ga::dynamics::Standard),
+///     // ... algorithm-specific dynamics
+/// }
+/// ```
+/// Selection pressures are only modified, if the selection method supports
+/// it (see [`Selection::set_pressure`]).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Standard {
+    /// Mutation rate in form of a cosine function of the generation:
+    /// `base + amplitude * cos(2 pi generation / wavelength)`, optionally
+    /// clamped to `bounds` (min, max).
+    MutRateCos {
+        base: f32,
+        amplitude: f32,
+        wavelength: usize,
+        bounds: Option<(f32, f32)>,
+    },
+
+    /// Mutation rate in form of a sine function of the generation (see
+    /// [`Standard::MutRateCos`]).
+    MutRateSin {
+        base: f32,
+        amplitude: f32,
+        wavelength: usize,
+        bounds: Option<(f32, f32)>,
+    },
+
+    /// Success rule (1/5th success rule for a `target` of 0.2): the mutation
+    /// rate is multiplied by `factor` (> 1), if the success rate
+    /// ([`RuntimeData::success_rate_pt1`]) is above the target, and divided
+    /// by it, if the success rate is below the target.
+    SuccessRule { target: f32, factor: f32, bounds: (f32, f32) },
+
+    /// Linear ramp of the selection pressure from `from` (at generation 0) to
+    /// `to` (at the given generation and afterwards).
+    PressureRamp { from: f64, to: f64, generations: usize },
+
+    /// Increases the selection pressure by `step` every `interval`
+    /// generations without improvement of the best solution (up to `max`).
+    /// After `reset` generations without improvement at the maximum, the
+    /// pressure is reset to `initial`. The tournament size is rounded, so the
+    /// step must be at least 1 for tournament selection.
+    PressureOnStagnation {
+        initial: f64,
+        interval: usize,
+        step: f64,
+        max: f64,
+        reset: usize,
+    },
+
+    /// Proportional controller for the objective value diversity of the
+    /// population ([`RuntimeData::diversity`], 0 to 1): the mutation rate is
+    /// multiplied by `exp(gain * (target - diversity))`.
+    TargetDiversity { target: f64, gain: f64, bounds: (f32, f32) },
+
+    /// Piecewise-linear schedule of a parameter. The points (generation,
+    /// value) must be sorted by generation. Before the first and after the
+    /// last point, the value of the first and last point is used.
+    PiecewiseLinear { parameter: Parameter, points: Vec<(usize, f64)> },
+}
+
+impl Standard {
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::MutRateCos { base, amplitude, wavelength, bounds } => {
+                format!("mut-rate-cos-{base}-{amplitude}-{wavelength}-{bounds:?}")
+            }
+            Self::MutRateSin { base, amplitude, wavelength, bounds } => {
+                format!("mut-rate-sin-{base}-{amplitude}-{wavelength}-{bounds:?}")
+            }
+            Self::SuccessRule { target, factor, bounds } => {
+                format!("success-rule-{target}-{factor}-{bounds:?}")
+            }
+            Self::PressureRamp { from, to, generations } => {
+                format!("sel-pressure-ramp-{from}-{to}-{generations}")
+            }
+            Self::PressureOnStagnation {
+                initial,
+                interval,
+                step,
+                max,
+                reset,
+            } => format!(
+                "sel-pressure-stagnation-{initial}-{interval}-{step}-{max}-{reset}"
+            ),
+            Self::TargetDiversity { target, gain, bounds } => {
+                format!("target-diversity-{target}-{gain}-{bounds:?}")
+            }
+            Self::PiecewiseLinear { parameter, points } => {
+                let points: Vec<String> =
+                    points.iter().map(|(g, v)| format!("{g}:{v}")).collect();
+                format!("pwl-{}-{}", parameter.identifier(), points.join("-"))
+            }
+        }
+    }
+
+    /// Value of a piecewise-linear schedule in the given generation.
+    fn interpolate(points: &[(usize, f64)], generation: usize) -> Option<f64> {
+        let after = points.iter().position(|(g, _)| *g > generation);
+
+        match after {
+            Some(0) => points.first().map(|(_, v)| *v),
+            Some(i) => {
+                let (g0, v0) = points[i - 1];
+                let (g1, v1) = points[i];
+                let t = (generation - g0) as f64 / (g1 - g0) as f64;
+                Some(v0 + t * (v1 - v0))
+            }
+            None => points.last().map(|(_, v)| *v),
+        }
+    }
+
+    /// Value of a cosine or sine schedule in the given generation.
+    fn wave(
+        f: fn(f32) -> f32,
+        base: f32,
+        amplitude: f32,
+        wavelength: usize,
+        bounds: Option<(f32, f32)>,
+        generation: usize,
+    ) -> f32 {
+        let wavelength = 2. * std::f32::consts::PI / (wavelength as f32);
+        let result = amplitude * f(wavelength * generation as f32) + base;
+
+        match bounds {
+            Some((min, max)) => result.clamp(min, max),
+            None => result,
+        }
+    }
+}
+
+impl<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+    > Dynamic<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> for Standard
+{
+    fn setup(
+        &self,
+        // Output
+        _rtd: &mut RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _context: &mut Ctx,
+        _rng: &mut GaRng,
+    ) {
+        match self {
+            Self::MutRateCos { base, .. } | Self::MutRateSin { base, .. } => {
+                parameters.mutation_rate = *base;
+            }
+            Self::PressureRamp { from: initial, .. }
+            | Self::PressureOnStagnation { initial, .. } => {
+                parameters.selection.set_pressure(*initial);
+            }
+            Self::PiecewiseLinear { parameter, points } => {
+                if let Some(value) = Self::interpolate(points, 0) {
+                    apply(parameters, *parameter, value);
+                }
+            }
+            Self::SuccessRule { .. } | Self::TargetDiversity { .. } => {}
+        }
+    }
+
+    fn exec(
+        &self,
+        // Input
+        rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+
+        // "Output"
+        parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _context: &mut Ctx,
+        _rng: &mut GaRng,
+
+        // Logger
+        #[cfg(feature = "log_dynamics")] rerun_logger: &RerunLogger,
+    ) -> bool {
+        let before = (
+            parameters.mutation_rate,
+            parameters.crossover_rate,
+            parameters.selection.pressure(),
+        );
+
+        match self {
+            Self::MutRateCos { base, amplitude, wavelength, bounds } => {
+                parameters.mutation_rate = Self::wave(
+                    f32::cos,
+                    *base,
+                    *amplitude,
+                    *wavelength,
+                    *bounds,
+                    rtd.generation,
+                );
+            }
+
+            Self::MutRateSin { base, amplitude, wavelength, bounds } => {
+                parameters.mutation_rate = Self::wave(
+                    f32::sin,
+                    *base,
+                    *amplitude,
+                    *wavelength,
+                    *bounds,
+                    rtd.generation,
+                );
+            }
+
+            Self::SuccessRule { target, factor, bounds: (min, max) } => {
+                let rate = &mut parameters.mutation_rate;
+                if rtd.success_rate_pt1 > *target {
+                    *rate *= factor;
+                } else if rtd.success_rate_pt1 < *target {
+                    *rate /= factor;
+                }
+                *rate = rate.clamp(*min, *max);
+            }
+
+            Self::PressureRamp { from, to, generations } => {
+                let t = match generations {
+                    0 => 1.,
+                    g => (rtd.generation as f64 / *g as f64).min(1.),
+                };
+                parameters.selection.set_pressure(from + t * (to - from));
+            }
+
+            Self::PressureOnStagnation {
+                initial,
+                interval,
+                step,
+                max,
+                reset,
+            } => {
+                if let Some(mut pressure) = parameters.selection.pressure() {
+                    // Generations without improvement
+                    let no_improv = rtd.generation - rtd.last_success;
+
+                    if no_improv != 0
+                        && no_improv.is_multiple_of((*interval).max(1))
+                        && pressure <= max - 0.001
+                    {
+                        pressure += step;
+                    }
+
+                    if pressure >= max - 0.001 && no_improv > *reset {
+                        pressure = *initial;
+                    }
+
+                    parameters.selection.set_pressure(pressure);
+                }
+            }
+
+            Self::TargetDiversity { target, gain, bounds: (min, max) } => {
+                let factor = (gain * (target - rtd.diversity)).exp() as f32;
+                parameters.mutation_rate =
+                    (parameters.mutation_rate * factor).clamp(*min, *max);
+            }
+
+            Self::PiecewiseLinear { parameter, points } => {
+                if let Some(value) = Self::interpolate(points, rtd.generation) {
+                    apply(parameters, *parameter, value);
+                }
+            }
+        }
+
+        #[cfg(feature = "log_dynamics")]
+        {
+            rerun_logger
+                .log_mutation_rate(rtd.generation, parameters.mutation_rate);
+
+            if let Some(pressure) = parameters.selection.pressure() {
+                rerun_logger.log_selection_pressure(rtd.generation, pressure);
+            }
+        };
+
+        before
+            != (
+                parameters.mutation_rate,
+                parameters.crossover_rate,
+                parameters.selection.pressure(),
+            )
+    }
+
+    fn identifier(&self) -> String {
+        Standard::identifier(self)
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Sets the value of a parameter.
+fn apply<
+    Ov: ObjectiveValue + Into<T>,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+>(
+    parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    parameter: Parameter,
+    value: f64,
+) {
+    match parameter {
+        Parameter::MutationRate => parameters.mutation_rate = value as f32,
+        Parameter::CrossoverRate => {
+            parameters.crossover_rate = Some(value as f32)
+        }
+        Parameter::SelectionPressure => {
+            parameters.selection.set_pressure(value);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
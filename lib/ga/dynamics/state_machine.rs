@@ -0,0 +1,524 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use super::Dynamic;
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::{Crossover, Mutation},
+    parameters::Parameters,
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    rng::GaRng,
+    runtime_data::RuntimeData,
+};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[cfg(feature = "log_dynamics")]
+use crate::tools::rerun_logger::RerunLogger;
+
+// Overrides ///////////////////////////////////////////////////////////////////
+
+/// Parameters, which are set when a state is entered (`None` keeps the
+/// current value).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Overrides<Se, Cr, Mu, Rp> {
+    pub mutation_rate: Option<f32>,
+    pub crossover_rate: Option<f32>,
+
+    pub selection: Option<Se>,
+    pub crossover: Option<Cr>,
+    pub mutation: Option<Mu>,
+    pub replacement: Option<Rp>,
+
+    /// Applied after the selection method (see [`Selection::set_pressure`]).
+    pub selection_pressure: Option<f64>,
+}
+
+impl<Se, Cr, Mu, Rp> Default for Overrides<Se, Cr, Mu, Rp> {
+    fn default() -> Self {
+        Self {
+            mutation_rate: None,
+            crossover_rate: None,
+            selection: None,
+            crossover: None,
+            mutation: None,
+            replacement: None,
+            selection_pressure: None,
+        }
+    }
+}
+
+impl<Se, Cr, Mu, Rp> Overrides<Se, Cr, Mu, Rp> {
+    fn identifier<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        T,
+    >(
+        &self,
+    ) -> String
+    where
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+    {
+        let mut values = Vec::new();
+        if let Some(x) = self.mutation_rate {
+            values.push(format!("mr={x}"));
+        }
+        if let Some(x) = self.crossover_rate {
+            values.push(format!("cr={x}"));
+        }
+        if let Some(x) = &self.selection {
+            values.push(format!("se={}", x.identifier()));
+        }
+        if let Some(x) = &self.crossover {
+            values.push(format!("cx={}", x.identifier()));
+        }
+        if let Some(x) = &self.mutation {
+            values.push(format!("mu={}", x.identifier()));
+        }
+        if let Some(x) = &self.replacement {
+            values.push(format!("rp={}", x.identifier()));
+        }
+        if let Some(x) = self.selection_pressure {
+            values.push(format!("sp={x}"));
+        }
+        values.join(",")
+    }
+
+    fn apply<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        T,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Te: Termination<Ov>,
+    >(
+        &self,
+        parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    ) where
+        Se: Selection<Ov, Ctx, Ge, T> + Clone,
+        Cr: Crossover<Ctx, Ge> + Clone,
+        Mu: Mutation<Ctx, Ge> + Clone,
+        Rp: Replacement<(Ge, Ov)> + Clone,
+    {
+        if let Some(rate) = self.mutation_rate {
+            parameters.mutation_rate = rate;
+        }
+        if let Some(rate) = self.crossover_rate {
+            parameters.crossover_rate = Some(rate);
+        }
+        if let Some(selection) = &self.selection {
+            parameters.selection = selection.clone();
+        }
+        if let Some(crossover) = &self.crossover {
+            parameters.crossover = crossover.clone();
+        }
+        if let Some(mutation) = &self.mutation {
+            parameters.mutation = mutation.clone();
+        }
+        if let Some(replacement) = &self.replacement {
+            parameters.replacement = replacement.clone();
+        }
+        if let Some(pressure) = self.selection_pressure {
+            parameters.selection.set_pressure(pressure);
+        }
+    }
+}
+
+// Condition ///////////////////////////////////////////////////////////////////
+
+/// Guard of a [`Transition`], which is evaluated over the [`RuntimeData`] at
+/// the end of every generation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Condition {
+    /// At least the given amount of generations in the current state.
+    GenerationsInState(usize),
+
+    /// The algorithm reached the given generation.
+    Generation(usize),
+
+    /// At least the given amount of generations without improvement of the
+    /// best solution (independent of the current state, combine it with
+    /// [`Condition::GenerationsInState`] to stay in a state for a while).
+    Stagnation(usize),
+
+    /// Objective value diversity ([`RuntimeData::diversity`], 0 to 1).
+    DiversityBelow(f64),
+    DiversityAbove(f64),
+
+    /// Success rate ([`RuntimeData::success_rate_pt1`]).
+    SuccessRateBelow(f32),
+    SuccessRateAbove(f32),
+
+    All(Vec<Condition>),
+    Any(Vec<Condition>),
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    fn holds(&self, observation: &Observation) -> bool {
+        match self {
+            Self::GenerationsInState(n) => observation.in_state >= *n,
+            Self::Generation(n) => observation.generation >= *n,
+            Self::Stagnation(n) => observation.stagnation >= *n,
+            Self::DiversityBelow(x) => observation.diversity < *x,
+            Self::DiversityAbove(x) => observation.diversity > *x,
+            Self::SuccessRateBelow(x) => observation.success_rate < *x,
+            Self::SuccessRateAbove(x) => observation.success_rate > *x,
+            Self::All(conditions) => {
+                conditions.iter().all(|c| c.holds(observation))
+            }
+            Self::Any(conditions) => {
+                conditions.iter().any(|c| c.holds(observation))
+            }
+            Self::Not(condition) => !condition.holds(observation),
+        }
+    }
+}
+
+/// Values of the [`RuntimeData`], which are used by the conditions.
+struct Observation {
+    generation: usize,
+    in_state: usize,
+    stagnation: usize,
+    diversity: f64,
+    success_rate: f32,
+}
+
+// State & Transition //////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State<Se, Cr, Mu, Rp> {
+    pub name: String,
+
+    #[cfg_attr(feature = "serde", serde(default = "Overrides::default"))]
+    pub overrides: Overrides<Se, Cr, Mu, Rp>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transition {
+    pub from: String,
+    pub to: String,
+    pub when: Condition,
+}
+
+/// Current state of the machine during an algorithm execution.
+#[derive(Debug, Default)]
+struct Status {
+    /// Index of the current state.
+    state: usize,
+
+    /// Generation in which the current state was entered.
+    entered: usize,
+
+    /// Transitions of the last execution (see [`Dynamic::take_events`]).
+    events: Vec<String>,
+}
+
+// StateMachine ////////////////////////////////////////////////////////////////
+
+/// Declarative state machine: every state carries parameter overrides, which
+/// are applied when the state is entered. The first state is the initial
+/// state. At the end of every generation, the transitions of the current state
+/// are checked in the order in which they were added and the first one whose
+/// condition holds is taken (at most one transition per generation). Every
+/// transition is recorded in the report log (see
+/// [`ReportLog::events`](crate::report::ReportLog::events)).
+///
+/// The machine can be built in code or deserialized from a config (with the
+/// `serde` feature):
+/// ```rust ,ignore
+/// let machine = StateMachine::new()
+///     .add_state("broad", Overrides {
+///         selection: Some(Select::LinearRank(1.4)),
+///         mutation_rate: Some(0.015),
+///         ..Overrides::default()
+///     })
+///     .add_state("focus", Overrides {
+///         selection: Some(Select::LinearRank(1.6)),
+///         mutation_rate: Some(0.01),
+///         ..Overrides::default()
+///     })
+///     .add_transition("broad", "focus", Condition::GenerationsInState(200))
+///     .add_transition("focus", "broad", Condition::All(vec![
+///         Condition::GenerationsInState(100),
+///         Condition::Stagnation(100),
+///     ]));
+/// ```
+/// The current state is not part of the configuration, it is reset by
+/// [`Dynamic::setup`] (and stored in checkpoints, see
+/// [`Dynamic::save_state`]).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateMachine<Se, Cr, Mu, Rp> {
+    pub states: Vec<State<Se, Cr, Mu, Rp>>,
+    pub transitions: Vec<Transition>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    status: Mutex<Status>,
+}
+
+impl<Se, Cr, Mu, Rp> Default for StateMachine<Se, Cr, Mu, Rp> {
+    fn default() -> Self {
+        Self {
+            states: Vec::new(),
+            transitions: Vec::new(),
+            status: Mutex::default(),
+        }
+    }
+}
+
+impl<Se: Clone, Cr: Clone, Mu: Clone, Rp: Clone> Clone
+    for StateMachine<Se, Cr, Mu, Rp>
+{
+    /// Clones the configuration (the clone starts in the initial state).
+    fn clone(&self) -> Self {
+        Self {
+            states: self.states.clone(),
+            transitions: self.transitions.clone(),
+            status: Mutex::default(),
+        }
+    }
+}
+
+impl<Se, Cr, Mu, Rp> StateMachine<Se, Cr, Mu, Rp> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a state (the first state is the initial state).
+    pub fn add_state(
+        mut self,
+        name: &str,
+        overrides: Overrides<Se, Cr, Mu, Rp>,
+    ) -> Self {
+        assert!(self.index(name).is_none(), "state {name} is already defined");
+
+        self.states.push(State { name: name.into(), overrides });
+        self
+    }
+
+    /// Adds a transition between two (already added) states.
+    pub fn add_transition(
+        mut self,
+        from: &str,
+        to: &str,
+        when: Condition,
+    ) -> Self {
+        for name in [from, to] {
+            assert!(self.index(name).is_some(), "unknown state {name}");
+        }
+
+        self.transitions.push(Transition {
+            from: from.into(),
+            to: to.into(),
+            when,
+        });
+        self
+    }
+
+    /// Name of the current state.
+    pub fn state(&self) -> &str {
+        &self.states[self.status().state].name
+    }
+
+    pub fn identifier<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        T,
+    >(
+        &self,
+    ) -> String
+    where
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+    {
+        let states: Vec<String> = self
+            .states
+            .iter()
+            .map(|s| {
+                let overrides = s.overrides.identifier::<Ov, Ctx, Ge, T>();
+                format!("{}({overrides})", s.name)
+            })
+            .collect();
+        let transitions: Vec<String> = self
+            .transitions
+            .iter()
+            .map(|t| format!("{}>{}:{:?}", t.from, t.to, t.when))
+            .collect();
+
+        format!("state-machine-{}-{}", states.join(","), transitions.join(","))
+    }
+
+    /// Returns (and clears) the transitions since the last call (see
+    /// [`Dynamic::take_events`]).
+    pub fn take_events(&self) -> Vec<String> {
+        std::mem::take(&mut self.status().events)
+    }
+
+    /// Returns the current state and the generation in which it was entered
+    /// (see [`Dynamic::save_state`]).
+    #[cfg(feature = "serde")]
+    pub fn save_state(&self) -> serde_json::Value {
+        let status = self.status();
+        serde_json::json!({ "state": status.state, "entered": status.entered })
+    }
+
+    /// Restores the state returned by [`StateMachine::save_state`]. Returns
+    /// an error if the state doesn't belong to this state machine.
+    #[cfg(feature = "serde")]
+    pub fn restore_state(
+        &self,
+        state: serde_json::Value,
+    ) -> Result<(), crate::checkpoint::Error> {
+        self.validate();
+
+        let field = |name: &str| {
+            state[name].as_u64().map(|x| x as usize).ok_or_else(|| {
+                crate::checkpoint::Error::Mismatch(format!(
+                    "state machine checkpoint without `{name}`: {state}"
+                ))
+            })
+        };
+        let (current, entered) = (field("state")?, field("entered")?);
+        if current >= self.states.len() {
+            return Err(crate::checkpoint::Error::Mismatch(format!(
+                "state machine checkpoint with unknown state {current}"
+            )));
+        }
+
+        *self.status() = Status { state: current, entered, events: vec![] };
+        Ok(())
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|s| s.name == name)
+    }
+
+    /// Checks the configuration (it can also be deserialized, so the checks
+    /// of the builder methods are not sufficient).
+    fn validate(&self) {
+        assert!(!self.states.is_empty(), "state machine without states");
+
+        for transition in &self.transitions {
+            for name in [&transition.from, &transition.to] {
+                assert!(self.index(name).is_some(), "unknown state {name}");
+            }
+        }
+    }
+
+    fn status(&self) -> MutexGuard<'_, Status> {
+        self.status.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge> + Clone,
+        Mu: Mutation<Ctx, Ge> + Clone,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T> + Clone,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)> + Clone,
+        Te: Termination<Ov>,
+    > Dynamic<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>
+    for StateMachine<Se, Cr, Mu, Rp>
+{
+    fn setup(
+        &self,
+        // Output
+        rtd: &mut RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _context: &mut Ctx,
+        _rng: &mut GaRng,
+    ) {
+        self.validate();
+
+        *self.status() =
+            Status { entered: rtd.generation, ..Status::default() };
+        self.states[0].overrides.apply(parameters);
+    }
+
+    fn exec(
+        &self,
+        // Input
+        rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+
+        // "Output"
+        parameters: &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _context: &mut Ctx,
+        _rng: &mut GaRng,
+
+        // Logger
+        #[cfg(feature = "log_dynamics")] rerun_logger: &RerunLogger,
+    ) -> bool {
+        let mut status = self.status();
+        let from = &self.states[status.state].name;
+
+        let observation = Observation {
+            generation: rtd.generation,
+            in_state: rtd.generation - status.entered,
+            stagnation: rtd.generation - rtd.last_success,
+            diversity: rtd.diversity,
+            success_rate: rtd.success_rate_pt1,
+        };
+
+        let transition = self
+            .transitions
+            .iter()
+            .find(|t| &t.from == from && t.when.holds(&observation));
+
+        if let Some(transition) = transition {
+            let to = self.index(&transition.to).expect("validated state");
+
+            status.state = to;
+            status.entered = rtd.generation;
+            status.events.push(format!("{from} -> {}", transition.to));
+            self.states[to].overrides.apply(parameters);
+
+            #[cfg(feature = "log_dynamics")]
+            {
+                rerun_logger.log_text(
+                    rtd.generation,
+                    &format!("{from} -> {}", transition.to),
+                );
+            };
+        }
+
+        transition.is_some()
+    }
+
+    fn take_events(&self) -> Vec<String> {
+        StateMachine::take_events(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn save_state(&self) -> serde_json::Value {
+        StateMachine::save_state(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(
+        &self,
+        state: serde_json::Value,
+    ) -> Result<(), crate::checkpoint::Error> {
+        StateMachine::restore_state(self, state)
+    }
+
+    fn identifier(&self) -> String {
+        StateMachine::identifier::<Ov, Ctx, Ge, T>(self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,90 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use super::ObjectiveValue;
+
+// Constants ///////////////////////////////////////////////////////////////////
+
+/// Weight of the infeasibility value in the scalar conversions of
+/// [`HardSoft`] (`infeasibility * HARD_WEIGHT + objective`). The conversions
+/// preserve the lexicographic order, as long as the objective value is below
+/// this weight.
+pub const HARD_WEIGHT: usize = 1_000_000;
+
+// Hard/Soft Objective Value ///////////////////////////////////////////////////
+
+/// Objective value, which consists of an infeasibility value (violations of
+/// the hard constraints) and an objective value (violations of the soft
+/// constraints). Both are minimized and compared lexicographically: a solution
+/// with a lower infeasibility value is always better, the objective value only
+/// decides between solutions with the same infeasibility value.
+///
+/// The selection methods, which use the objective values (not only their
+/// order), work on the scalar conversions (`usize` or `f64`, see
+/// [`HARD_WEIGHT`]). The runtime data contains the best and mean of both
+/// components (see [`ObjectiveValue::components`]), and the termination
+/// criterion can target each component (see
+/// [`Terminate::Components`](crate::process::termination::Terminate::Components)).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HardSoft {
+    pub infeasibility: usize,
+    pub objective: usize,
+}
+
+impl HardSoft {
+    pub fn new(infeasibility: usize, objective: usize) -> Self {
+        Self { infeasibility, objective }
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.infeasibility == 0
+    }
+}
+
+impl ObjectiveValue for HardSoft {
+    fn calc_average(values: &[Self]) -> f32 {
+        let sum: f32 = values.iter().map(|x| f64::from(*x) as f32).sum();
+        sum / values.len() as f32
+    }
+
+    /// Distribution of the infeasibility values (the scalar conversion would
+    /// be too sparse).
+    fn calc_distribution(values: &[Self]) -> Vec<usize> {
+        let max = values.iter().map(|x| x.infeasibility).max().unwrap_or(0);
+
+        let mut arr = vec![0; max + 1];
+        for val in values {
+            arr[val.infeasibility] += 1;
+        }
+
+        arr
+    }
+
+    /// Lossy scalar conversion (`infeasibility * HARD_WEIGHT + objective`).
+    fn to_usize(&self) -> usize {
+        usize::from(*self)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.infeasibility as f64, self.objective as f64]
+    }
+}
+
+impl From<HardSoft> for usize {
+    fn from(value: HardSoft) -> Self {
+        (value.infeasibility.saturating_mul(HARD_WEIGHT))
+            .saturating_add(value.objective)
+    }
+}
+
+impl From<HardSoft> for f64 {
+    fn from(value: HardSoft) -> Self {
+        value.infeasibility as f64 * HARD_WEIGHT as f64 + value.objective as f64
+    }
+}
+
+impl std::fmt::Display for HardSoft {
+    /// Formats the objective value as `(infeasibility, objective)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.infeasibility, self.objective)
+    }
+}
@@ -1,8 +1,11 @@
 // Modules /////////////////////////////////////////////////////////////////////
 #[rustfmt::skip] mod builder;
+mod hard_soft;
 pub use builder::*;
+pub use hard_soft::*;
 
 // Imports /////////////////////////////////////////////////////////////////////
+use crate::{rng::GaRng, self_adaptation::Strategy};
 use std::{fmt::Debug, hash::Hash};
 
 // Objective Value /////////////////////////////////////////////////////////////
@@ -34,6 +37,15 @@ pub trait ObjectiveValue:
     fn calc_distribution(values: &[Self]) -> Vec<usize>;
 
     fn to_usize(&self) -> usize;
+
+    /// Returns the components of a composite objective value (e.g. the
+    /// infeasibility and objective value of [`HardSoft`]), whose best and mean
+    /// values are part of the runtime data. Unlike [`ObjectiveValue::to_usize`]
+    /// the components are not combined into a single (lossy) number. By
+    /// default, an objective value has no components.
+    fn components(&self) -> Vec<f64> {
+        vec![]
+    }
 }
 
 // Context /////////////////////////////////////////////////////////////////////
@@ -65,7 +77,9 @@ pub trait Genotype<Ctx: Context>:
     /// The `ctx` parameter makes the custom context available to this function,
     /// which may contain pre-defined random value generators/distributions
     /// that help with generating lots of chromosomes.
-    fn generate(amount: usize, ctx: &Ctx) -> Vec<Self>;
+    /// The `rng` parameter is the source of randomness, which must be used
+    /// for all random decisions to keep algorithm executions reproducible.
+    fn generate(amount: usize, ctx: &Ctx, rng: &mut GaRng) -> Vec<Self>;
 
     /// This function calculates the diversity data of the population.
     /// Therefore equal chromosomes are grouped and counted.
@@ -76,6 +90,28 @@ pub trait Genotype<Ctx: Context>:
     fn calc_diversity<Ov: ObjectiveValue>(
         population: &[(Self, Ov)],
     ) -> Vec<usize>;
+
+    /// Returns the distance between two genotypes (e.g. the Hamming distance
+    /// of bit vectors), which is used for the genotype-level diversity
+    /// metrics of the runtime data. Implementing this method is optional; by
+    /// default no distance is defined (`None`) and only the number of unique
+    /// genotypes is calculated.
+    fn distance(&self, _other: &Self) -> Option<f64> {
+        None
+    }
+
+    /// Returns the strategy parameters of the individual, which are used for
+    /// the self-adaptation of the mutation (see [`crate::self_adaptation`]).
+    /// Implementing this method (and [`Genotype::strategy_mut`]) is optional;
+    /// by default the genotype carries no strategy parameters (`None`) and
+    /// the mutation rate of the parameters is used.
+    fn strategy(&self) -> Option<&Strategy> {
+        None
+    }
+
+    fn strategy_mut(&mut self) -> Option<&mut Strategy> {
+        None
+    }
 }
 
 // Phenotype ///////////////////////////////////////////////////////////////////
@@ -84,12 +120,49 @@ pub trait Genotype<Ctx: Context>:
 /// represent the phenotype of an encoding. The trait definition contains
 /// methods for deriving a phenotype from a given phenotype, as well as
 /// evaluating the phenotype.
+///
+/// The framework calls the optional methods [`Phenotype::evaluate_batch`] and
+/// [`Phenotype::evaluate_delta`] on the blueprint phenotype (the one of the
+/// encoding).
 pub trait Phenotype<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>:
     Clone + Debug + Send + Sync
 {
     fn derive(&self, chromosome: &Ge, ctx: &Ctx) -> Self;
 
     fn evaluate(&self, ctx: &Ctx) -> Ov;
+
+    /// Calculates the objective values of a batch of chromosomes. The batches
+    /// are evaluated in parallel (one batch per task), so implementations can
+    /// reuse scratch buffers within a batch (e.g. derive one phenotype and
+    /// overwrite it for every chromosome). By default, every chromosome is
+    /// derived and evaluated separately.
+    fn evaluate_batch(&self, chromosomes: &[Ge], ctx: &Ctx) -> Vec<Ov> {
+        chromosomes
+            .iter()
+            .map(|chromosome| self.derive(chromosome, ctx).evaluate(ctx))
+            .collect()
+    }
+
+    /// Incremental evaluation: calculates the objective value of `chromosome`,
+    /// which differs from `parent` (with the objective value `parent_ov`) only
+    /// in the genes with the given indices (see
+    /// [`Mutation::exec_tracked`](crate::operators::Mutation::exec_tracked)).
+    ///
+    /// By default, incremental evaluation is not supported (`None`) and the
+    /// chromosome is evaluated from scratch. Implementations may also return
+    /// `None` for single chromosomes, e.g. if a cost function is not additive.
+    /// The incremental evaluation is not used with the Baldwinian local search
+    /// (the objective value of a parent belongs to its improved chromosome).
+    fn evaluate_delta(
+        &self,
+        _parent: &Ge,
+        _parent_ov: &Ov,
+        _chromosome: &Ge,
+        _changed: &[usize],
+        _ctx: &Ctx,
+    ) -> Option<Ov> {
+        None
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,141 @@
+//! Strategies for creating the initial population.
+//!
+//! By default, the initial population is generated randomly with
+//! [`Genotype::generate`] (see [`Random`]). A custom [`Initializer`] can be set
+//! with [`Builder::set_initializer`](crate::Builder::set_initializer), e.g.
+//! [`Mix`], which combines heuristic and random chromosomes in set
+//! proportions. Additionally, seed chromosomes (e.g. the best chromosomes of a
+//! previous run) can be added with
+//! [`Builder::add_seed_chromosomes`](crate::Builder::add_seed_chromosomes).
+//! They take precedence over the initializer, which only creates the rest of
+//! the initial population.
+//!
+//! The number of chromosomes created by each source is stored in the runtime
+//! data and the report (`initial_sources`).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype},
+    rng::GaRng,
+};
+
+// Trait ///////////////////////////////////////////////////////////////////////
+
+/// This trait is implemented by strategies for creating the initial
+/// population.
+pub trait Initializer<Ctx: Context, Ge: Genotype<Ctx>>: Send + Sync {
+    /// Creates `amount` chromosomes, grouped by their source (name of the
+    /// source and its chromosomes). The total number of chromosomes must be
+    /// equal to `amount`.
+    fn exec(
+        &self,
+        amount: usize,
+        ctx: &Ctx,
+        rng: &mut GaRng,
+    ) -> Vec<(String, Vec<Ge>)>;
+
+    /// Identifier of the initializer (part of the parameter identifier).
+    fn identifier(&self) -> String;
+}
+
+// Random //////////////////////////////////////////////////////////////////////
+
+/// Creates all chromosomes with [`Genotype::generate`]. This is the default
+/// initializer.
+pub struct Random;
+
+impl<Ctx: Context, Ge: Genotype<Ctx>> Initializer<Ctx, Ge> for Random {
+    fn exec(
+        &self,
+        amount: usize,
+        ctx: &Ctx,
+        rng: &mut GaRng,
+    ) -> Vec<(String, Vec<Ge>)> {
+        vec![("random".into(), Ge::generate(amount, ctx, rng))]
+    }
+
+    fn identifier(&self) -> String {
+        "random".into()
+    }
+}
+
+// Mix /////////////////////////////////////////////////////////////////////////
+
+/// Function, which creates the given amount of chromosomes.
+pub type Generator<Ctx, Ge> =
+    Box<dyn Fn(usize, &Ctx, &mut GaRng) -> Vec<Ge> + Send + Sync>;
+
+/// Combines several (heuristic) sources of chromosomes with set proportions.
+/// The rest of the population is generated randomly with
+/// [`Genotype::generate`]. If a source creates fewer chromosomes than
+/// requested, the missing chromosomes are generated randomly as well.
+pub struct Mix<Ctx, Ge> {
+    sources: Vec<(String, f32, Generator<Ctx, Ge>)>,
+}
+
+impl<Ctx: Context, Ge: Genotype<Ctx>> Mix<Ctx, Ge> {
+    pub fn new() -> Self {
+        Self { sources: vec![] }
+    }
+
+    /// Adds a source with the given name, which creates the given proportion
+    /// (0, 1] of the chromosomes requested from this initializer (rounded
+    /// down). The proportions of all sources should not exceed 1; sources
+    /// which exceed it are truncated.
+    pub fn add(
+        mut self,
+        name: &str,
+        proportion: f32,
+        generator: impl Fn(usize, &Ctx, &mut GaRng) -> Vec<Ge>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.sources.push((name.into(), proportion, Box::new(generator)));
+        self
+    }
+}
+
+impl<Ctx: Context, Ge: Genotype<Ctx>> Default for Mix<Ctx, Ge> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ctx: Context, Ge: Genotype<Ctx>> Initializer<Ctx, Ge> for Mix<Ctx, Ge> {
+    fn exec(
+        &self,
+        amount: usize,
+        ctx: &Ctx,
+        rng: &mut GaRng,
+    ) -> Vec<(String, Vec<Ge>)> {
+        let mut groups: Vec<(String, Vec<Ge>)> = vec![];
+        let mut remaining = amount;
+
+        for (name, proportion, generator) in &self.sources {
+            let n = ((amount as f32 * proportion.max(0.)).floor() as usize)
+                .min(remaining);
+
+            let mut chromosomes = generator(n, ctx, rng);
+            chromosomes.truncate(n);
+
+            remaining -= chromosomes.len();
+            groups.push((name.clone(), chromosomes));
+        }
+
+        groups.push(("random".into(), Ge::generate(remaining, ctx, rng)));
+        groups
+    }
+
+    fn identifier(&self) -> String {
+        let sources: Vec<String> = self
+            .sources
+            .iter()
+            .map(|(name, proportion, _)| format!("{}-{:.2}", name, proportion))
+            .collect();
+
+        format!("mix[{}]", sources.join("|"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
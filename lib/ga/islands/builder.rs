@@ -0,0 +1,122 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    dynamics::Dynamic, encoding::{Context, Genotype, ObjectiveValue, Phenotype}, operators::{Crossover, Mutation}, process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection, termination::Termination
+    }, tools::rerun_logger::CustomLogger, Algorithm
+};
+
+use super::{Islands, Migration};
+
+// Typestates //////////////////////////////////////////////////////////////////
+
+// islands ---------------------------------------------------------------------
+#[allow(non_camel_case_types)] pub struct W_Islands<A>(Vec<A>);
+impl<A> From<Vec<A>> for W_Islands<A> {
+    fn from(value: Vec<A>) -> Self {
+        Self(value)
+    }
+}
+
+#[allow(non_camel_case_types)] pub trait TS_Islands {}
+impl TS_Islands for () {}
+impl<A> TS_Islands for W_Islands<A> {}
+
+// migration -------------------------------------------------------------------
+#[allow(non_camel_case_types)] pub struct W_Migration(Migration);
+impl From<Migration> for W_Migration {
+    fn from(value: Migration) -> Self {
+        Self(value)
+    }
+}
+
+#[allow(non_camel_case_types)] pub trait TS_Migration {}
+impl TS_Migration for () {}
+impl TS_Migration for W_Migration {}
+
+// Builder /////////////////////////////////////////////////////////////////////
+
+/// Island model builder.
+pub struct Builder<TsIs: TS_Islands, TsMi: TS_Migration> {
+    islands: TsIs,
+    migration: TsMi,
+
+    // Optional
+    seed: Option<u64>,
+}
+
+impl Builder<(), ()> {
+    pub fn new() -> Self {
+        Self { islands: (), migration: (), seed: None }
+    }
+}
+
+impl Default for Builder<(), ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// set_islands -----------------------------------------------------------------
+impl<TsMi: TS_Migration> Builder<(), TsMi> {
+    pub fn set_islands<A>(self, islands: Vec<A>) -> Builder<W_Islands<A>, TsMi> {
+        Builder {
+            islands: islands.into(),
+            migration: self.migration,
+            seed: self.seed,
+        }
+    }
+}
+
+// set_migration ---------------------------------------------------------------
+impl<TsIs: TS_Islands> Builder<TsIs, ()> {
+    pub fn set_migration(self, migration: Migration) -> Builder<TsIs, W_Migration> {
+        Builder {
+            islands: self.islands,
+            migration: migration.into(),
+            seed: self.seed,
+        }
+    }
+}
+
+// set_seed --------------------------------------------------------------------
+impl<TsIs: TS_Islands, TsMi: TS_Migration> Builder<TsIs, TsMi> {
+    /// Set the seed of the island model. It is used for all random decisions
+    /// of the migration and to derive the seeds of all islands, which have no
+    /// seed set in their parameters.
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+// build -----------------------------------------------------------------------
+impl<
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Ph: Phenotype<Ov, Ctx, Ge>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T: From<Ov>,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+    Dy: Dynamic<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    Cl: CustomLogger<Ov, Ctx, Ge>,
+> Builder<
+    W_Islands<Algorithm<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl>>,
+    W_Migration
+> {
+    pub fn build(self) -> Islands<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl> {
+        assert!(!self.islands.0.is_empty(), "the island model needs at least one island");
+
+        Islands {
+            islands: self.islands.0,
+            migration: self.migration.0,
+            seed: self.seed,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,329 @@
+//! The island model runs several populations (islands) of the genetic
+//! algorithm in parallel. Each island is a complete [`Algorithm`] with its own
+//! parameters (e.g., different selection, crossover or mutation methods per
+//! island), but all islands share the same encoding. At a set migration
+//! interval, the islands exchange individuals over a configurable topology.
+//! This preserves the diversity across the islands and counteracts premature
+//! convergence of a single population.
+//!
+//! Example:
+//! ```rust ,ignore
+//! let islands = ga::islands::Builder::new()
+//!     .set_islands(vec![alg_0, alg_1, alg_2, alg_3])
+//!     .set_migration(Migration {
+//!         interval: 100,
+//!         size: 5,
+//!         topology: Topology::Ring,
+//!         emigrate: Emigrate::Best,
+//!         immigrate: Immigrate::ReplaceWorst,
+//!     })
+//!     .set_seed(42) // optional
+//!     .build();
+//!
+//! let reports = islands.run();
+//! ```
+
+// Modules /////////////////////////////////////////////////////////////////////
+#[rustfmt::skip] mod builder;
+pub use builder::*;
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    dynamics::Dynamic,
+    encoding::{Context, Genotype, ObjectiveValue, Phenotype},
+    operators::{Crossover, Mutation},
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    report::Report,
+    rng::{GaRng, Seeder},
+    tools::rerun_logger::CustomLogger,
+    Algorithm,
+};
+use hashbrown::HashSet;
+use rand::{Rng, RngCore};
+use rayon::prelude::*;
+
+// Migration ///////////////////////////////////////////////////////////////////
+
+/// Configuration of the migration between islands.
+#[derive(Clone, Debug)]
+pub struct Migration {
+    /// Number of generations between two migrations.
+    pub interval: usize,
+
+    /// Number of individuals, which emigrate from each island.
+    pub size: usize,
+
+    /// Defines to which islands the emigrants of an island migrate.
+    pub topology: Topology,
+
+    /// Defines which individuals emigrate.
+    pub emigrate: Emigrate,
+
+    /// Defines which residents are replaced by the immigrants.
+    pub immigrate: Immigrate,
+}
+
+/// Migration topology.
+#[derive(Clone, Debug)]
+pub enum Topology {
+    /// Island `i` sends its emigrants to island `i + 1` (the last island
+    /// sends its emigrants to the first one).
+    Ring,
+
+    /// Every island sends its emigrants to all other islands.
+    FullyConnected,
+
+    /// Every island sends its emigrants to one randomly chosen other island.
+    Random,
+}
+
+impl Topology {
+    /// Returns the indices of the islands, which receive the emigrants of the
+    /// island with index `from`.
+    fn destinations(
+        &self,
+        from: usize,
+        islands: usize,
+        rng: &mut GaRng,
+    ) -> Vec<usize> {
+        if islands < 2 {
+            return vec![];
+        }
+
+        match self {
+            Self::Ring => vec![(from + 1) % islands],
+
+            Self::FullyConnected => {
+                (0..islands).filter(|i| *i != from).collect()
+            }
+
+            Self::Random => {
+                let to = rng.gen_range(0..islands - 1);
+                vec![if to >= from { to + 1 } else { to }]
+            }
+        }
+    }
+}
+
+/// Emigration policy, which decides which individuals leave an island.
+/// Emigrants are copied, so they remain in their original population.
+#[derive(Clone, Debug)]
+pub enum Emigrate {
+    /// The best individuals emigrate.
+    Best,
+
+    /// Randomly chosen individuals emigrate.
+    Random,
+
+    /// Distinct individuals, which are evenly spread over the (sorted)
+    /// population, emigrate. This sends not only the best individuals but
+    /// also some of the genetic material of the rest of the population.
+    Diverse,
+}
+
+impl Emigrate {
+    fn exec<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>(
+        &self,
+        population: &[(Ge, Ov)],
+        amount: usize,
+        rng: &mut GaRng,
+    ) -> Vec<(Ge, Ov)> {
+        let amount = amount.min(population.len());
+
+        match self {
+            Self::Best => population[..amount].to_vec(),
+
+            Self::Random => {
+                rand::seq::index::sample(rng, population.len(), amount)
+                    .into_iter()
+                    .map(|i| population[i].clone())
+                    .collect()
+            }
+
+            Self::Diverse => {
+                // Remove duplicates (the population stays sorted)
+                let mut seen = HashSet::<&Ge>::new();
+                let distinct: Vec<&(Ge, Ov)> = population
+                    .iter()
+                    .filter(|(ge, _)| seen.insert(ge))
+                    .collect();
+
+                let amount = amount.min(distinct.len());
+                (0..amount)
+                    .map(|i| distinct[i * distinct.len() / amount].clone())
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Immigration policy, which decides which residents of an island are
+/// replaced by the immigrants.
+#[derive(Clone, Debug)]
+pub enum Immigrate {
+    /// The immigrants replace the worst residents.
+    ReplaceWorst,
+
+    /// The immigrants replace randomly chosen residents.
+    ReplaceRandom,
+
+    /// An immigrant only replaces the worst resident, if it is better than
+    /// this resident.
+    ReplaceWorstIfBetter,
+}
+
+impl Immigrate {
+    /// Inserts the immigrants into the (sorted) population. The population
+    /// is sorted again afterwards.
+    pub(crate) fn exec<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>(
+        &self,
+        population: &mut Vec<(Ge, Ov)>,
+        mut immigrants: Vec<(Ge, Ov)>,
+        rng: &mut GaRng,
+    ) {
+        let amount = immigrants.len().min(population.len());
+        immigrants.truncate(amount);
+
+        match self {
+            Self::ReplaceWorst => {
+                population.truncate(population.len() - amount);
+                population.append(&mut immigrants);
+            }
+
+            Self::ReplaceRandom => {
+                let indices =
+                    rand::seq::index::sample(rng, population.len(), amount);
+
+                for (i, immigrant) in indices.into_iter().zip(immigrants) {
+                    population[i] = immigrant;
+                }
+            }
+
+            Self::ReplaceWorstIfBetter => {
+                for immigrant in immigrants {
+                    let worst = population.len() - 1;
+                    if immigrant.1 < population[worst].1 {
+                        population[worst] = immigrant;
+                        population.sort_by_key(|(_, x)| x.clone());
+                    }
+                }
+            }
+        }
+
+        population.sort_by_key(|(_, x)| x.clone());
+    }
+}
+
+// Islands /////////////////////////////////////////////////////////////////////
+
+/// Multi-population driver, which executes several algorithms (islands) in
+/// parallel and migrates individuals between them.
+pub struct Islands<
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Ph: Phenotype<Ov, Ctx, Ge>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T: From<Ov>,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+    Dy: Dynamic<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    Cl: CustomLogger<Ov, Ctx, Ge>,
+> {
+    islands: Vec<Algorithm<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl>>,
+    migration: Migration,
+    seed: Option<u64>,
+}
+
+impl<
+        Ov: ObjectiveValue,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Ph: Phenotype<Ov, Ctx, Ge>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T: From<Ov>,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+        Dy: Dynamic<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        Cl: CustomLogger<Ov, Ctx, Ge>,
+    > Islands<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl>
+where
+    Algorithm<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl>: Send,
+{
+    /// Executes all islands until the termination criterion of (at least) one
+    /// island is met, and returns the reports of all islands (in the order in
+    /// which the islands were given to the builder).
+    ///
+    /// Islands without a seed get a seed derived from the seed of the island
+    /// model, so that setting one seed makes the whole execution
+    /// reproducible.
+    pub fn run(mut self) -> Vec<Report<Ov, Ctx, Ge>> {
+        let seeder = Seeder::new(self.seed);
+        let mut rng = seeder.main();
+
+        // Derive seeds of the islands
+        for island in &mut self.islands {
+            if island.params.seed.is_none() {
+                island.params.seed = Some(rng.next_u64());
+            }
+        }
+
+        // Initialize islands
+        self.islands.par_iter_mut().for_each(|island| island.init());
+
+        let mut generation: usize = 0;
+        while !self.islands.iter().any(|island| island.is_terminated()) {
+            self.islands.par_iter_mut().for_each(|island| island.step());
+            generation += 1;
+
+            if self.migration.interval > 0
+                && generation.is_multiple_of(self.migration.interval)
+            {
+                self.migrate(&mut rng);
+            }
+        }
+
+        self.islands.into_iter().map(|island| island.finish()).collect()
+    }
+
+    /// Migrates individuals between the islands. All emigrants are chosen
+    /// before any immigration takes place.
+    fn migrate(&mut self, rng: &mut GaRng) {
+        let emigrants: Vec<Vec<(Ge, Ov)>> = self
+            .islands
+            .iter()
+            .map(|island| {
+                self.migration.emigrate.exec(
+                    island.state().population(),
+                    self.migration.size,
+                    rng,
+                )
+            })
+            .collect();
+
+        let num_islands = self.islands.len();
+        for (from, emigrants) in emigrants.into_iter().enumerate() {
+            let destinations =
+                self.migration.topology.destinations(from, num_islands, rng);
+
+            for to in destinations {
+                self.islands[to].immigrate(
+                    emigrants.clone(),
+                    &self.migration.immigrate,
+                    rng,
+                );
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
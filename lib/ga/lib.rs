@@ -1,17 +1,36 @@
 #![doc = include_str!("README.md")]
 
 // Modules /////////////////////////////////////////////////////////////////////
+pub mod analysis;
+pub mod cache;
+#[cfg(feature = "serde")]
+pub mod checkpoint;
 pub mod encoding;
+pub mod initializer;
+pub mod islands;
+pub mod niching;
+pub mod observer;
 pub mod operators;
+pub mod pareto;
 pub mod process;
+pub mod profiling;
+pub mod statistics;
+pub mod tuning;
 pub mod utils;
 #[rustfmt::skip] pub mod parameters;
 #[rustfmt::skip] mod builder;
 pub mod dynamics;
 pub mod report;
+pub mod restart;
+pub mod rng;
 pub mod runtime_data;
+pub mod self_adaptation;
+pub mod state;
 pub mod tools;
 
+#[cfg(test)]
+mod tests;
+
 // Re-Exports //////////////////////////////////////////////////////////////////
 pub use builder::*;
 
@@ -25,17 +44,27 @@ pub use colors_transform;
 // Imports /////////////////////////////////////////////////////////////////////
 use dynamics::{Dynamic, Dynamics};
 use encoding::{Context, Encoding, Genotype, ObjectiveValue, Phenotype};
-use operators::{Crossover, Mutation};
+use initializer::Initializer;
+use niching::Niching;
+use observer::{Event, Observer};
+use operators::{
+    adaptive::Portfolio, local_search::Mode, Crossover, LocalSearch, Mutation,
+};
 use parameters::Parameters;
 use process::{
     rejection::Rejection, replacement::Replacement, selection::Selection,
     termination::Termination,
 };
+use profiling::Phase;
 use rayon::prelude::*;
+use restart::{Restart, Strategy};
+use rng::{GaRng, Seeder};
 use runtime_data::RuntimeData;
-
-#[cfg(feature = "cache")]
-use hashbrown::HashMap;
+use state::State;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
 
 #[cfg(feature = "rerun_logger")]
 use tools::rerun_logger::RerunLogger;
@@ -43,27 +72,17 @@ use tools::rerun_logger::RerunLogger;
 // #[cfg(feature = "rerun_logger")]
 use tools::rerun_logger::CustomLogger;
 
-// Macros //////////////////////////////////////////////////////////////////////
-macro_rules! measure_runtime_start {
-    ($alg:ident) => {
-        #[cfg(feature = "log_runtimes")]
-        {
-            $alg.runtime_reference = std::time::Instant::now();
-        };
-    };
-}
+// Algorithm ///////////////////////////////////////////////////////////////////
 
-macro_rules! measure_runtime_end {
-    ($alg:ident) => {
-        #[cfg(feature = "log_runtimes")]
-        {
-            let elapsed = $alg.runtime_reference.elapsed();
-            $alg.runtimes.push(elapsed);
-        };
-    };
-}
+const NOT_INITIALIZED: &str = "algorithm is not initialized (call `init`)";
 
-// Algorithm ///////////////////////////////////////////////////////////////////
+/// Number of batches per thread, into which chromosomes are split for the
+/// batch evaluation (see [`Phenotype::evaluate_batch`]).
+const BATCHES_PER_THREAD: usize = 4;
+
+/// Index of the random number stream (per generation), which is used for the
+/// genotype-level diversity metrics. Pairs of parents use the indices from 0.
+const GENOTYPE_METRICS_STREAM: usize = usize::MAX;
 
 pub struct Algorithm<
     Ov: ObjectiveValue,
@@ -85,18 +104,23 @@ pub struct Algorithm<
     dynamics: Option<Dynamics<Ov, Ctx, Ge, T, Se, Cr, Mu, Re, Rp, Te, Dy>>,
     #[allow(dead_code)]
     custom_logger: Option<Cl>,
+    observers: Vec<Box<dyn Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>>>,
+    local_search: Option<Box<dyn LocalSearch<Ov, Ctx, Ge>>>,
+    operator_portfolio: Option<Portfolio<Cr, Mu>>,
+    initializer: Box<dyn Initializer<Ctx, Ge>>,
+
+    /// Chromosomes, which are part of the initial population.
+    seed_chromosomes: Vec<Ge>,
+
+    /// State of the current execution (`None` until [`Algorithm::init`] is
+    /// called).
+    state: Option<State<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>>,
 
     #[cfg(feature = "cache")]
-    cache: HashMap<Ge, Ov>,
+    cache: cache::Cache<Ge, Ov>,
 
     #[cfg(feature = "rerun_logger")]
     rerun_logger: RerunLogger,
-
-    #[cfg(feature = "log_runtimes")]
-    runtime_reference: std::time::Instant,
-
-    #[cfg(feature = "log_runtimes")]
-    runtimes: Vec<std::time::Duration>,
 }
 
 impl<
@@ -115,34 +139,279 @@ impl<
         Cl: CustomLogger<Ov, Ctx, Ge>,
     > Algorithm<Ov, Ctx, Ge, Ph, Cr, Mu, T, Se, Re, Rp, Te, Dy, Cl>
 {
+    /// Executes the algorithm until the termination criterion is met and
+    /// returns the report. This is a shorthand for calling [`Algorithm::init`],
+    /// [`Algorithm::step`] (until [`Algorithm::is_terminated`] returns `true`)
+    /// and [`Algorithm::finish`].
     pub fn run(mut self) -> Report<Ov, Ctx, Ge> {
-        // Create empty report
-        let log_size = self.params.termination.max_generations().unwrap_or(0);
-        let mut report_log: Vec<ReportLog> = Vec::with_capacity(log_size);
+        self.init();
+
+        while !self.is_terminated() {
+            self.step();
+        }
+
+        self.finish()
+    }
+
+    /// Initializes the algorithm execution by creating and evaluating the
+    /// initial population and setting up the dynamics. Calling this method
+    /// again restarts the execution.
+    pub fn init(&mut self) {
+        let state = self.create_state();
+        self.state = Some(state);
+    }
+
+    /// Executes one generation of the genetic algorithm (selection, crossover,
+    /// mutation, rejection, replacement and dynamics).
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub fn step(&mut self) {
+        let mut state = self.state.take().expect(NOT_INITIALIZED);
+        self.next_generation(&mut state);
+        self.state = Some(state);
+    }
+
+    /// Returns `true`, if the termination criterion of the algorithm is met.
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub fn is_terminated(&self) -> bool {
+        let state = self.state();
+        self.params.termination.stop(state.rtd.generation, &state.rtd.best)
+    }
+
+    /// Returns the state of the current execution, which contains the
+    /// population, the runtime data and the log.
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub fn state(&self) -> &State<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> {
+        self.state.as_ref().expect(NOT_INITIALIZED)
+    }
+
+    /// Returns the parameters of the algorithm.
+    pub fn parameters(
+        &self,
+    ) -> &Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> {
+        &self.params
+    }
+
+    /// Returns the parameters of the algorithm mutably. Changes take effect
+    /// in the next generation.
+    pub fn parameters_mut(
+        &mut self,
+    ) -> &mut Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> {
+        &mut self.params
+    }
+
+    /// Returns the context of the encoding.
+    pub fn context(&self) -> &Ctx {
+        &self.encoding.context
+    }
+
+    /// Returns the context of the encoding mutably.
+    pub fn context_mut(&mut self) -> &mut Ctx {
+        &mut self.encoding.context
+    }
+
+    /// Evaluates the given individuals and injects them into the population,
+    /// where they replace the worst individuals (at most the whole
+    /// population, surplus individuals are ignored). Like immigrants, the
+    /// injected individuals are added to the cache and update the best and
+    /// worst objective values of the runtime data immediately.
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub fn inject(&mut self, mut individuals: Vec<Ge>) {
+        let size = self.state.as_ref().expect(NOT_INITIALIZED).population.len();
+        individuals.truncate(size);
+
+        let mut injected = evaluate_batch(&self.encoding, individuals);
+
+        #[cfg(feature = "cache")]
+        injected.iter().for_each(|(ge, ov)| self.cache.insert(ge, ov));
+
+        let state = self.state.as_mut().expect(NOT_INITIALIZED);
+        state.rtd.evaluations += injected.len();
+
+        state.population.truncate(size - injected.len());
+        state.population.append(&mut injected);
+        state.population.par_sort_by_key(|(_, x)| x.clone());
+        state.update_best_and_worst();
+    }
+
+    /// Inserts immigrants (which were evaluated on another island) into the
+    /// population with the given immigration policy. The immigrants are added
+    /// to the cache and the best and worst objective values of the runtime
+    /// data are updated immediately, so an improvement by an immigrant counts
+    /// as a success of the current generation. The immigrants are not
+    /// evaluated again, so the evaluation counts don't change.
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub(crate) fn immigrate(
+        &mut self,
+        immigrants: Vec<(Ge, Ov)>,
+        policy: &islands::Immigrate,
+        rng: &mut GaRng,
+    ) {
+        #[cfg(feature = "cache")]
+        immigrants.iter().for_each(|(ge, ov)| self.cache.insert(ge, ov));
+
+        let state = self.state.as_mut().expect(NOT_INITIALIZED);
+        policy.exec(&mut state.population, immigrants, rng);
+        state.update_best_and_worst();
+    }
+
+    /// Finishes the algorithm execution and returns its report.
+    ///
+    /// # Panics
+    /// Panics if the algorithm was not initialized with [`Algorithm::init`].
+    pub fn finish(mut self) -> Report<Ov, Ctx, Ge> {
+        let state = self.state.take().expect(NOT_INITIALIZED);
+        self.create_report(state)
+    }
+
+    /// Returns the identifier of the current parameters.
+    fn parameter_identifier(&self) -> String {
+        let mut parts = vec![
+            // Population: P1000
+            format!("P:{}", self.params.population_size),
+            // Mutation Rate: MR0.0100
+            format!("MR:{:.4}", self.params.mutation_rate),
+            // Selection: SE...
+            format!("SE:{}", self.params.selection.identifier()),
+            // Crossover: CX...
+            format!("CX:{}", self.params.crossover.identifier()),
+            // Mutation: MU...
+            format!("MU:{}", self.params.mutation.identifier()),
+            // Replace: RE...
+            format!("RE:{}", self.params.replacement.identifier()),
+            // Termination: TE...
+            format!("TE:{}", self.params.termination.identifier()),
+        ];
+
+        // Local Search: LS... (only if enabled)
+        if let (Some(local_search), Some(config)) =
+            (&self.local_search, &self.params.local_search)
+        {
+            parts.push(format!(
+                "LS:{}-{}",
+                local_search.identifier(),
+                config.identifier()
+            ));
+        }
 
+        // Restart: RS... (only if enabled)
+        if let Some(restart) = &self.params.restart {
+            parts.push(format!("RS:{}", restart.identifier()));
+        }
+
+        // Operator Portfolio: OP... (only if enabled)
+        if let Some(portfolio) = &self.operator_portfolio {
+            parts.push(format!("OP:{}", portfolio.identifier()));
+        }
+
+        // Niching: NI... (only if enabled)
+        if let Some(niching) = &self.params.niching {
+            parts.push(format!("NI:{}", niching.identifier()));
+        }
+
+        // Self-Adaptation: SA... (only if enabled)
+        if let Some(self_adaptation) = &self.params.self_adaptation {
+            parts.push(format!("SA:{}", self_adaptation.identifier()));
+        }
+
+        // Initialization: IN... (only if not the default random one)
+        let initializer = self.initializer.identifier();
+        if !self.seed_chromosomes.is_empty() {
+            parts.push(format!(
+                "IN:seed-{}-{}",
+                self.seed_chromosomes.len(),
+                initializer
+            ));
+        } else if initializer != "random" {
+            parts.push(format!("IN:{}", initializer));
+        }
+
+        parts.join("_")
+    }
+
+    /// Restarts the population with the given restart strategy (the
+    /// population must be sorted). Returns the number of evaluations.
+    fn restart(
+        &mut self,
+        restart: &Restart,
+        population: &mut Vec<(Ge, Ov)>,
+        rng: &mut GaRng,
+    ) -> usize {
+        let elite = restart.elite.clamp(1, population.len());
+
+        // New chromosomes, which replace all individuals except the elite
+        let mut chromosomes: Vec<Ge> = match restart.strategy {
+            Strategy::Full | Strategy::DoublePopulation(_) => {
+                // (the population never shrinks)
+                if let Strategy::DoublePopulation(max) = restart.strategy {
+                    let size = self.params.population_size;
+                    self.params.population_size = (size * 2).min(max).max(size);
+                }
+
+                let amount = self.params.population_size.saturating_sub(elite);
+                self.initializer
+                    .exec(amount, &self.encoding.context, rng)
+                    .into_iter()
+                    .flat_map(|(_, chromosomes)| chromosomes)
+                    .collect()
+            }
+
+            Strategy::Cataclysm(rate) => population[elite..]
+                .iter()
+                .map(|(chromosome, _)| {
+                    let mut chromosome = chromosome.clone();
+                    self.params.mutation.exec(
+                        &mut chromosome,
+                        rate,
+                        rng,
+                        &self.encoding.context,
+                    );
+
+                    chromosome
+                })
+                .collect(),
+        };
+
+        // Strategy parameters of the new chromosomes (the mutated ones keep
+        // their strategy parameters)
+        if let (Some(sa), false) = (
+            &self.params.self_adaptation,
+            matches!(restart.strategy, Strategy::Cataclysm(_)),
+        ) {
+            for chromosome in chromosomes.iter_mut() {
+                sa.initialize(chromosome, self.params.mutation_rate);
+            }
+        }
+
+        // Evaluate the new chromosomes
+        let evaluations = chromosomes.len();
+
+        population.truncate(elite);
+        population.extend(evaluate_batch(&self.encoding, chromosomes));
+
+        population.par_sort_by_key(|(_, x)| x.clone());
+
+        evaluations
+    }
+
+    /// Creates and evaluates the initial population and sets up the runtime
+    /// data as well as the dynamics.
+    fn create_state(
+        &mut self,
+    ) -> State<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> {
         // Define parameter identifier before the algorithm starts, because if
         // dynamics modify those parameters, the configurations cannot be
         // grouped correctly afterwards.
-        let parameter_identifier = {
-            let parts = [
-                // Population: P1000
-                format!("P:{}", self.params.population_size),
-                // Mutation Rate: MR0.0100
-                format!("MR:{:.4}", self.params.mutation_rate),
-                // Selection: SE...
-                format!("SE:{}", self.params.selection.identifier()),
-                // Crossover: CX...
-                format!("CX:{}", self.params.crossover.identifier()),
-                // Mutation: MU...
-                format!("MU:{}", self.params.mutation.identifier()),
-                // Replace: RE...
-                format!("RE:{}", self.params.replacement.identifier()),
-                // Termination: TE...
-                format!("TE:{}", self.params.termination.identifier()),
-            ];
-
-            parts.join("_")
-        };
+        let parameter_identifier = self.parameter_identifier();
 
         // The same goes for the dynamics identifier. See `parameter_identifier`
         // for explanation (above).
@@ -150,46 +419,76 @@ impl<
             ds.list.iter().map(|d| d.identifier()).collect::<Vec<_>>().join("_")
         });
 
+        // Create the source of randomness for this execution
+        let seeder = Seeder::new(self.params.seed);
+        let mut rng = seeder.main();
+
         // Start runtime measurement
-        let total_runtime_start = std::time::Instant::now();
+        let start = std::time::Instant::now();
+
+        // Create initial population (seed chromosomes first, the rest is
+        // created by the initializer)
+        let (population, initial_sources, evaluation_time) = {
+            let size = self.params.population_size;
+            let seeds: Vec<Ge> =
+                self.seed_chromosomes.iter().take(size).cloned().collect();
+
+            let mut groups: Vec<(String, Vec<Ge>)> = vec![];
+            if !seeds.is_empty() {
+                groups.push(("seed".into(), seeds));
+            }
 
-        // Create initial population
-        let mut population: Vec<(Ge, Ov)> = {
-            // Generate individuals
-            let individuals: Vec<Ge> = Ge::generate(
-                self.params.population_size,
+            let amount =
+                size - groups.iter().map(|(_, g)| g.len()).sum::<usize>();
+            groups.extend(self.initializer.exec(
+                amount,
                 &self.encoding.context,
-            );
+                &mut rng,
+            ));
 
-            // Evaluate the individuals
-            let mut population: Vec<(Ge, Ov)> = individuals
-                .into_iter()
-                .map(|chromosome| {
-                    // Create derived phenotype from blueprint.
-                    let derivative = self
-                        .encoding
-                        .phenotype
-                        .derive(&chromosome, &self.encoding.context);
-
-                    // Evaluate the derivative
-                    let evaluation =
-                        derivative.evaluate(&self.encoding.context);
-                    drop(derivative);
-
-                    // Return
-                    (chromosome, evaluation)
-                })
+            let initial_sources: Vec<(String, usize)> = groups
+                .iter()
+                .map(|(name, g)| (name.clone(), g.len()))
                 .collect();
 
+            // Strategy parameters of the individuals
+            if let Some(sa) = &self.params.self_adaptation {
+                for chromosome in groups.iter_mut().flat_map(|(_, g)| g) {
+                    sa.initialize(chromosome, self.params.mutation_rate);
+                }
+            }
+
+            // Evaluate the individuals
+            let timer = Instant::now();
+            let mut population = evaluate_batch(
+                &self.encoding,
+                groups.into_iter().flat_map(|(_, g)| g).collect(),
+            );
+            let evaluation_time = timer.elapsed();
+
             // Sort the vector and return it as population
             population.par_sort_by_key(|(_, x)| x.clone());
 
             // Return
-            population
+            (population, initial_sources, evaluation_time)
         };
 
+        if self.params.niching.is_some() {
+            niching::validate(&population[0].0);
+        }
+
         // Initialize runtime data
         let mut rtd = RuntimeData::init(&population, &self.params);
+        rtd.initial_sources = initial_sources;
+        rtd.profile.record(Phase::Evaluation, evaluation_time);
+        rtd.profile.record_evaluations(population.len());
+        if let Some(portfolio) = &self.operator_portfolio {
+            (rtd.crossover_bandit, rtd.mutation_bandit) = portfolio.bandits();
+        }
+        rtd.update_genotype_metrics(
+            &population,
+            &mut seeder.stream(0, GENOTYPE_METRICS_STREAM),
+        );
 
         // Setup dynamics
         if let Some(dynamics) = &self.dynamics {
@@ -198,6 +497,7 @@ impl<
                     &mut rtd,
                     &mut self.params,
                     &mut self.encoding.context,
+                    &mut rng,
                 );
             }
         }
@@ -205,7 +505,10 @@ impl<
         // Create and populate cache
         #[cfg(feature = "cache")]
         {
-            self.cache = HashMap::<Ge, Ov>::from_iter(population.clone());
+            self.cache.clear();
+            population.iter().for_each(|(ge, ov)| {
+                self.cache.insert(ge, ov);
+            });
         };
 
         // Initialize rerun logger
@@ -214,287 +517,567 @@ impl<
             self.rerun_logger.log(&rtd);
         };
 
-        // Start loop
-        while !self.params.termination.stop(rtd.generation, &rtd.best) {
-            // Increment generation counter
-            rtd.inc_generation();
-
-            // Select
-            measure_runtime_start!(self);
-            let (selection_size_raw, selection_size_corrected) = self
-                .params
-                .replacement
-                .selection_size(self.params.population_size);
-
-            let (parents, distinct_selections) = self
-                .params
-                .selection
-                .exec(selection_size_corrected, &population);
-
-            measure_runtime_end!(self);
-
-            // Crossover, Mutation, Rejection
-            measure_runtime_start!(self);
-            let cx_mu_re: Vec<(((Ge, Ov), (Ge, Ov)), usize)> = parents
-                .par_chunks(2)
-                .map(|parents| {
-                    // Get source of randomness
-                    let mut rng = rand::thread_rng();
-
-                    assert_eq!(parents.len(), 2);
-                    let a = parents[0];
-                    let b = parents[1];
-
-                    // Crossover
-                    let (mut x0, mut x1) = self.params.crossover.exec(
-                        &a.0,
-                        &b.0,
-                        self.params.crossover_rate,
-                        &mut rng,
-                        &self.encoding.context,
-                    );
+        // Create empty report log
+        let log_size = self.params.termination.max_generations().unwrap_or(0);
+        let report_log: Vec<ReportLog> = Vec::with_capacity(log_size);
 
-                    // Mutation
-                    self.params.mutation.exec(
-                        &mut x0,
-                        self.params.mutation_rate,
-                        &mut rng,
-                        &self.encoding.context,
-                    );
-                    self.params.mutation.exec(
-                        &mut x1,
-                        self.params.mutation_rate,
+        // Notify observers
+        for observer in self.observers.iter_mut() {
+            observer.notify(Event::Start, &rtd, &population);
+        }
+
+        State {
+            population,
+            rtd,
+            report_log,
+            seeder,
+            rng,
+            parameter_identifier,
+            dynamics_identifier,
+            start,
+            elapsed: std::time::Duration::ZERO,
+        }
+    }
+
+    /// Executes one generation of the genetic algorithm.
+    fn next_generation(
+        &mut self,
+        state: &mut State<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    ) {
+        let State {
+            population,
+            rtd,
+            report_log,
+            seeder,
+            rng,
+            start,
+            elapsed,
+            ..
+        } = state;
+
+        // Increment generation counter
+        rtd.inc_generation();
+        rtd.profile.start_generation();
+
+        // Select
+        let timer = Instant::now();
+        let (selection_size_raw, selection_size_corrected) =
+            self.params.replacement.selection_size(self.params.population_size);
+
+        // Fitness sharing: select from the population reordered by shared
+        // fitness
+        let shared = match self.params.niching {
+            Some(Niching::Sharing { radius, alpha }) => {
+                Some(niching::share(population, radius, alpha))
+            }
+            _ => None,
+        };
+
+        let (parents, distinct_selections) = self.params.selection.exec(
+            selection_size_corrected,
+            shared.as_deref().unwrap_or(population),
+            rng,
+        );
+
+        // Indices of the parents in the population (needed for deterministic
+        // crowding)
+        let parent_indices: Vec<usize> = match self.params.niching {
+            Some(Niching::DeterministicCrowding) => parents
+                .iter()
+                .map(|parent| niching::index_of(population, *parent))
+                .collect(),
+            _ => vec![],
+        };
+
+        rtd.profile.record(Phase::Selection, timer.elapsed());
+
+        // Offspring which are at least as good as this objective value belong
+        // to the elite (needed for the local search policy).
+        let elite_size = self.params.replacement.elite_size(population.len());
+        let elite_threshold =
+            population[elite_size.clamp(1, population.len()) - 1].1.clone();
+
+        // Adaptive operator selection: assign operators to the pairs of
+        // parents
+        let portfolio = self.operator_portfolio.as_ref();
+        let pairs = parents.len() / 2;
+        let crossover_arms = match (portfolio, &rtd.crossover_bandit) {
+            (Some(p), Some(bandit)) => bandit.assign(&p.strategy, pairs, rng),
+            _ => vec![],
+        };
+        let mutation_arms = match (portfolio, &rtd.mutation_bandit) {
+            (Some(p), Some(bandit)) => bandit.assign(&p.strategy, pairs, rng),
+            _ => vec![],
+        };
+
+        // Counter of the objective value calculations of the local search
+        let local_search_evaluations = AtomicUsize::new(0);
+
+        // Crossover, Mutation, Evaluation, Local Search, Rejection
+        let timer = Instant::now();
+
+        // Crossover and mutation. The changed genes of offspring, which are
+        // equal to one of their parents after the crossover, are tracked for
+        // the incremental evaluation.
+        type Child<'a, Ge, Ov> = (Ge, Option<(&'a (Ge, Ov), Vec<usize>)>);
+        let cx_mu: Vec<(GaRng, [Child<Ge, Ov>; 2])> = parents
+            .par_chunks(2)
+            .enumerate()
+            .map(|(i, parents)| {
+                // Get source of randomness (depends only on the seed, the
+                // generation and the index of the pair of parents).
+                let mut rng = seeder.stream(rtd.generation, i);
+
+                assert_eq!(parents.len(), 2);
+                let a = parents[0];
+                let b = parents[1];
+
+                // Operators of this pair of parents (from the portfolio, if
+                // enabled)
+                let crossover = match (portfolio, crossover_arms.get(i)) {
+                    (Some(p), Some(arm)) => &p.crossovers[*arm],
+                    _ => &self.params.crossover,
+                };
+                let mutation = match (portfolio, mutation_arms.get(i)) {
+                    (Some(p), Some(arm)) => &p.mutations[*arm],
+                    _ => &self.params.mutation,
+                };
+
+                // Crossover
+                let (x0, x1) = crossover.exec(
+                    &a.0,
+                    &b.0,
+                    self.params.crossover_rate,
+                    &mut rng,
+                    &self.encoding.context,
+                );
+
+                // Mutation (with the self-adapted rate of the offspring, if
+                // enabled)
+                let children = [x0, x1].map(|mut x| {
+                    let index = [a, b].iter().position(|p| p.0 == x);
+                    let parent = index.map(|i| [a, b][i]);
+                    let rate = self
+                        .params
+                        .self_adaptation
+                        .and_then(|sa| {
+                            sa.adapt(&mut x, [&a.0, &b.0], index, &mut rng)
+                        })
+                        .unwrap_or(self.params.mutation_rate);
+
+                    let changed = mutation.exec_tracked(
+                        &mut x,
+                        rate,
                         &mut rng,
                         &self.encoding.context,
                     );
 
-                    // Evaluation
-                    #[allow(unused_mut)]
-                    let mut cache_hits = 0;
-
-                    let y0: (Ge, Ov) = {
-                        #[allow(unused_labels)]
-                        let ov: Ov = 'ov: {
-                            #[cfg(feature = "cache")]
-                            if let Some(cached_ov) = self.cache.get(&x0) {
-                                // Increase cache hit count
-                                cache_hits += 1;
-
-                                // Return the cached objective value
-                                break 'ov cached_ov.clone();
-                            }
-
-                            // Create derived phenotype
-                            let ph = self
-                                .encoding
-                                .phenotype
-                                .derive(&x0, &self.encoding.context);
-
-                            // Calculate objective value and return it
-                            ph.evaluate(&self.encoding.context)
-                        };
-
-                        (x0, ov)
-                    };
-
-                    let y1: (Ge, Ov) = {
-                        #[allow(unused_labels)]
-                        let ov: Ov = 'ov: {
-                            #[cfg(feature = "cache")]
-                            if let Some(cached_ov) = self.cache.get(&x1) {
-                                // Increase cache hit count
-                                cache_hits += 1;
-
-                                // Return the cached objective value
-                                break 'ov cached_ov.clone();
-                            }
-
-                            // Create derived phenotype
-                            let ph = self
-                                .encoding
-                                .phenotype
-                                .derive(&x1, &self.encoding.context);
-
-                            // Calculate objective value and return it
-                            ph.evaluate(&self.encoding.context)
-                        };
-
-                        (x1, ov)
-                    };
-
-                    // Rejection
-                    let (z0, z1) = self.params.rejection.exec(
-                        a,
-                        b,
-                        &y0,
-                        &y1,
-                        &self.encoding.context,
-                    );
+                    (x, parent.zip(changed))
+                });
 
-                    // Return
-                    ((z0.clone(), z1.clone()), cache_hits)
-                })
-                .collect::<Vec<(((Ge, Ov), (Ge, Ov)), usize)>>();
+                (rng, children)
+            })
+            .collect();
+
+        rtd.profile.record(Phase::Variation, timer.elapsed());
+
+        // Evaluation: cached objective values first, then the incremental
+        // evaluation of tracked mutations and finally the batch evaluation of
+        // the remaining offspring
+        let timer = Instant::now();
+        let (rngs, children): (Vec<GaRng>, Vec<[Child<Ge, Ov>; 2]>) =
+            cx_mu.into_iter().unzip();
+        let children: Vec<Child<Ge, Ov>> =
+            children.into_iter().flatten().collect();
+
+        // With the Baldwinian local search, the objective values of the
+        // parents belong to their improved chromosomes, so they can not be
+        // used for the incremental evaluation.
+        let baldwinian = self
+            .params
+            .local_search
+            .is_some_and(|config| config.mode == Mode::Baldwinian);
+
+        let delta_evaluations = AtomicUsize::new(0);
+        let known: Vec<Option<Ov>> = children
+            .par_iter()
+            .map(|(x, delta)| {
+                #[cfg(feature = "cache")]
+                if let Some(cached_ov) = self.cache.get(x) {
+                    return Some(cached_ov);
+                }
 
-            // Extract offspring and the nuber of cache hits from the results
-            // of crossover, mutation and rejection
-            let (offspr, ch_num): (Vec<((Ge, Ov), (Ge, Ov))>, Vec<usize>) =
-                cx_mu_re.into_iter().unzip();
+                let (parent, changed) = delta.as_ref()?;
+                if baldwinian {
+                    return None;
+                }
+                let ov = self.encoding.phenotype.evaluate_delta(
+                    &parent.0,
+                    &parent.1,
+                    x,
+                    changed,
+                    &self.encoding.context,
+                )?;
+
+                delta_evaluations.fetch_add(1, Ordering::Relaxed);
+                Some(ov)
+            })
+            .collect();
+
+        let mut pending: Vec<Ge> = vec![];
+        let mut evaluated: Vec<Option<(Ge, Ov)>> = vec![];
+        for ((x, _), ov) in children.into_iter().zip(known) {
+            match ov {
+                Some(ov) => evaluated.push(Some((x, ov))),
+                None => {
+                    evaluated.push(None);
+                    pending.push(x);
+                }
+            }
+        }
 
-            let cache_hits: usize = ch_num.into_iter().sum();
+        let delta_evaluations = delta_evaluations.into_inner();
+        let evaluations = pending.len() + delta_evaluations;
 
-            let mut offspring: Vec<(Ge, Ov)> =
-                offspr.into_iter().flat_map(|(a, b)| vec![a, b]).collect();
+        let mut batch = evaluate_batch(&self.encoding, pending).into_iter();
+        let mut evaluated = evaluated.into_iter().map(|y| {
+            y.unwrap_or_else(|| batch.next().expect("missing evaluation"))
+        });
+        let pairs: Vec<(GaRng, (Ge, Ov), (Ge, Ov))> = rngs
+            .into_iter()
+            .map(|rng| {
+                let y0 = evaluated.next().expect("missing offspring");
+                let y1 = evaluated.next().expect("missing offspring");
+                (rng, y0, y1)
+            })
+            .collect();
+
+        rtd.profile.record(Phase::Evaluation, timer.elapsed());
+        rtd.profile.record_evaluations(evaluations);
+
+        // Local search (the objective values of the offspring before the
+        // local search are kept for the credit of the operators)
+        let timer = Instant::now();
+        let pairs: Vec<((Ge, Ov), (Ge, Ov), [Ov; 2])> = pairs
+            .into_par_iter()
+            .map(|(mut rng, y0, y1)| {
+                let variation = [y0.1.clone(), y1.1.clone()];
+                let mut improve = |y| {
+                    operators::local_search::improve(
+                        y,
+                        self.local_search.as_deref(),
+                        self.params.local_search.as_ref(),
+                        &self.encoding,
+                        &elite_threshold,
+                        &mut rng,
+                        &local_search_evaluations,
+                    )
+                };
+
+                (improve(y0), improve(y1), variation)
+            })
+            .collect();
+
+        rtd.profile.record(Phase::LocalSearch, timer.elapsed());
+
+        // Credit and rejection
+        let timer = Instant::now();
+        let cx_mu_re: Vec<((Ge, Ov), (Ge, Ov), f64)> = parents
+            .par_chunks(2)
+            .zip(pairs.into_par_iter())
+            .map(|(parents, (y0, y1, variation))| {
+                let a = parents[0];
+                let b = parents[1];
+
+                // Credit of the operators: fraction of the offspring, which
+                // are better than both parents (before the local search)
+                let credit =
+                    variation.iter().filter(|y| **y < a.1 && **y < b.1).count()
+                        as f64
+                        / 2.;
+
+                // Rejection
+                let (z0, z1) = self.params.rejection.exec(
+                    a,
+                    b,
+                    &y0,
+                    &y1,
+                    &self.encoding.context,
+                );
 
-            measure_runtime_end!(self);
+                // Return
+                (z0.clone(), z1.clone(), credit)
+            })
+            .collect::<Vec<((Ge, Ov), (Ge, Ov), f64)>>();
+
+        // Credit the operators of the portfolio
+        if let Some(portfolio) = portfolio {
+            let credits = cx_mu_re.iter().map(|(_, _, credit)| *credit);
+            for (bandit, arms) in [
+                (&mut rtd.crossover_bandit, &crossover_arms),
+                (&mut rtd.mutation_bandit, &mutation_arms),
+            ] {
+                if let Some(bandit) = bandit {
+                    let rewards: Vec<(usize, f64)> =
+                        arms.iter().copied().zip(credits.clone()).collect();
+                    bandit.update(&portfolio.strategy, &rewards);
+                }
+            }
+        }
 
-            // Correct offspring length (might be off by one, because of
-            // selection size correction to get PAIRS of parents).
-            measure_runtime_start!(self);
-            offspring.truncate(selection_size_raw);
+        // Extract offspring from the results of crossover, mutation and
+        // rejection
+        let mut offspring: Vec<(Ge, Ov)> =
+            cx_mu_re.into_iter().flat_map(|(a, b, _)| vec![a, b]).collect();
+
+        rtd.profile.record(Phase::Variation, timer.elapsed());
+
+        // Correct offspring length (might be off by one, because of
+        // selection size correction to get PAIRS of parents).
+        offspring.truncate(selection_size_raw);
+
+        // Calculate the average mean objective value of the offspring
+        // let offspring_mean: f32 = Ov::calc_average(
+        //     &offspring.iter().map(|(_, ov)| ov.clone()).collect::<Vec<_>>(),
+        // );
+
+        // Replace (population must be sorted; offspring is not).
+        let timer = Instant::now();
+        match self.params.niching {
+            Some(Niching::DeterministicCrowding) => {
+                niching::deterministic_crowding(
+                    population,
+                    &parent_indices,
+                    offspring,
+                );
+            }
+            Some(Niching::RestrictedTournament(window)) => {
+                niching::restricted_tournament(
+                    population, offspring, window, rng,
+                );
+            }
+            _ => self.params.replacement.exec(population, offspring, rng),
+        }
 
-            measure_runtime_end!(self);
+        rtd.profile.record(Phase::Replacement, timer.elapsed());
 
-            // Calculate the average mean objective value of the offspring
-            // measure_runtime_start!(self);
-            // let offspring_mean: f32 = Ov::calc_average(
-            //     &offspring.iter().map(|(_, ov)| ov.clone()).collect::<Vec<_>>(),
-            // );
+        // Sort the new population
+        let timer = Instant::now();
+        population.par_sort_by_key(|(_, x)| x.clone());
 
-            // measure_runtime_end!(self);
+        rtd.profile.record(Phase::Sort, timer.elapsed());
 
-            // Replace (population must be sorted; offspring is not).
-            measure_runtime_start!(self);
-            self.params.replacement.exec(&mut population, offspring);
+        // Update cache
+        #[cfg(feature = "cache")]
+        {
+            let timer = Instant::now();
+            population.iter().for_each(|(ge, ov)| {
+                self.cache.insert(ge, ov);
+            });
 
-            measure_runtime_end!(self);
+            rtd.profile.record(Phase::Cache, timer.elapsed());
+        };
 
-            // Sort the new population
-            measure_runtime_start!(self);
-            population.par_sort_by_key(|(_, x)| x.clone());
+        // Cache statistics of this generation
+        #[cfg(feature = "cache")]
+        let cache_stats = self.cache.take_stats();
 
-            measure_runtime_end!(self);
+        #[cfg(not(feature = "cache"))]
+        let cache_stats = cache::CacheStats::default();
 
-            // Update cache
-            #[cfg(feature = "cache")]
-            {
-                measure_runtime_start!(self);
-                population.iter().for_each(|(ge, ov)| {
-                    self.cache.insert(ge.clone(), ov.clone());
-                });
+        // Update runtime data
+        let timer = Instant::now();
+        rtd.update(
+            population,
+            self.params.replacement.elite_size(self.params.population_size),
+            selection_size_corrected,
+            distinct_selections,
+            // offspring_mean,
+            cache_stats,
+        );
 
-                measure_runtime_end!(self);
-            };
-
-            // Update runtime data
-            measure_runtime_start!(self);
-            rtd.update(
-                &population,
-                self.params.replacement.elite_size(self.params.population_size),
-                selection_size_corrected,
-                distinct_selections,
-                // offspring_mean,
-                cache_hits,
-            );
+        if rtd.success {
+            rtd.time_to_best = *elapsed + start.elapsed();
+        }
 
-            measure_runtime_end!(self);
+        let local_search_evaluations = local_search_evaluations.into_inner();
+        rtd.evaluations += evaluations + local_search_evaluations;
+        rtd.local_search_evaluations += local_search_evaluations;
+        rtd.delta_evaluations += delta_evaluations;
 
-            #[cfg(feature = "log_runtimes")]
-            {
-                rtd.update_execution_times(self.runtimes);
-                self.runtimes = vec![];
-            }
+        // Update genotype-level diversity metrics
+        rtd.update_genotype_metrics(
+            population,
+            &mut seeder.stream(rtd.generation, GENOTYPE_METRICS_STREAM),
+        );
 
-            // Update report log
-            report_log.push(ReportLog {
-                generation: rtd.generation,
-                best: rtd.best.to_usize(),
-                worst: rtd.worst.to_usize(),
-                mean: rtd.mean,
-                median: rtd.median,
-                std_dev: rtd.std_dev,
-                variance: rtd.variance,
-                diversity: rtd.diversity,
-            });
+        rtd.profile.record(Phase::Stats, timer.elapsed());
 
-            // Log (to 'rerun' or 'console')
-            #[cfg(feature = "rerun_logger")]
-            {
-                // Send runtime data to logger
-                self.rerun_logger.log(&rtd);
-
-                // Execute custom logger
-                if let Some(cl) = &self.custom_logger {
-                    cl.log(
-                        self.rerun_logger.get_stream(),
-                        rtd.generation,
-                        &self.encoding.context,
-                        &population,
-                    );
-                }
+        // Restart the population, if the trigger fires
+        let restart = self.params.restart.filter(|restart| {
+            let since = rtd.last_success.max(rtd.last_restart);
+            restart.trigger.fires(rtd.generation, since, rtd.diversity)
+        });
+
+        if let Some(restart) = &restart {
+            rtd.evaluations += self.restart(restart, population, rng);
+            rtd.restarts += 1;
+            rtd.last_restart = rtd.generation;
+        }
 
-                // Also print out minimal information to the console
-                println!(
-                    "[{:>7}] best:{:>4} mean: {:>4.2} worst:{:>4}",
+        let restart = restart.map(|restart| restart.identifier());
+
+        // Update report log
+        report_log.push(ReportLog {
+            generation: rtd.generation,
+            runtime_ms: (*elapsed + start.elapsed()).as_millis() as u64,
+            best: rtd.best.to_usize(),
+            worst: rtd.worst.to_usize(),
+            mean: rtd.mean,
+            median: rtd.median,
+            std_dev: rtd.std_dev,
+            variance: rtd.variance,
+            diversity: rtd.diversity,
+            component_best: rtd.component_best.clone(),
+            component_mean: rtd.component_mean.clone(),
+            unique_genotypes: rtd.unique_genotypes,
+            mean_distance: rtd.mean_distance,
+            mean_distance_to_best: rtd.mean_distance_to_best,
+            strategy: rtd.strategy,
+            restart: restart.clone(),
+            events: Vec::new(),
+        });
+
+        // Log to 'rerun' (console output is done by the observers)
+        #[cfg(feature = "rerun_logger")]
+        {
+            // Send runtime data to logger
+            self.rerun_logger.log(rtd);
+
+            // Execute custom logger
+            if let Some(cl) = &self.custom_logger {
+                cl.log(
+                    self.rerun_logger.get_stream(),
                     rtd.generation,
-                    rtd.best.to_usize(),
-                    rtd.mean,
-                    rtd.worst.to_usize(),
+                    &self.encoding.context,
+                    population,
                 );
-            };
-
-            #[cfg(all(
-                not(feature = "rerun_logger"),
-                not(feature = "no_stdout_log"),
-            ))]
-            {
-                // Print some more information, if the "rerun_logger" feature
-                // is NOT enabled.
-                println!(
-                    "[{}] best = {:?}, mean = {}, worst = {:?}, cache-hits = {}",
-                    rtd.generation,
-                    rtd.best,
-                    rtd.mean,
-                    rtd.worst,
-                    rtd.cache_hits,
+            }
+        };
+
+        // Notify observers
+        for observer in self.observers.iter_mut() {
+            observer.notify(Event::Generation, rtd, population);
+
+            if rtd.success {
+                observer.notify(Event::Improvement, rtd, population);
+            }
+
+            if let Some(identifier) = &restart {
+                observer.notify(Event::Restart(identifier), rtd, population);
+            }
+        }
+
+        // Execute dynamics
+        let timer = Instant::now();
+        if let Some(dynamics) = &self.dynamics {
+            for dyn_exe in &dynamics.list {
+                let modified = dyn_exe.exec(
+                    rtd,
+                    &mut self.params,
+                    &mut self.encoding.context,
+                    rng,
+                    #[cfg(feature = "log_dynamics")]
+                    &self.rerun_logger,
                 );
-            };
-
-            // Execute dynamics
-            if let Some(dynamics) = &self.dynamics {
-                for dyn_exe in &dynamics.list {
-                    dyn_exe.exec(
-                        &rtd,
-                        &mut self.params,
-                        &mut self.encoding.context,
-                        #[cfg(feature = "log_dynamics")]
-                        &self.rerun_logger,
-                    );
+
+                if let Some(log) = report_log.last_mut() {
+                    log.events.extend(dyn_exe.take_events());
+                }
+
+                if modified {
+                    let identifier = dyn_exe.identifier();
+                    for observer in self.observers.iter_mut() {
+                        observer.notify(
+                            Event::Dynamic(&identifier),
+                            rtd,
+                            population,
+                        );
+                    }
                 }
             }
         }
 
-        // Print result to console
-        println!(
-            "[{}] best = {:?}, mean = {}, worst = {:?}, cache-hits = {}",
-            rtd.generation, rtd.best, rtd.mean, rtd.worst, rtd.cache_hits,
-        );
+        rtd.profile.record(Phase::Dynamics, timer.elapsed());
+    }
+
+    /// Creates the report of the algorithm execution.
+    fn create_report(
+        mut self,
+        state: State<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    ) -> Report<Ov, Ctx, Ge> {
+        let rtd = &state.rtd;
+
+        // Notify observers
+        for observer in self.observers.iter_mut() {
+            observer.notify(Event::Finish, rtd, &state.population);
+        }
 
         // Stop runtime measurement (total runtime)
-        let total_runtime = total_runtime_start.elapsed().as_secs() as usize;
+        let total_runtime = state.runtime().as_millis() as u64;
 
         // Create report and return
         Report {
-            population,
             generation: rtd.generation,
-            log: report_log,
-            runtime: total_runtime,
-            parameter_identifier,
-            dynamics_identifier,
+            population: state.population,
+            log: state.report_log,
+            runtime_ms: total_runtime,
+            time_to_best_ms: rtd.time_to_best.as_millis() as u64,
+            last_improvement: rtd.last_success,
+            evaluations: rtd.evaluations,
+            profile: rtd.profile.clone(),
+            seed: state.seeder.seed(),
+            initial_sources: state.rtd.initial_sources.clone(),
+            operators: self.operator_portfolio.as_ref().map_or(vec![], |p| {
+                p.stats(
+                    state.rtd.crossover_bandit.as_ref(),
+                    state.rtd.mutation_bandit.as_ref(),
+                )
+            }),
+            parameter_identifier: state.parameter_identifier,
+            dynamics_identifier: state.dynamics_identifier,
 
             ctx: std::marker::PhantomData,
         }
     }
 }
 
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Evaluates the given chromosomes in parallel batches (see
+/// [`Phenotype::evaluate_batch`]) and returns them with their objective
+/// values (in the same order).
+fn evaluate_batch<Ov, Ctx, Ge, Ph>(
+    encoding: &Encoding<Ov, Ctx, Ge, Ph>,
+    chromosomes: Vec<Ge>,
+) -> Vec<(Ge, Ov)>
+where
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Ph: Phenotype<Ov, Ctx, Ge>,
+{
+    let batches = BATCHES_PER_THREAD * rayon::current_num_threads();
+    let batch_size = chromosomes.len().div_ceil(batches).max(1);
+
+    let objective_values: Vec<Ov> = chromosomes
+        .par_chunks(batch_size)
+        .flat_map_iter(|batch| {
+            encoding.phenotype.evaluate_batch(batch, &encoding.context)
+        })
+        .collect();
+
+    chromosomes.into_iter().zip(objective_values).collect()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,223 @@
+//! Niching methods, which keep several distinct good solutions (niches) alive
+//! in the population at once.
+//!
+//! All niching methods need a genotype distance (see
+//! [`Genotype::distance`](crate::encoding::Genotype::distance)):
+//! - [`Niching::Sharing`] lowers the fitness of individuals in crowded niches
+//!   before the selection.
+//! - [`Niching::DeterministicCrowding`] and
+//!   [`Niching::RestrictedTournament`] let each offspring compete with a
+//!   similar individual of the population. They replace the replacement
+//!   method of the algorithm (only its selection size is still used).
+//!
+//! Niching is configured in the parameters (see
+//! [`parameters::Builder::set_niching`](crate::parameters::Builder::set_niching)).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    rng::GaRng,
+};
+use rayon::prelude::*;
+
+// Niching /////////////////////////////////////////////////////////////////////
+
+/// Niching method.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Niching {
+    /// Fitness sharing: the (rank-based) fitness of every individual is
+    /// divided by its niche count, which is the sum of `1 - (d / radius)^alpha`
+    /// over all individuals within the sharing radius (distance `d < radius`).
+    ///
+    /// The selection is executed on the population reordered by the shared
+    /// fitness. Therefore, sharing is best combined with rank-based selection
+    /// methods (e.g. tournament or linear rank selection).
+    Sharing { radius: f64, alpha: f64 },
+
+    /// Deterministic crowding: the offspring are paired with their most
+    /// similar parent and replace it, if they are at least as good. Classic
+    /// deterministic crowding uses random selection of the parents.
+    DeterministicCrowding,
+
+    /// Restricted tournament selection (replacement): every offspring
+    /// competes with the most similar of the given number of randomly chosen
+    /// individuals (window size) and replaces it, if it is at least as good.
+    RestrictedTournament(usize),
+}
+
+impl Niching {
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::Sharing { radius, alpha } => {
+                format!("share-{:.2}-{:.2}", radius, alpha)
+            }
+            Self::DeterministicCrowding => "det-crowd".into(),
+            Self::RestrictedTournament(w) => format!("rts-{w}"),
+        }
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+const DISTANCE_REQUIRED: &str = "niching requires `Genotype::distance`";
+
+/// Distance of two genotypes, which must be defined for niching.
+fn distance<Ctx: Context, Ge: Genotype<Ctx>>(a: &Ge, b: &Ge) -> f64 {
+    a.distance(b).expect(DISTANCE_REQUIRED)
+}
+
+/// Checks (once, with the initial population) that the genotype defines a
+/// distance, so that niching doesn't fail in the middle of a run.
+///
+/// # Panics
+/// Panics if [`Genotype::distance`] is not implemented.
+pub(crate) fn validate<Ctx: Context, Ge: Genotype<Ctx>>(chromosome: &Ge) {
+    assert!(chromosome.distance(chromosome).is_some(), "{DISTANCE_REQUIRED}");
+}
+
+/// Returns the niche counts of the individuals (including themselves, so each
+/// niche count is at least 1).
+pub fn niche_counts<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>(
+    population: &[(Ge, Ov)],
+    radius: f64,
+    alpha: f64,
+) -> Vec<f64> {
+    population
+        .par_iter()
+        .map(|(a, _)| {
+            population
+                .iter()
+                .map(|(b, _)| distance::<Ctx, Ge>(a, b))
+                .filter(|d| *d < radius)
+                .map(|d| 1. - (d / radius).powf(alpha))
+                .sum()
+        })
+        .collect()
+}
+
+/// Returns the population (sorted ascendingly by objective value) reordered
+/// by shared fitness, so that the individual with the best shared fitness is
+/// the first one.
+///
+/// The raw fitness is based on the rank (`n - rank`, with equal ranks for
+/// equal objective values), because objective values can not be scaled
+/// generically.
+pub fn share<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>(
+    population: &[(Ge, Ov)],
+    radius: f64,
+    alpha: f64,
+) -> Vec<(Ge, Ov)> {
+    let n = population.len();
+    let niche_counts = niche_counts(population, radius, alpha);
+
+    // Rank-based fitness (individuals with equal objective values share the
+    // rank of the first one)
+    let mut fitness = Vec::with_capacity(n);
+    let mut rank = 0;
+    for i in 0..n {
+        if population[i].1 != population[rank].1 {
+            rank = i;
+        }
+        fitness.push((n - rank) as f64 / niche_counts[i].max(1.));
+    }
+
+    // Reorder (stable, so ties keep the order of the objective values)
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| fitness[*b].total_cmp(&fitness[*a]));
+
+    order.into_iter().map(|i| population[i].clone()).collect()
+}
+
+/// Returns the index of an individual, which is referenced by the selection,
+/// in the population.
+///
+/// # Panics
+/// Panics if the individual is not an element of the population (e.g. if the
+/// selection returned a reference to a copy).
+pub(crate) fn index_of<T>(population: &[T], individual: &T) -> usize {
+    let range = population.as_ptr_range();
+    let individual: *const T = individual;
+    assert!(
+        range.contains(&individual),
+        "the selected individual is not part of the population"
+    );
+
+    let size = std::mem::size_of::<T>().max(1);
+    (individual as usize - range.start as usize) / size
+}
+
+/// Deterministic crowding: every pair of offspring is matched with the pair
+/// of its parents (given by their indices in the population), so that the
+/// sum of the distances is minimal. Each offspring replaces its parent, if it
+/// is at least as good (the parent might already be replaced by an offspring
+/// of an earlier pair).
+pub fn deterministic_crowding<
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+>(
+    population: &mut [(Ge, Ov)],
+    parents: &[usize],
+    offspring: Vec<(Ge, Ov)>,
+) {
+    let mut offspring = offspring.into_iter();
+    for parents in parents.chunks(2) {
+        let (Some(x0), x1) = (offspring.next(), offspring.next()) else {
+            break;
+        };
+
+        let pairs = match x1 {
+            Some(x1) if parents.len() == 2 => {
+                let (p0, p1) =
+                    (&population[parents[0]].0, &population[parents[1]].0);
+                let straight = distance::<Ctx, Ge>(p0, &x0.0)
+                    + distance::<Ctx, Ge>(p1, &x1.0);
+                let crossed = distance::<Ctx, Ge>(p0, &x1.0)
+                    + distance::<Ctx, Ge>(p1, &x0.0);
+
+                if straight <= crossed {
+                    vec![(parents[0], x0), (parents[1], x1)]
+                } else {
+                    vec![(parents[0], x1), (parents[1], x0)]
+                }
+            }
+            _ => vec![(parents[0], x0)],
+        };
+
+        for (i, child) in pairs {
+            if child.1 <= population[i].1 {
+                population[i] = child;
+            }
+        }
+    }
+}
+
+/// Restricted tournament selection: every offspring competes with the most
+/// similar of `window` randomly chosen individuals of the population and
+/// replaces it, if it is at least as good.
+pub fn restricted_tournament<
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+>(
+    population: &mut [(Ge, Ov)],
+    offspring: Vec<(Ge, Ov)>,
+    window: usize,
+    rng: &mut GaRng,
+) {
+    let n = population.len();
+    let window = window.clamp(1, n);
+
+    for child in offspring {
+        let nearest = rand::seq::index::sample(rng, n, window)
+            .into_iter()
+            .map(|i| (i, distance::<Ctx, Ge>(&population[i].0, &child.0)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(i, _)| i);
+
+        if child.1 <= population[nearest].1 {
+            population[nearest] = child;
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use super::{Event, Observer};
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::{Crossover, Mutation},
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    runtime_data::RuntimeData,
+};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+// Constants ///////////////////////////////////////////////////////////////////
+const HEADER: &str = "generation,best,worst,mean,median,variance,std_dev,\
+                      diversity,cache_hits,success_rate";
+
+// CSV /////////////////////////////////////////////////////////////////////////
+
+/// Writes the runtime data of the initial population and of each generation
+/// as one row of comma-separated values (including a header row).
+///
+/// If writing to the underlying writer fails, the error is printed to stderr
+/// and the observer is disabled (the algorithm execution continues).
+pub struct Csv<W: Write> {
+    writer: W,
+    header: bool,
+    disabled: bool,
+}
+
+impl<W: Write> Csv<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, header: false, disabled: false }
+    }
+}
+
+impl Csv<BufWriter<File>> {
+    /// Creates (or truncates) the file at `path` and writes to it.
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<
+        W: Write + Send + Sync,
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+    > Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> for Csv<W>
+{
+    fn notify(
+        &mut self,
+        event: Event,
+        rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _population: &[(Ge, Ov)],
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        let mut write = || -> std::io::Result<()> {
+            match event {
+                Event::Start | Event::Generation => {
+                    if !self.header {
+                        writeln!(self.writer, "{HEADER}")?;
+                        self.header = true;
+                    }
+
+                    writeln!(
+                        self.writer,
+                        "{},{},{},{},{},{},{},{},{},{}",
+                        rtd.generation,
+                        rtd.best.to_usize(),
+                        rtd.worst.to_usize(),
+                        rtd.mean,
+                        rtd.median,
+                        rtd.variance,
+                        rtd.std_dev,
+                        rtd.diversity,
+                        rtd.cache_hits,
+                        rtd.success_rate_pt1,
+                    )
+                }
+
+                Event::Finish => self.writer.flush(),
+
+                Event::Improvement | Event::Dynamic(_) | Event::Restart(_) => {
+                    Ok(())
+                }
+            }
+        };
+
+        if let Err(error) = write() {
+            eprintln!(
+                "failed to write CSV log, disabling the observer: {error}"
+            );
+            self.disabled = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
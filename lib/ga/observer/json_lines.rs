@@ -0,0 +1,145 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use super::{Event, Observer};
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::{Crossover, Mutation},
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    runtime_data::RuntimeData,
+};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+// JSON Lines //////////////////////////////////////////////////////////////////
+
+/// Writes one JSON object per line for each event, e.g.:
+/// ```json
+/// {"event":"generation","generation":1,"best":12,"worst":20,"mean":15.5,...}
+/// ```
+/// [`Event::Dynamic`] and [`Event::Restart`] objects additionally contain the
+/// identifier of the dynamic (`"dynamic"`) or the restart (`"restart"`).
+/// Non-finite numbers are written as `null`.
+///
+/// If writing to the underlying writer fails, the error is printed to stderr
+/// and the observer is disabled (the algorithm execution continues).
+pub struct JsonLines<W: Write> {
+    writer: W,
+    disabled: bool,
+}
+
+impl<W: Write> JsonLines<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, disabled: false }
+    }
+}
+
+impl JsonLines<BufWriter<File>> {
+    /// Creates (or truncates) the file at `path` and writes to it.
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<
+        W: Write + Send + Sync,
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+    > Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> for JsonLines<W>
+{
+    fn notify(
+        &mut self,
+        event: Event,
+        rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _population: &[(Ge, Ov)],
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        let mut line = format!(
+            "{{\"event\":\"{}\",\"generation\":{},\"best\":{},\"worst\":{},\
+             \"mean\":{},\"median\":{},\"variance\":{},\"std_dev\":{},\
+             \"diversity\":{},\"cache_hits\":{}",
+            event.name(),
+            rtd.generation,
+            rtd.best.to_usize(),
+            rtd.worst.to_usize(),
+            number(rtd.mean),
+            number(rtd.median),
+            number(rtd.variance),
+            number(rtd.std_dev),
+            number(rtd.diversity),
+            rtd.cache_hits,
+        );
+
+        if let Event::Dynamic(identifier) = event {
+            line.push_str(&format!(",\"dynamic\":{}", string(identifier)));
+        }
+
+        if let Event::Restart(identifier) = event {
+            line.push_str(&format!(",\"restart\":{}", string(identifier)));
+        }
+
+        line.push('}');
+
+        let result =
+            writeln!(self.writer, "{line}").and_then(|_| match event {
+                Event::Finish => self.writer.flush(),
+                _ => Ok(()),
+            });
+
+        if let Err(error) = result {
+            eprintln!(
+                "failed to write JSON lines log, disabling the observer: \
+                 {error}"
+            );
+            self.disabled = true;
+        }
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Formats a floating point number as JSON number (or `null`).
+fn number(x: f64) -> String {
+    if x.is_finite() {
+        x.to_string()
+    } else {
+        "null".into()
+    }
+}
+
+/// Formats a string as JSON string (incl. quotes and escapes).
+fn string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            c if c.is_control() => {
+                result.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+////////////////////////////////////////////////////////////////////////////////
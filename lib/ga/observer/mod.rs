@@ -0,0 +1,177 @@
+//! Observers receive the runtime data and the population of an algorithm
+//! execution at defined lifecycle events (see [`Event`]). They are used for
+//! logging the progress of an algorithm execution to different backends.
+//!
+//! Observers are added to the algorithm with
+//! [`Builder::add_observer`](crate::Builder::add_observer). Multiple observers
+//! can be added, and all of them are notified in the order they were added.
+//! If no observer is added, the progress is printed to stdout with [`Console`]
+//! (unless the `no_stdout_log` feature is enabled), also when logging to Rerun.
+//!
+//! The following backends are provided:
+//! -   [`Console`] prints the progress to stdout,
+//! -   [`Quiet`] does not output anything,
+//! -   [`Csv`] writes one row per generation,
+//! -   [`JsonLines`] writes one JSON object per event.
+//!
+//! ```rust ,ignore
+//! let alg = ga::Builder::new()
+//!     .set_encoding(encoding)
+//!     .set_parameters(parameters)
+//!     .set_dynamics::<()>(None)
+//!     .set_custom_logger::<()>(None)
+//!     .add_observer(ga::observer::Csv::create("run.csv").unwrap())
+//!     .add_observer(ga::observer::JsonLines::create("run.jsonl").unwrap())
+//!     .build();
+//! ```
+
+// Modules /////////////////////////////////////////////////////////////////////
+mod csv;
+mod json_lines;
+
+pub use csv::Csv;
+pub use json_lines::JsonLines;
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::{Crossover, Mutation},
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    runtime_data::RuntimeData,
+};
+
+// Event ///////////////////////////////////////////////////////////////////////
+
+/// Lifecycle events of an algorithm execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event<'a> {
+    /// The initial population was created (and the dynamics were set up).
+    Start,
+
+    /// A generation was finished.
+    Generation,
+
+    /// A generation improved on the best solution. This event is emitted after
+    /// the [`Event::Generation`] event of the same generation.
+    Improvement,
+
+    /// The dynamic with the given identifier modified the parameters or the
+    /// context (see [`Dynamic::exec`](crate::dynamics::Dynamic::exec)).
+    Dynamic(&'a str),
+
+    /// The population was restarted with the given restart identifier. This
+    /// event is emitted after the [`Event::Generation`] event of the same
+    /// generation.
+    Restart(&'a str),
+
+    /// The algorithm execution was finished.
+    Finish,
+}
+
+impl Event<'_> {
+    /// Returns the name of the event.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Generation => "generation",
+            Self::Improvement => "improvement",
+            Self::Dynamic(_) => "dynamic",
+            Self::Restart(_) => "restart",
+            Self::Finish => "finish",
+        }
+    }
+}
+
+// Trait ///////////////////////////////////////////////////////////////////////
+
+/// This trait is implemented by logging backends, which observe the algorithm
+/// execution.
+pub trait Observer<
+    Ov: ObjectiveValue + Into<T>,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+>: Send + Sync
+{
+    /// Is called at each lifecycle event of the algorithm execution. The
+    /// population is sorted ascendingly by objective value.
+    fn notify(
+        &mut self,
+        event: Event,
+        rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        population: &[(Ge, Ov)],
+    );
+}
+
+// Console /////////////////////////////////////////////////////////////////////
+
+/// Prints the progress of the algorithm execution to stdout. This is the
+/// default observer, if no other observers are added.
+pub struct Console;
+
+impl<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+    > Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> for Console
+{
+    fn notify(
+        &mut self,
+        event: Event,
+        rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _population: &[(Ge, Ov)],
+    ) {
+        if let Event::Generation | Event::Finish = event {
+            println!(
+                "[{}] best = {:?}, mean = {}, worst = {:?}, cache-hits = {}",
+                rtd.generation, rtd.best, rtd.mean, rtd.worst, rtd.cache_hits,
+            );
+        }
+    }
+}
+
+// Quiet ///////////////////////////////////////////////////////////////////////
+
+/// Does not output anything. Adding this observer disables the default
+/// [`Console`] output.
+pub struct Quiet;
+
+impl<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+    > Observer<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> for Quiet
+{
+    fn notify(
+        &mut self,
+        _event: Event,
+        _rtd: &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+        _population: &[(Ge, Ov)],
+    ) {
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,334 @@
+//! Adaptive operator selection: a portfolio of crossover and mutation
+//! operators, whose application probabilities adapt to the success of the
+//! operators during the execution.
+//!
+//! Every pair of parents is assigned one crossover and one mutation operator
+//! of the [`Portfolio`]. The credit of a pair is the fraction of its offspring,
+//! which are better than both parents (before a local search). It is credited
+//! to both operators, which produced the offspring. After every generation the operator
+//! probabilities are adapted with the [`Strategy`] of the portfolio.
+//!
+//! The portfolio is set on the algorithm builder (see
+//! [`Builder::set_operator_portfolio`](crate::Builder::set_operator_portfolio)).
+//! The usage and credit of every operator is recorded in the report
+//! ([`Report::operators`](crate::report::Report::operators)).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use super::{Crossover, Mutation};
+use crate::{
+    encoding::{Context, Genotype},
+    rng::GaRng,
+};
+use rand::Rng;
+
+// Strategy ////////////////////////////////////////////////////////////////////
+
+/// Adapts the probabilities of the operators of a portfolio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strategy {
+    /// Probability matching: the probabilities are proportional to the
+    /// quality estimates of the operators (with a minimum probability
+    /// `p_min`). The quality estimates follow the rewards with the
+    /// adaptation rate `alpha`.
+    ProbabilityMatching { p_min: f64, alpha: f64 },
+
+    /// Adaptive pursuit: the probability of the operator with the best
+    /// quality estimate is moved towards `1 - (K - 1) * p_min` with the
+    /// learning rate `beta`, all others towards `p_min`. The quality
+    /// estimates follow the rewards with the adaptation rate `alpha`.
+    AdaptivePursuit { p_min: f64, alpha: f64, beta: f64 },
+
+    /// Upper confidence bound (multi-armed bandit): the operator with the
+    /// highest `mean reward + c * sqrt(2 * ln(N) / n)` is chosen, where `n`
+    /// is the number of applications of the operator and `N` of all
+    /// operators. Within a generation, the assigned operators are counted as
+    /// applied, to spread the operators among the pairs of parents.
+    Ucb(f64),
+}
+
+impl Strategy {
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::ProbabilityMatching { p_min, alpha } => {
+                format!("pm-{:.2}-{:.2}", p_min, alpha)
+            }
+            Self::AdaptivePursuit { p_min, alpha, beta } => {
+                format!("ap-{:.2}-{:.2}-{:.2}", p_min, alpha, beta)
+            }
+            Self::Ucb(c) => format!("ucb-{:.2}", c),
+        }
+    }
+}
+
+// Portfolio ///////////////////////////////////////////////////////////////////
+
+/// Portfolio of crossover and mutation operators. If one of the lists is
+/// empty, the respective operator of the parameters is used instead.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Portfolio<Cr, Mu> {
+    pub crossovers: Vec<Cr>,
+    pub mutations: Vec<Mu>,
+    pub strategy: Strategy,
+}
+
+impl<Cr, Mu> Portfolio<Cr, Mu> {
+    pub fn new(strategy: Strategy) -> Self {
+        Self { crossovers: vec![], mutations: vec![], strategy }
+    }
+
+    pub fn add_crossover(mut self, crossover: Cr) -> Self {
+        self.crossovers.push(crossover);
+        self
+    }
+
+    pub fn add_mutation(mut self, mutation: Mu) -> Self {
+        self.mutations.push(mutation);
+        self
+    }
+
+    pub fn identifier<Ctx: Context, Ge: Genotype<Ctx>>(&self) -> String
+    where
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+    {
+        let crossovers: Vec<String> =
+            self.crossovers.iter().map(|cx| cx.identifier()).collect();
+        let mutations: Vec<String> =
+            self.mutations.iter().map(|mu| mu.identifier()).collect();
+
+        format!(
+            "{}[{}][{}]",
+            self.strategy.identifier(),
+            crossovers.join("|"),
+            mutations.join("|")
+        )
+    }
+
+    /// Returns the initial adaptation states of the crossover and mutation
+    /// operators (`None` for empty lists).
+    pub fn bandits(&self) -> (Option<Bandit>, Option<Bandit>) {
+        (
+            (!self.crossovers.is_empty())
+                .then(|| Bandit::new(self.crossovers.len())),
+            (!self.mutations.is_empty())
+                .then(|| Bandit::new(self.mutations.len())),
+        )
+    }
+
+    /// Returns the usage and credit of all operators of the portfolio.
+    pub fn stats<Ctx: Context, Ge: Genotype<Ctx>>(
+        &self,
+        crossover_bandit: Option<&Bandit>,
+        mutation_bandit: Option<&Bandit>,
+    ) -> Vec<OperatorStats>
+    where
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+    {
+        let crossovers: Vec<String> =
+            self.crossovers.iter().map(|cx| cx.identifier()).collect();
+        let mutations: Vec<String> =
+            self.mutations.iter().map(|mu| mu.identifier()).collect();
+
+        let mut stats = vec![];
+        if let Some(bandit) = crossover_bandit {
+            stats.extend(bandit.stats("crossover", &crossovers));
+        }
+        if let Some(bandit) = mutation_bandit {
+            stats.extend(bandit.stats("mutation", &mutations));
+        }
+
+        stats
+    }
+}
+
+// Bandit //////////////////////////////////////////////////////////////////////
+
+/// Adaptation state of the operators of one kind (crossover or mutation).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bandit {
+    /// Quality estimates (mean rewards for UCB).
+    pub quality: Vec<f64>,
+
+    /// Current application probabilities (share of the applications in the
+    /// last generation for UCB).
+    pub probability: Vec<f64>,
+
+    /// Total number of applications.
+    pub usage: Vec<usize>,
+
+    /// Total credit.
+    pub credit: Vec<f64>,
+}
+
+impl Bandit {
+    pub fn new(operators: usize) -> Self {
+        Self {
+            quality: vec![0.; operators],
+            probability: vec![1. / operators as f64; operators],
+            usage: vec![0; operators],
+            credit: vec![0.; operators],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.quality.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.quality.is_empty()
+    }
+
+    /// Assigns an operator to each of the given number of pairs of parents.
+    pub fn assign(
+        &self,
+        strategy: &Strategy,
+        pairs: usize,
+        rng: &mut GaRng,
+    ) -> Vec<usize> {
+        match strategy {
+            Strategy::Ucb(c) => {
+                let mut usage = self.usage.clone();
+                (0..pairs)
+                    .map(|_| {
+                        let total: usize = usage.iter().sum();
+                        let ucb = |i: usize| match usage[i] {
+                            0 => f64::INFINITY,
+                            n => {
+                                self.quality[i]
+                                    + c * (2. * (total as f64).ln() / n as f64)
+                                        .sqrt()
+                            }
+                        };
+
+                        let arm = (0..self.len())
+                            .max_by(|a, b| ucb(*a).total_cmp(&ucb(*b)))
+                            .unwrap_or(0);
+
+                        usage[arm] += 1;
+                        arm
+                    })
+                    .collect()
+            }
+            _ => (0..pairs).map(|_| self.sample(rng)).collect(),
+        }
+    }
+
+    /// Samples an operator according to the current probabilities.
+    fn sample(&self, rng: &mut GaRng) -> usize {
+        let mut x = rng.gen::<f64>() * self.probability.iter().sum::<f64>();
+        for (i, p) in self.probability.iter().enumerate() {
+            if x < *p {
+                return i;
+            }
+            x -= p;
+        }
+
+        self.len() - 1
+    }
+
+    /// Credits the rewards (operator, credit) of one generation and adapts
+    /// the probabilities.
+    pub fn update(&mut self, strategy: &Strategy, rewards: &[(usize, f64)]) {
+        let k = self.len();
+        let mut usage = vec![0; k];
+        let mut credit = vec![0.; k];
+        for (i, reward) in rewards {
+            usage[*i] += 1;
+            credit[*i] += reward;
+        }
+
+        for i in 0..k {
+            self.usage[i] += usage[i];
+            self.credit[i] += credit[i];
+        }
+
+        match *strategy {
+            Strategy::ProbabilityMatching { p_min, alpha }
+            | Strategy::AdaptivePursuit { p_min, alpha, .. } => {
+                // Quality estimates of the used operators
+                for i in (0..k).filter(|i| usage[*i] > 0) {
+                    let reward = credit[i] / usage[i] as f64;
+                    self.quality[i] += alpha * (reward - self.quality[i]);
+                }
+
+                let p_min = p_min.clamp(0., 1. / k as f64);
+                if let Strategy::AdaptivePursuit { beta, .. } = *strategy {
+                    let p_max = 1. - (k - 1) as f64 * p_min;
+                    let best = (0..k)
+                        .max_by(|a, b| {
+                            self.quality[*a].total_cmp(&self.quality[*b])
+                        })
+                        .unwrap_or(0);
+
+                    for (i, p) in self.probability.iter_mut().enumerate() {
+                        let target = if i == best { p_max } else { p_min };
+                        *p += beta * (target - *p);
+                    }
+                } else {
+                    // Uniform probabilities, until an operator is credited
+                    let total: f64 = self.quality.iter().sum();
+                    let share = |q: f64| {
+                        if total > 0. {
+                            q / total
+                        } else {
+                            1. / k as f64
+                        }
+                    };
+
+                    for (p, q) in self.probability.iter_mut().zip(&self.quality)
+                    {
+                        *p = p_min + (1. - k as f64 * p_min) * share(*q);
+                    }
+                }
+            }
+            Strategy::Ucb(_) => {
+                let total = rewards.len().max(1) as f64;
+                for (i, used) in usage.iter().enumerate() {
+                    if self.usage[i] > 0 {
+                        self.quality[i] = self.credit[i] / self.usage[i] as f64;
+                    }
+                    self.probability[i] = *used as f64 / total;
+                }
+            }
+        }
+    }
+
+    /// Returns the statistics of the operators with the given identifiers.
+    fn stats(&self, kind: &str, identifiers: &[String]) -> Vec<OperatorStats> {
+        identifiers
+            .iter()
+            .enumerate()
+            .map(|(i, identifier)| OperatorStats {
+                kind: kind.into(),
+                identifier: identifier.clone(),
+                usage: self.usage[i],
+                credit: self.credit[i],
+                probability: self.probability[i],
+            })
+            .collect()
+    }
+}
+
+// Operator Stats //////////////////////////////////////////////////////////////
+
+/// Usage and credit of one operator of the portfolio.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorStats {
+    /// Kind of the operator ("crossover" or "mutation").
+    pub kind: String,
+    pub identifier: String,
+
+    /// Number of pairs of parents, the operator was applied to.
+    pub usage: usize,
+
+    /// Total credit of the operator.
+    pub credit: f64,
+
+    /// Application probability of the operator in the last generation.
+    pub probability: f64,
+}
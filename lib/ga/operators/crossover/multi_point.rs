@@ -1,15 +1,15 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use rand::{prelude::SliceRandom, rngs::ThreadRng, Rng};
+use rand::{prelude::SliceRandom, Rng};
 
 // Function ////////////////////////////////////////////////////////////////////
 /// Multi point crossover. If a crossover rate of 100% is desired, use `None`
 /// as parameter for the `rate`. This will skip the random number sampling.
-pub fn multi_point<'a, T>(
+pub fn multi_point<'a, T, R: Rng>(
     a: &'a [T],
     b: &'a [T],
     rate: Option<f32>,
     num_splits: usize,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) -> (Vec<&'a T>, Vec<&'a T>) {
     // Decide whether the crossover should be performed or not.
     if let Some(rate) = rate {
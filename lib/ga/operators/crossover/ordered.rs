@@ -1,14 +1,14 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use rand::{prelude::SliceRandom, rngs::ThreadRng, Rng};
+use rand::{prelude::SliceRandom, Rng};
 
 // Function ////////////////////////////////////////////////////////////////////
 /// Ordered Crossover. If a crossover rate of 100% is desired, use `None`
 /// as parameter for the `rate`. This will skip the random number sampling.
-pub fn ordered<T: Clone + PartialEq + Eq>(
+pub fn ordered<T: Clone + PartialEq + Eq, R: Rng>(
     a: Vec<T>,
     b: Vec<T>,
     rate: Option<f32>,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) -> (Vec<T>, Vec<T>) {
     // Decide whether the crossover should be performed or not.
     if let Some(rate) = rate {
@@ -1,16 +1,16 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use hashbrown::HashMap;
-use rand::{prelude::SliceRandom, rngs::ThreadRng, Rng};
+use rand::{prelude::SliceRandom, Rng};
 use std::hash::Hash;
 
 // Function ////////////////////////////////////////////////////////////////////
 /// PMX Crossover. If a crossover rate of 100% is desired, use `None`
 /// as parameter for the `rate`. This will skip the random number sampling.
-pub fn pmx<'a, T: Eq + Hash>(
+pub fn pmx<'a, T: Eq + Hash, R: Rng>(
     a: &'a [T],
     b: &'a [T],
     rate: Option<f32>,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) -> (Vec<&'a T>, Vec<&'a T>) {
     // Decide whether the crossover should be performed or not.
     if let Some(rate) = rate {
@@ -1,15 +1,15 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use rand::{rngs::ThreadRng, Rng};
+use rand::Rng;
 
 // Function ////////////////////////////////////////////////////////////////////
 
 /// Uniform crossover. If a crossover rate of 100% is desired, use `None`
 /// as parameter for the `rate`. This will skip the random number sampling.
-pub fn uniform<'a, T>(
+pub fn uniform<'a, T, R: Rng>(
     a: &'a [T],
     b: &'a [T],
     rate: Option<f32>,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) -> (Vec<&'a T>, Vec<&'a T>) {
     // Decide whether the crossover should be performed or not.
     if let Some(rate) = rate {
@@ -0,0 +1,168 @@
+//! Memetic algorithms combine the genetic search with a local search, which
+//! improves individual offspring (see [`LocalSearch`](super::LocalSearch)).
+//!
+//! The local search is executed on the offspring after mutation and before
+//! rejection. Which offspring are improved is defined by the [`Policy`], and
+//! how the result is used is defined by the [`Mode`]:
+//! -   [`Mode::Lamarckian`] writes the improved chromosome back into the
+//!     offspring (acquired traits are inherited),
+//! -   [`Mode::Baldwinian`] keeps the original chromosome and only assigns it
+//!     the improved objective value (the ability to learn is inherited).
+//!
+//! Both are configured in the parameters (see
+//! [`parameters::Builder::set_local_search`](crate::parameters::Builder::set_local_search)),
+//! while the local search operator itself is added to the algorithm with
+//! [`Builder::set_local_search`](crate::Builder::set_local_search).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use super::LocalSearch;
+use crate::{
+    encoding::{Context, Encoding, Genotype, ObjectiveValue, Phenotype},
+    rng::GaRng,
+};
+use rand::Rng;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Configuration ///////////////////////////////////////////////////////////////
+
+/// Defines to which offspring the local search is applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Policy {
+    /// All offspring are improved.
+    All,
+
+    /// Only offspring, which are at least as good as the worst individual of
+    /// the elite of the current population, are improved. If the replacement
+    /// method has no elite, the best individual is used instead.
+    Elite,
+
+    /// Each offspring is improved with the given probability.
+    Probability(f32),
+}
+
+/// Defines how the result of the local search is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mode {
+    /// The improved chromosome (and its objective value) replaces the
+    /// offspring.
+    Lamarckian,
+
+    /// The offspring keeps its chromosome, but gets the objective value of
+    /// the improved chromosome.
+    Baldwinian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub policy: Policy,
+    pub mode: Mode,
+}
+
+impl Config {
+    pub fn identifier(&self) -> String {
+        let policy = match self.policy {
+            Policy::All => "all".into(),
+            Policy::Elite => "elite".into(),
+            Policy::Probability(p) => format!("p-{:.2}", p),
+        };
+
+        let mode = match self.mode {
+            Mode::Lamarckian => "lam",
+            Mode::Baldwinian => "bald",
+        };
+
+        format!("{policy}-{mode}")
+    }
+}
+
+// Evaluator ///////////////////////////////////////////////////////////////////
+
+/// Calculates objective values for the local search and counts the
+/// evaluations, so that they are part of the runtime data.
+pub struct Evaluator<'a, Ge, Ov> {
+    evaluate: &'a dyn Fn(&Ge) -> Ov,
+    evaluations: usize,
+}
+
+impl<'a, Ge, Ov> Evaluator<'a, Ge, Ov> {
+    pub fn new(evaluate: &'a dyn Fn(&Ge) -> Ov) -> Self {
+        Self { evaluate, evaluations: 0 }
+    }
+
+    /// Calculates the objective value of the given chromosome.
+    pub fn evaluate(&mut self, chromosome: &Ge) -> Ov {
+        self.evaluations += 1;
+        (self.evaluate)(chromosome)
+    }
+
+    /// Returns the number of evaluations so far.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Executes the local search on the given offspring, if the policy applies to
+/// it. The evaluations of the local search are added to `evaluations`.
+pub(crate) fn improve<
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Ph: Phenotype<Ov, Ctx, Ge>,
+>(
+    offspring: (Ge, Ov),
+    local_search: Option<&dyn LocalSearch<Ov, Ctx, Ge>>,
+    config: Option<&Config>,
+    encoding: &Encoding<Ov, Ctx, Ge, Ph>,
+    elite_threshold: &Ov,
+    rng: &mut GaRng,
+    evaluations: &AtomicUsize,
+) -> (Ge, Ov) {
+    let (Some(local_search), Some(config)) = (local_search, config) else {
+        return offspring;
+    };
+
+    let apply = match config.policy {
+        Policy::All => true,
+        Policy::Elite => offspring.1 <= *elite_threshold,
+        Policy::Probability(p) => rng.gen_bool(p.clamp(0., 1.) as f64),
+    };
+
+    if !apply {
+        return offspring;
+    }
+
+    let evaluate = |chromosome: &Ge| {
+        encoding
+            .phenotype
+            .derive(chromosome, &encoding.context)
+            .evaluate(&encoding.context)
+    };
+
+    let mut evaluator = Evaluator::new(&evaluate);
+    let (chromosome, ov) = local_search.exec(
+        &offspring.0,
+        &offspring.1,
+        &mut evaluator,
+        rng,
+        &encoding.context,
+    );
+
+    evaluations.fetch_add(evaluator.evaluations(), Ordering::Relaxed);
+
+    // Only accept improvements
+    if ov >= offspring.1 {
+        return offspring;
+    }
+
+    match config.mode {
+        Mode::Lamarckian => (chromosome, ov),
+        Mode::Baldwinian => (offspring.0, ov),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -1,10 +1,15 @@
 // Modules /////////////////////////////////////////////////////////////////////
+pub mod adaptive;
 pub mod crossover;
+pub mod local_search;
 pub mod mutation;
 
 // Imports /////////////////////////////////////////////////////////////////////
-use crate::encoding::{Context, Genotype};
-use rand::rngs::ThreadRng;
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    rng::GaRng,
+};
+use local_search::Evaluator;
 
 // Crossover ///////////////////////////////////////////////////////////////////
 
@@ -25,7 +30,7 @@ pub trait Crossover<Ctx: Context, Ge: Genotype<Ctx>>: Send + Sync {
         parent_0: &Ge,
         parent_1: &Ge,
         rate: Option<f32>,
-        rng: &mut ThreadRng,
+        rng: &mut GaRng,
         context: &Ctx,
     ) -> (Ge, Ge);
 
@@ -50,10 +55,50 @@ pub trait Mutation<Ctx: Context, Ge: Genotype<Ctx>>: Send + Sync {
         &self,
         chromosome: &mut Ge,
         rate: f32,
-        rng: &mut ThreadRng,
+        rng: &mut GaRng,
         context: &Ctx,
     );
 
+    /// Mutates the chromosome like [`Mutation::exec`] and returns the indices
+    /// of the changed genes, which enables the incremental evaluation of the
+    /// offspring (see [`Phenotype::evaluate_delta`]). By default, the changed
+    /// genes are unknown (`None`).
+    ///
+    /// [`Phenotype::evaluate_delta`]: crate::encoding::Phenotype::evaluate_delta
+    fn exec_tracked(
+        &self,
+        chromosome: &mut Ge,
+        rate: f32,
+        rng: &mut GaRng,
+        context: &Ctx,
+    ) -> Option<Vec<usize>> {
+        self.exec(chromosome, rate, rng, context);
+        None
+    }
+
+    fn identifier(&self) -> String;
+}
+
+// Local Search ////////////////////////////////////////////////////////////////
+
+/// This trait is implemented by local search methods (e.g. a hill climber),
+/// which improve single offspring (see [`crate::operators::local_search`]).
+///
+/// All objective values must be calculated with the passed `evaluator`, which
+/// counts the evaluations for the runtime data. If the returned individual is
+/// not better than the passed one, the offspring remains unchanged.
+pub trait LocalSearch<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>:
+    Send + Sync
+{
+    fn exec(
+        &self,
+        chromosome: &Ge,
+        objective_value: &Ov,
+        evaluator: &mut Evaluator<Ge, Ov>,
+        rng: &mut GaRng,
+        context: &Ctx,
+    ) -> (Ge, Ov);
+
     fn identifier(&self) -> String;
 }
 
@@ -1,5 +1,5 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use rand::{rngs::ThreadRng, Rng};
+use rand::Rng;
 
 // Functions ///////////////////////////////////////////////////////////////////
 
@@ -9,11 +9,15 @@ use rand::{rngs::ThreadRng, Rng};
 /// Genes may have different value ranges. For this case check out the
 /// [`randomize_multi_dist`] functions.
 ///
-pub fn randomize_single_dist<T, D: rand::distributions::Distribution<T>>(
+pub fn randomize_single_dist<
+    T,
+    D: rand::distributions::Distribution<T>,
+    R: Rng,
+>(
     chromosome: &mut [T],
     rate: f32,
     generator: &D,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) {
     for gene in chromosome.iter_mut() {
         // Decide wether to mutate or not
@@ -34,11 +38,15 @@ pub fn randomize_single_dist<T, D: rand::distributions::Distribution<T>>(
 /// takes the `generators` parameter, which contains a separate random number
 /// generator for each gene. Therefore the index of the gene in the chromosome
 /// corresponds to the index for the respective random number generator.
-pub fn randomize_multi_dist<T, D: rand::distributions::Distribution<T>>(
+pub fn randomize_multi_dist<
+    T,
+    D: rand::distributions::Distribution<T>,
+    R: Rng,
+>(
     chromosome: &mut [T],
     rate: f32,
     generators: &[D],
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) {
     assert_eq!(chromosome.len(), generators.len());
 
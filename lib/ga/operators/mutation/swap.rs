@@ -1,16 +1,19 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use rand::{rngs::ThreadRng, Rng};
+use rand::Rng;
 use rand_distr::Distribution;
 
 // Functions ///////////////////////////////////////////////////////////////////
 
 /// Swaps the genes based on a single random number distribution, which
 /// is passed as parameter and creates valid gene values for all genes.
-pub fn swap_uniform_dist<D: rand::distributions::Distribution<usize>>(
+pub fn swap_uniform_dist<
+    D: rand::distributions::Distribution<usize>,
+    R: Rng,
+>(
     chromosome: &mut [usize],
     rate: f32,
     generator: &D,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) {
     for i in 0..chromosome.len() {
         // Decide wether to mutate or not
@@ -29,11 +32,14 @@ pub fn swap_uniform_dist<D: rand::distributions::Distribution<usize>>(
     }
 }
 
-pub fn swap_uniform_dist_u8<D: rand::distributions::Distribution<u8>>(
+pub fn swap_uniform_dist_u8<
+    D: rand::distributions::Distribution<u8>,
+    R: Rng,
+>(
     chromosome: &mut [u8],
     rate: f32,
     generator: &D,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) {
     for i in 0..chromosome.len() {
         // Decide wether to mutate or not
@@ -52,11 +58,11 @@ pub fn swap_uniform_dist_u8<D: rand::distributions::Distribution<u8>>(
     }
 }
 
-pub fn swap_normal_dist(
+pub fn swap_normal_dist<R: Rng>(
     chromosome: &mut [usize],
     rate: f32,
     generator: &rand_distr::Normal<f32>,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) {
     for i in 0..chromosome.len() {
         // Decide wether to mutate or not
@@ -80,11 +86,11 @@ pub fn swap_normal_dist(
     }
 }
 
-pub fn swap_normal_dist_u8(
+pub fn swap_normal_dist_u8<R: Rng>(
     chromosome: &mut [u8],
     rate: f32,
     generator: &rand_distr::Normal<f32>,
-    rng: &mut ThreadRng,
+    rng: &mut R,
 ) {
     for i in 0..chromosome.len() {
         // Decide wether to mutate or not
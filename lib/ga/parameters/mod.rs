@@ -2,14 +2,25 @@
 use std::marker::PhantomData;
 use crate::{
     encoding::{Encoding, Context, Genotype, ObjectiveValue, Phenotype},
-    operators::{Crossover, Mutation},
+    operators::{local_search, Crossover, Mutation},
     process::{
         rejection::Rejection, replacement::Replacement, selection::Selection,
         termination::Termination
     },
+    niching, restart, self_adaptation,
 };
 
 // Parameter ///////////////////////////////////////////////////////////////////
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(
+    serialize = "Ov: serde::Serialize, Cr: serde::Serialize, \
+        Mu: serde::Serialize, Se: serde::Serialize, Re: serde::Serialize, \
+        Rp: serde::Serialize, Te: serde::Serialize",
+    deserialize = "Ov: serde::de::DeserializeOwned, \
+        Cr: serde::de::DeserializeOwned, Mu: serde::de::DeserializeOwned, \
+        Se: serde::de::DeserializeOwned, Re: serde::de::DeserializeOwned, \
+        Rp: serde::de::DeserializeOwned, Te: serde::de::DeserializeOwned"
+)))]
 pub struct Parameters<
     Ov: ObjectiveValue + Into<T>,
     Ctx: Context,
@@ -35,11 +46,36 @@ pub struct Parameters<
     pub replacement: Rp,
     pub termination: Te,
 
+    /// Seed for all random number generators of the algorithm. If no seed is
+    /// given, a random seed is chosen (and stored in the report).
+    pub seed: Option<u64>,
+
+    /// Policy and mode of the local search. The local search is only executed
+    /// if additionally an operator is added to the algorithm (see
+    /// [`crate::operators::local_search`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub local_search: Option<local_search::Config>,
+
+    /// Restart strategy, which is applied when the population stagnates (see
+    /// [`crate::restart`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restart: Option<restart::Restart>,
+
+    /// Niching method, which keeps several distinct solutions alive (see
+    /// [`crate::niching`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub niching: Option<niching::Niching>,
+
+    /// Self-adaptation of the mutation parameters, which are carried by the
+    /// individuals (see [`crate::self_adaptation`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub self_adaptation: Option<self_adaptation::SelfAdaptation>,
+
     // PhantomData
-    objective_value: PhantomData<Ov>,
-    context: PhantomData<Ctx>,
-    genotype: PhantomData<Ge>,
-    t: PhantomData<T>,
+    #[cfg_attr(feature = "serde", serde(skip))] objective_value: PhantomData<Ov>,
+    #[cfg_attr(feature = "serde", serde(skip))] context: PhantomData<Ctx>,
+    #[cfg_attr(feature = "serde", serde(skip))] genotype: PhantomData<Ge>,
+    #[cfg_attr(feature = "serde", serde(skip))] t: PhantomData<T>,
 }
 
 
@@ -294,6 +330,12 @@ pub struct Builder<
     replacement: TsRp,
     termination: TsTe,
 
+    // Optional
+    seed: Option<u64>,
+    local_search: Option<local_search::Config>,
+    restart: Option<restart::Restart>,
+    niching: Option<niching::Niching>,
+    self_adaptation: Option<self_adaptation::SelfAdaptation>,
 
     // PhantomData (from encoding)
     objective_value: PhantomData<Ov>,
@@ -330,6 +372,12 @@ impl<
             replacement: (),
             termination: (),
 
+            seed: None,
+            local_search: None,
+            restart: None,
+            niching: None,
+            self_adaptation: None,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -372,6 +420,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -414,6 +468,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -456,6 +516,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -502,6 +568,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -548,6 +620,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -594,6 +672,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -640,6 +724,12 @@ impl<
             replacement: self.replacement,
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -686,6 +776,12 @@ impl<
             replacement: replacement.into(),
             termination: self.termination,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -732,6 +828,12 @@ impl<
             replacement: self.replacement,
             termination: termination.into(),
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             objective_value: PhantomData,
             context: PhantomData,
@@ -742,6 +844,77 @@ impl<
     }
 }
 
+// set_seed --------------------------------------------------------------------
+impl<
+    Ov: ObjectiveValue + Into<T>,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Ph: Phenotype<Ov, Ctx, Ge>,
+    T,
+    //
+    TsPs: TS_PopulationSize,
+    TsCrr: TS_CrossoverRate,
+    TsMur: TS_MutationRate,
+    TsSe: TS_Selection,
+    TsCr: TS_Crossover,
+    TsMu: TS_Mutation,
+    TsRe: TS_Rejection,
+    TsRp: TS_Replacement,
+    TsTe: TS_Termination,
+> Builder<Ov, Ctx, Ge, Ph, T, TsPs, TsCrr, TsMur, TsSe, TsCr, TsMu, TsRe, TsRp, TsTe> {
+    /// Set the seed for all random number generators of the algorithm. Two
+    /// executions with the same seed and parameters yield the same report.
+    /// Setting the seed is optional and possible in any state of the builder.
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Enable the local search with the given policy and mode (see
+    /// [`crate::operators::local_search`]). Setting the local search is
+    /// optional and possible in any state of the builder.
+    pub fn set_local_search(
+        mut self,
+        policy: local_search::Policy,
+        mode: local_search::Mode
+    ) -> Self {
+        self.local_search = Some(local_search::Config { policy, mode });
+        self
+    }
+
+    /// Enable restarts of the population with the given trigger and strategy,
+    /// keeping the given number of best individuals (see [`crate::restart`]).
+    /// Setting the restart is optional and possible in any state of the
+    /// builder.
+    pub fn set_restart(
+        mut self,
+        trigger: restart::Trigger,
+        strategy: restart::Strategy,
+        elite: usize
+    ) -> Self {
+        self.restart = Some(restart::Restart { trigger, strategy, elite });
+        self
+    }
+
+    /// Enable the given niching method (see [`crate::niching`]). Setting the
+    /// niching method is optional and possible in any state of the builder.
+    pub fn set_niching(mut self, niching: niching::Niching) -> Self {
+        self.niching = Some(niching);
+        self
+    }
+
+    /// Enable the self-adaptation of the mutation parameters (see
+    /// [`crate::self_adaptation`]). Setting the self-adaptation is optional
+    /// and possible in any state of the builder.
+    pub fn set_self_adaptation(
+        mut self,
+        self_adaptation: self_adaptation::SelfAdaptation
+    ) -> Self {
+        self.self_adaptation = Some(self_adaptation);
+        self
+    }
+}
+
 // build -----------------------------------------------------------------------
 impl<
     Ov: ObjectiveValue + Into<T>,
@@ -770,7 +943,31 @@ impl<
     W_Mutation<Ctx, Ge, Mu>, W_Rejection<Ov, Ctx, Ge, Re>,
     W_Replacement<Ov, Ctx, Ge, Rp>, W_Termination<Ov, Te>
 > {
+    /// Builds the parameters.
+    ///
+    /// # Panics
+    /// Panics if the replacement method can not preserve the population size
+    /// (e.g. (μ,λ) with λ < μ). The largest population size of a restart,
+    /// which doubles the population, is checked as well.
     pub fn build(self) -> Parameters<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> {
+        let mut sizes = vec![self.population_size.0];
+        if let Some(restart::Restart {
+            strategy: restart::Strategy::DoublePopulation(max), ..
+        }) = self.restart {
+            sizes.push(max.max(self.population_size.0));
+        }
+
+        for size in sizes {
+            let replacement = &self.replacement.0;
+            let (offspring, _) = replacement.selection_size(size);
+            assert!(
+                replacement.elite_size(size) + offspring >= size,
+                "the replacement `{}` can not preserve the population size {}",
+                replacement.identifier(),
+                size
+            );
+        }
+
         Parameters {
             population_size: self.population_size.0,
             crossover_rate: self.crossover_rate.0,
@@ -782,6 +979,12 @@ impl<
             replacement: self.replacement.0,
             termination: self.termination.0,
 
+            seed: self.seed,
+            local_search: self.local_search,
+            restart: self.restart,
+            niching: self.niching,
+            self_adaptation: self.self_adaptation,
+
             // PhantomData
             context: PhantomData,
             objective_value: PhantomData,
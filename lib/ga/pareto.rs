@@ -0,0 +1,335 @@
+//! Multi-objective optimization in the style of NSGA-II.
+//!
+//! Objective values which consist of multiple objectives implement
+//! [`MultiObjective`] (additionally to [`ObjectiveValue`]). All objectives are
+//! minimized. Instead of a weighted sum of the objectives, the individuals are
+//! compared by their Pareto rank (non-dominated sorting) and their crowding
+//! distance within their front:
+//! -   [`Select::CrowdedTournament`] is a tournament selection, which prefers
+//!     individuals with a lower rank and (on equal rank) a larger crowding
+//!     distance.
+//! -   [`Replace::Nsga2`] merges the population with the offspring and keeps
+//!     the best individuals in terms of rank and crowding distance.
+//!
+//! The total order ([`Ord`]) of the objective value is still required by the
+//! framework, because the population is sorted by it and the runtime data (best,
+//! worst, mean, ...) as well as the termination criterion are based on it.
+//! A lexicographic order of the objectives is usually a sensible choice.
+//!
+//! The final Pareto front can be retrieved from the report with
+//! [`Report::pareto_front`](crate::report::Report::pareto_front).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    process::{replacement::Replacement, selection::Selection},
+    rng::GaRng,
+};
+use hashbrown::HashSet;
+use rand::Rng;
+use std::{cmp::Ordering, hash::Hash};
+
+// Multi Objective /////////////////////////////////////////////////////////////
+
+/// This trait must be implemented by objective values, which consist of
+/// multiple objectives (e.g. the costs of hard and soft constraints).
+pub trait MultiObjective: ObjectiveValue {
+    /// Returns the values of the individual objectives. All objectives are
+    /// minimized and every objective value must return the same number of
+    /// objectives.
+    fn objectives(&self) -> Vec<f64>;
+}
+
+// Selection ///////////////////////////////////////////////////////////////////
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Select {
+    /// Tournament selection with the given tournament size, which uses the
+    /// crowded comparison operator of NSGA-II.
+    CrowdedTournament(usize),
+}
+
+impl<Ov: MultiObjective + Into<T>, Ctx: Context, Ge: Genotype<Ctx>, T>
+    Selection<Ov, Ctx, Ge, T> for Select
+{
+    fn exec<'a>(
+        &self,
+        amount: usize,
+        individuals: &'a [(Ge, Ov)],
+        rng: &mut GaRng,
+    ) -> (Vec<&'a (Ge, Ov)>, usize) {
+        match self {
+            Self::CrowdedTournament(size) => {
+                crowded_tournament(*size, amount, individuals, rng)
+            }
+        }
+    }
+
+    fn identifier(&self) -> String {
+        match self {
+            Self::CrowdedTournament(n) => format!("crwd-trn-{n}"),
+        }
+    }
+}
+
+// Replacement /////////////////////////////////////////////////////////////////
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Replace {
+    /// The population and the offspring (of the same size) are merged, and
+    /// the next population is filled front by front. The last front, which
+    /// does not fit completely, is truncated by crowding distance.
+    Nsga2,
+}
+
+impl<Ge, Ov: MultiObjective> Replacement<(Ge, Ov)> for Replace {
+    fn elite_size(&self, _population_size: usize) -> usize {
+        0
+    }
+
+    fn selection_size(&self, population_size: usize) -> (usize, usize) {
+        // Ensure selection size is a multiple of two
+        (population_size, population_size + population_size % 2)
+    }
+
+    fn exec(
+        &self,
+        population: &mut Vec<(Ge, Ov)>,
+        offspring: Vec<(Ge, Ov)>,
+        _rng: &mut GaRng,
+    ) {
+        match self {
+            Self::Nsga2 => nsga2_replacement(population, offspring),
+        }
+    }
+
+    fn identifier(&self) -> String {
+        match self {
+            Self::Nsga2 => "nsga2".into(),
+        }
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Returns `true`, if the objectives `a` dominate the objectives `b`. This is
+/// the case, if `a` is not worse than `b` in any objective and better in at
+/// least one objective.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    let mut better = false;
+
+    for (x, y) in a.iter().zip(b) {
+        if x > y {
+            return false;
+        }
+
+        if x < y {
+            better = true;
+        }
+    }
+
+    better
+}
+
+/// Fast non-dominated sorting (Deb et al.). Returns the fronts as lists of
+/// indices into `objectives`. The first front contains all non-dominated
+/// individuals, the second front all individuals which are only dominated by
+/// the first front and so on.
+pub fn non_dominated_sort(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let n = objectives.len();
+
+    // For each individual: the individuals it dominates and the number of
+    // individuals which dominate it.
+    let mut dominated: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut domination_count: Vec<usize> = vec![0; n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if dominates(&objectives[i], &objectives[j]) {
+                dominated[i].push(j);
+                domination_count[j] += 1;
+            } else if dominates(&objectives[j], &objectives[i]) {
+                dominated[j].push(i);
+                domination_count[i] += 1;
+            }
+        }
+    }
+
+    // Peel off the fronts
+    let mut fronts: Vec<Vec<usize>> = vec![];
+    let mut current: Vec<usize> =
+        (0..n).filter(|i| domination_count[*i] == 0).collect();
+
+    while !current.is_empty() {
+        let mut next = vec![];
+        for i in &current {
+            for j in &dominated[*i] {
+                domination_count[*j] -= 1;
+                if domination_count[*j] == 0 {
+                    next.push(*j);
+                }
+            }
+        }
+
+        fronts.push(current);
+        current = next;
+    }
+
+    fronts
+}
+
+/// Calculates the crowding distance of the individuals in the given `front`
+/// (indices into `objectives`). The result is aligned with `front`. Boundary
+/// individuals of each objective get an infinite distance.
+pub fn crowding_distance(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    let mut distance = vec![0.; front.len()];
+    if front.len() < 3 {
+        distance.iter_mut().for_each(|d| *d = f64::INFINITY);
+        return distance;
+    }
+
+    // Values of the front, grouped by objective
+    let columns: Vec<Vec<f64>> = (0..objectives[front[0]].len())
+        .map(|m| front.iter().map(|i| objectives[*i][m]).collect())
+        .collect();
+
+    let mut order: Vec<usize> = (0..front.len()).collect();
+    for values in &columns {
+        order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+        let first = order[0];
+        let last = order[order.len() - 1];
+        distance[first] = f64::INFINITY;
+        distance[last] = f64::INFINITY;
+
+        let range = values[last] - values[first];
+        if range <= 0. {
+            continue;
+        }
+
+        for w in order.windows(3) {
+            distance[w[1]] += (values[w[2]] - values[w[0]]) / range;
+        }
+    }
+
+    distance
+}
+
+/// Calculates the Pareto rank (index of the front, starting at 0) and the
+/// crowding distance of each individual.
+pub fn rank<Ge, Ov: MultiObjective>(
+    individuals: &[(Ge, Ov)],
+) -> Vec<(usize, f64)> {
+    let objectives: Vec<Vec<f64>> =
+        individuals.iter().map(|(_, ov)| ov.objectives()).collect();
+
+    let mut result = vec![(0, 0.); individuals.len()];
+    for (r, front) in non_dominated_sort(&objectives).iter().enumerate() {
+        let distance = crowding_distance(&objectives, front);
+        for (i, d) in front.iter().zip(distance) {
+            result[*i] = (r, d);
+        }
+    }
+
+    result
+}
+
+/// Crowded comparison operator of NSGA-II: a lower rank is better, and on
+/// equal rank a larger crowding distance is better. `Ordering::Less` means
+/// `a` is better than `b`.
+pub fn crowded_cmp(a: &(usize, f64), b: &(usize, f64)) -> Ordering {
+    a.0.cmp(&b.0).then_with(|| b.1.total_cmp(&a.1))
+}
+
+/// Returns the first front (non-dominated individuals) of the given
+/// individuals. Duplicate chromosomes are only contained once.
+pub fn pareto_front<Ge: Eq + Hash, Ov: MultiObjective>(
+    individuals: &[(Ge, Ov)],
+) -> Vec<&(Ge, Ov)> {
+    let objectives: Vec<Vec<f64>> =
+        individuals.iter().map(|(_, ov)| ov.objectives()).collect();
+
+    let mut front =
+        non_dominated_sort(&objectives).into_iter().next().unwrap_or_default();
+    front.sort();
+
+    let mut seen = HashSet::<&Ge>::new();
+    front
+        .into_iter()
+        .map(|i| &individuals[i])
+        .filter(|(ge, _)| seen.insert(ge))
+        .collect()
+}
+
+fn crowded_tournament<
+    'a,
+    Ov: MultiObjective,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+>(
+    size: usize,
+    amount: usize,
+    individuals: &'a [(Ge, Ov)],
+    rng: &mut GaRng,
+) -> (Vec<&'a (Ge, Ov)>, usize) {
+    let ranks = rank(individuals);
+
+    let mut selection: Vec<&(Ge, Ov)> = Vec::with_capacity(amount);
+    let mut selected_indices = HashSet::<usize>::new();
+
+    for _ in 0..amount {
+        let winner = (0..size.max(1))
+            .map(|_| rng.gen_range(0..individuals.len()))
+            .min_by(|a, b| crowded_cmp(&ranks[*a], &ranks[*b]))
+            .unwrap();
+
+        selection.push(&individuals[winner]);
+        selected_indices.insert(winner);
+    }
+
+    (selection, selected_indices.len())
+}
+
+fn nsga2_replacement<Ge, Ov: MultiObjective>(
+    population: &mut Vec<(Ge, Ov)>,
+    offspring: Vec<(Ge, Ov)>,
+) {
+    let size = population.len();
+    population.extend(offspring);
+
+    let objectives: Vec<Vec<f64>> =
+        population.iter().map(|(_, ov)| ov.objectives()).collect();
+
+    // Fill the next population front by front
+    let mut keep: Vec<usize> = Vec::with_capacity(size);
+    for mut front in non_dominated_sort(&objectives) {
+        if keep.len() + front.len() > size {
+            // Truncate the last front by crowding distance (stable sort keeps
+            // the result deterministic on equal distances).
+            let distance = crowding_distance(&objectives, &front);
+            let mut order: Vec<usize> = (0..front.len()).collect();
+            order.sort_by(|a, b| distance[*b].total_cmp(&distance[*a]));
+
+            front = order.into_iter().map(|i| front[i]).collect();
+            front.truncate(size - keep.len());
+        }
+
+        keep.extend(front);
+        if keep.len() == size {
+            break;
+        }
+    }
+
+    // Move the kept individuals into the population
+    keep.sort();
+    let mut keep = keep.into_iter().peekable();
+    let merged = std::mem::take(population);
+
+    for (i, individual) in merged.into_iter().enumerate() {
+        if keep.next_if_eq(&i).is_some() {
+            population.push(individual);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -22,6 +22,7 @@ pub trait Rejection<Ov: ObjectiveValue, Ctx: Context, Ge: Genotype<Ctx>>:
 }
 
 // Implementation //////////////////////////////////////////////////////////////
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Reject {
     None,
     BetterThanWorstParent,
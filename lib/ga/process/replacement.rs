@@ -1,27 +1,39 @@
 // Imports /////////////////////////////////////////////////////////////////////
-// use crate::encoding::{Context, Genotype, ObjectiveValue};
+use crate::rng::GaRng;
 
 // Trait ///////////////////////////////////////////////////////////////////////
 /// This trait is usually implemented by enums, which represent a set of
-/// rejection methods. The rejection methods are executed after crossover and
-/// mutation are finished, to ensure certain features in the offspring
-/// chromosomes (e.g. better than either of the parents).
+/// replacement methods (generation models). The replacement methods decide
+/// which individuals of the current population and the offspring form the
+/// next generation.
+///
+/// The number of offspring does not need to match the population size. It is
+/// defined by [`Replacement::selection_size`].
+///
+/// All random decisions must be drawn from the passed `rng`, to keep algorithm
+/// executions reproducible.
 ///
 pub trait Replacement<T>: Send + Sync {
+    /// Returns the number of individuals of the current population, which
+    /// can survive into the next generation.
     fn elite_size(&self, population_size: usize) -> usize;
 
-    /// Returns the raw selection size, and also the corrected. The correction
-    /// is needed to ensure the selected number of parents is even, to perform
-    /// crossover with pairs of parents.
+    /// Returns the raw selection size (number of offspring), and also the
+    /// corrected. The correction is needed to ensure the selected number of
+    /// parents is even, to perform crossover with pairs of parents.
     fn selection_size(&self, population_size: usize) -> (usize, usize);
 
-    fn exec(&self, population: &mut Vec<T>, offspring: Vec<T>);
+    /// Creates the next generation from the current population (which is
+    /// sorted ascendingly by objective value) and the offspring (which is not
+    /// sorted). The population size must be preserved.
+    fn exec(&self, population: &mut Vec<T>, offspring: Vec<T>, rng: &mut GaRng);
 
     fn identifier(&self) -> String;
 }
 
 // Implementation //////////////////////////////////////////////////////////////
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Replace {
     /// No elitism, full replacement of the current population with the
     /// offspring/children.
@@ -33,9 +45,32 @@ pub enum Replace {
     /// Elitism with relative size of the elite. A minimum absolute size of 1
     /// is ensured.
     EliteRelative(f32),
+
+    /// Steady-state: the given number of offspring is created per generation,
+    /// which replace the worst individuals of the population.
+    SteadyStateWorst(usize),
+
+    /// Steady-state: the given number of offspring is created per generation,
+    /// which replace randomly chosen individuals of the population. The best
+    /// individual is never replaced.
+    SteadyStateRandom(usize),
+
+    /// (μ+λ): the given number of offspring (λ) is created per generation.
+    /// The best individuals of the population and the offspring form the
+    /// next generation.
+    MuPlusLambda(usize),
+
+    /// (μ,λ): the given number of offspring (λ) is created per generation and
+    /// the best of them form the next generation. λ must be at least the
+    /// population size (μ), which is checked by the parameters builder.
+    MuCommaLambda(usize),
+
+    /// The given number of offspring is created per generation. Each of them
+    /// replaces the worst individual of the population, if it is better.
+    WorstIfBetter(usize),
 }
 
-impl<T> Replacement<T> for Replace {
+impl<Ge, Ov: Ord> Replacement<(Ge, Ov)> for Replace {
     fn elite_size(&self, population_size: usize) -> usize {
         match self {
             Self::Full => 0,
@@ -47,30 +82,107 @@ impl<T> Replacement<T> for Replace {
                 // Retrun (ensure minimum of 1)
                 elite_size.max(1)
             }
+            Self::SteadyStateWorst(k) | Self::SteadyStateRandom(k) => {
+                population_size.saturating_sub(*k)
+            }
+            Self::MuPlusLambda(_) | Self::WorstIfBetter(_) => population_size,
+            Self::MuCommaLambda(_) => 0,
         }
     }
 
     fn selection_size(&self, population_size: usize) -> (usize, usize) {
-        let elite_size =
-            <Replace as Replacement<T>>::elite_size(self, population_size);
-
-        let diff = population_size - elite_size;
+        let offspring = match self {
+            Self::Full | Self::EliteAbsolute(_) | Self::EliteRelative(_) => {
+                let elite_size = <Replace as Replacement<(Ge, Ov)>>::elite_size(
+                    self,
+                    population_size,
+                );
+
+                population_size - elite_size
+            }
+            Self::SteadyStateWorst(k) | Self::SteadyStateRandom(k) => {
+                (*k).min(population_size)
+            }
+            Self::MuPlusLambda(lambda)
+            | Self::MuCommaLambda(lambda)
+            | Self::WorstIfBetter(lambda) => *lambda,
+        };
 
         // Ensure selection size is a multiple of two
-        if diff % 2 != 0 {
-            (diff, diff + 1)
-        } else {
-            (diff, diff)
-        }
+        (offspring, offspring + offspring % 2)
     }
 
-    fn exec(&self, population: &mut Vec<T>, offspring: Vec<T>) {
-        // Calculate elite size
-        let elite_size =
-            <Replace as Replacement<T>>::elite_size(self, population.len());
+    fn exec(
+        &self,
+        population: &mut Vec<(Ge, Ov)>,
+        mut offspring: Vec<(Ge, Ov)>,
+        rng: &mut GaRng,
+    ) {
+        match self {
+            Self::Full | Self::EliteAbsolute(_) | Self::EliteRelative(_) => {
+                // Calculate elite size
+                let elite_size = <Replace as Replacement<(Ge, Ov)>>::elite_size(
+                    self,
+                    population.len(),
+                );
+
+                // Mutate the population
+                population.splice(elite_size.., offspring);
+            }
+
+            Self::SteadyStateWorst(_) => {
+                let keep = population.len().saturating_sub(offspring.len());
+                population.truncate(keep);
+                population.append(&mut offspring);
+            }
+
+            Self::SteadyStateRandom(_) => {
+                // Choose distinct random individuals (except the best one)
+                let candidates = population.len().saturating_sub(1);
+                let amount = offspring.len().min(candidates);
+                let indices = rand::seq::index::sample(rng, candidates, amount);
 
-        // Mutate the population
-        population.splice(elite_size.., offspring);
+                for (i, child) in indices.into_iter().zip(offspring) {
+                    population[i + 1] = child;
+                }
+            }
+
+            Self::MuPlusLambda(_) => {
+                let size = population.len();
+                population.append(&mut offspring);
+                population.sort_by(|(_, a), (_, b)| a.cmp(b));
+                population.truncate(size);
+            }
+
+            Self::MuCommaLambda(_) => {
+                let size = population.len();
+                assert!(
+                    offspring.len() >= size,
+                    "(μ,λ) replacement needs at least μ offspring"
+                );
+
+                offspring.sort_by(|(_, a), (_, b)| a.cmp(b));
+                offspring.truncate(size);
+                *population = offspring;
+            }
+
+            Self::WorstIfBetter(_) => {
+                offspring.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+                for child in offspring {
+                    // The population is sorted, so the worst individual is
+                    // the last one.
+                    match population.last() {
+                        Some((_, worst)) if child.1 < *worst => {}
+                        _ => break,
+                    }
+
+                    population.pop();
+                    let i = population.partition_point(|(_, x)| *x <= child.1);
+                    population.insert(i, child);
+                }
+            }
+        }
     }
 
     fn identifier(&self) -> String {
@@ -78,6 +190,11 @@ impl<T> Replacement<T> for Replace {
             Self::Full => "full".into(),
             Self::EliteAbsolute(n) => format!("eli-abs-{n}"),
             Self::EliteRelative(x) => format!("eli-rel-{:.4}", x),
+            Self::SteadyStateWorst(k) => format!("ss-worst-{k}"),
+            Self::SteadyStateRandom(k) => format!("ss-rnd-{k}"),
+            Self::MuPlusLambda(l) => format!("mu-plus-{l}"),
+            Self::MuCommaLambda(l) => format!("mu-comma-{l}"),
+            Self::WorstIfBetter(l) => format!("worst-if-better-{l}"),
         }
     }
 }
@@ -1,7 +1,10 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use crate::encoding::{Context, Genotype, ObjectiveValue};
-use hashbrown::HashSet;
-use rand::prelude::Distribution;
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    rng::GaRng,
+};
+use rand::{prelude::Distribution, seq::SliceRandom, Rng, SeedableRng};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 // Trait ///////////////////////////////////////////////////////////////////////
 
@@ -14,6 +17,12 @@ use rand::prelude::Distribution;
 ///
 /// The amount of distinct selections is part of the genetic algorithms metrics.
 ///
+/// The individuals are sorted ascendingly by objective value, so that the best
+/// individual is the FIRST one.
+///
+/// All random decisions must be drawn from the passed `rng`, to keep algorithm
+/// executions reproducible.
+///
 pub trait Selection<
     Ov: ObjectiveValue + Into<T>,
     Ctx: Context,
@@ -25,13 +34,58 @@ pub trait Selection<
         &self,
         amount: usize,
         individuals: &'a [(Ge, Ov)],
+        rng: &mut GaRng,
     ) -> (Vec<&'a (Ge, Ov)>, usize);
 
     fn identifier(&self) -> String;
+
+    /// Returns the selection pressure, if the selection method has a
+    /// parameter which controls it (used by generic dynamics, see
+    /// [`crate::dynamics::Standard`]). Higher values always mean a stronger
+    /// selection pressure.
+    fn pressure(&self) -> Option<f64> {
+        None
+    }
+
+    /// Sets the parameter which controls the selection pressure (see
+    /// [`Selection::pressure`]). Returns false, if the selection method has
+    /// no such parameter.
+    fn set_pressure(&mut self, _pressure: f64) -> bool {
+        false
+    }
+}
+
+// Value Conversion ////////////////////////////////////////////////////////////
+
+/// Conversion of the value `T`, which objective values are converted into for
+/// selection, to `f64`. This allows [`Select`] to be used with integer and
+/// floating point objective values.
+pub trait ToF64: Send + Sync {
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl ToF64 for $t {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
 }
 
+impl_to_f64!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
 // Implementation //////////////////////////////////////////////////////////////
+
+/// Selection methods. Methods which use the objective values (not only their
+/// order) work for minimization as well as maximization problems, because
+/// they only use the distance of the objective values to the best or worst
+/// individual.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Select {
     RouletteWheel,
     Tournament(usize),
@@ -41,21 +95,89 @@ pub enum Select {
     // Parameters:
     // 1) selection pressure (>= 1.0)
     LinearRank(f32),
+
+    /// Stochastic universal sampling: fitness proportional selection (with
+    /// the same proportions as the roulette wheel), which uses evenly spaced
+    /// pointers instead of independent spins.
+    StochasticUniversalSampling,
+
+    /// Truncation selection: parents are chosen uniformly at random from the
+    /// given fraction (0, 1] of the best individuals.
+    Truncation(f32),
+
+    /// Boltzmann selection with the given temperature (> 0). The probability
+    /// of an individual is proportional to `exp(-d / temperature)`, where `d`
+    /// is the distance of its objective value to the best one. The
+    /// temperature can be annealed by dynamics.
+    Boltzmann(f32),
+
+    /// Exponential rank selection with the given base `c` in (0, 1]. The
+    /// probability of rank `r` (starting at 0 for the best individual) is
+    /// proportional to `c^r`.
+    ExponentialRank(f32),
 }
 
-impl<Ov: ObjectiveValue + Into<usize>, Ctx: Context, Ge: Genotype<Ctx>>
-    Selection<Ov, Ctx, Ge, usize> for Select
+impl<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        T: ToF64,
+    > Selection<Ov, Ctx, Ge, T> for Select
 {
     fn exec<'a>(
         &self,
         amount: usize,
         individuals: &'a [(Ge, Ov)],
+        rng: &mut GaRng,
     ) -> (Vec<&'a (Ge, Ov)>, usize) {
         match self {
-            Self::RouletteWheel => roulette_wheel_usize(amount, individuals),
-            Self::Tournament(n) => tournament_usize(*n, amount, individuals),
-            Self::Random => random_usize(amount, individuals),
-            Self::LinearRank(sp) => linear_rank_usize(*sp, amount, individuals),
+            Self::RouletteWheel => {
+                let weights = proportional_weights(&values(individuals));
+                roulette(&weights, amount, individuals, rng)
+            }
+            Self::Tournament(n) => tournament(*n, amount, individuals, rng),
+            Self::Random => random(amount, individuals.len(), individuals, rng),
+            Self::LinearRank(sp) => {
+                let n = individuals.len();
+                let weights: Vec<f64> = (0..n)
+                    .map(|r| linear_selection_probability(r, n, *sp) as f64)
+                    .collect();
+
+                roulette(&weights, amount, individuals, rng)
+            }
+            Self::StochasticUniversalSampling => {
+                let weights = proportional_weights(&values(individuals));
+                stochastic_universal_sampling(
+                    &weights,
+                    amount,
+                    individuals,
+                    rng,
+                )
+            }
+            Self::Truncation(fraction) => {
+                let n = individuals.len();
+                let best = ((n as f32 * fraction).ceil() as usize).clamp(1, n);
+                random(amount, best, individuals, rng)
+            }
+            Self::Boltzmann(temperature) => {
+                let values = values(individuals);
+                let best = values[0];
+                let temperature = (*temperature as f64).max(f64::MIN_POSITIVE);
+
+                let weights: Vec<f64> = values
+                    .iter()
+                    .map(|x| (-(x - best).abs() / temperature).exp())
+                    .collect();
+
+                roulette(&weights, amount, individuals, rng)
+            }
+            Self::ExponentialRank(c) => {
+                let weights: Vec<f64> = (0..individuals.len())
+                    .map(|r| (*c as f64).powi(r as i32))
+                    .collect();
+
+                roulette(&weights, amount, individuals, rng)
+            }
         }
     }
 
@@ -65,135 +187,293 @@ impl<Ov: ObjectiveValue + Into<usize>, Ctx: Context, Ge: Genotype<Ctx>>
             Self::Tournament(n) => format!("trn-{n}"),
             Self::Random => "rnd".into(),
             Self::LinearRank(x) => format!("lnrnk-{:.2}", x),
+            Self::StochasticUniversalSampling => "sus".into(),
+            Self::Truncation(x) => format!("trunc-{:.2}", x),
+            Self::Boltzmann(x) => format!("boltz-{:.4}", x),
+            Self::ExponentialRank(x) => format!("exprnk-{:.4}", x),
+        }
+    }
+
+    /// The selection pressure is the tournament size, the linear rank
+    /// parameter, the inverse of the truncation fraction, of the exponential
+    /// rank base or of the Boltzmann temperature. Except for the Boltzmann
+    /// selection, a pressure of 1 means uniform selection (which is also the
+    /// minimum).
+    fn pressure(&self) -> Option<f64> {
+        match self {
+            Self::Tournament(n) => Some(*n as f64),
+            Self::LinearRank(x) => Some(*x as f64),
+            Self::Truncation(x)
+            | Self::Boltzmann(x)
+            | Self::ExponentialRank(x) => Some(1. / *x as f64),
+            _ => None,
+        }
+    }
+
+    fn set_pressure(&mut self, pressure: f64) -> bool {
+        match self {
+            Self::Tournament(n) => *n = (pressure.round() as usize).max(1),
+            Self::LinearRank(x) => *x = pressure.max(1.) as f32,
+            Self::Truncation(x) | Self::ExponentialRank(x) => {
+                *x = (1. / pressure.max(1.)) as f32
+            }
+            Self::Boltzmann(x) => *x = (1. / pressure.max(f64::EPSILON)) as f32,
+            _ => return false,
         }
+
+        true
     }
 }
 
-// Functions ///////////////////////////////////////////////////////////////////
-fn roulette_wheel_usize<
-    Ov: ObjectiveValue + Into<usize>,
-    Ctx: Context,
-    Ge: Genotype<Ctx>,
->(
-    amount: usize,
-    individuals: &[(Ge, Ov)],
-) -> (Vec<&(Ge, Ov)>, usize) {
-    // Extract cost (convert objective value to usize)
-    let costs: Vec<usize> = individuals
-        .iter()
-        .map(|(_, c)| c.to_owned().into()) // Convert `Ov` into usize
-        .collect();
+// Alias Table /////////////////////////////////////////////////////////////////
 
-    // Calculate max
-    let max_cost: usize = *costs.iter().max().unwrap();
+/// Number of samples, which are drawn by one task of
+/// [`AliasTable::sample_many`].
+const SAMPLE_CHUNK_SIZE: usize = 1024;
+
+/// Alias table (Vose's alias method) for sampling indices proportionally to
+/// a list of weights. Building the table takes O(n), afterwards each sample
+/// takes O(1) (one uniform index and one uniform number), independent of the
+/// distribution of the weights.
+///
+/// If the weights do not sum up to a positive (finite) value, all indices are
+/// sampled with the same probability.
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    /// Probability of keeping the drawn column (instead of its alias).
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
 
-    // Invert
-    let inverted_costs: Vec<usize> =
-        costs.iter().map(|x| (max_cost - *x) * (max_cost - *x)).collect();
+impl AliasTable {
+    /// Builds the alias table for the given (non-empty) weights.
+    pub fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        assert!(n > 0, "alias table needs at least one weight");
+
+        let total: f64 = weights.iter().sum();
+        let mut scaled: Vec<f64> = if total.is_finite() && total > 0. {
+            weights.iter().map(|w| w * n as f64 / total).collect()
+        } else {
+            vec![1.; n]
+        };
+
+        let mut prob = vec![1.; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|i| scaled[*i] < 1.);
+
+        // Fill each small column with the excess of a large one
+        while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+            prob[s] = scaled[s];
+            alias[s] = l;
+
+            scaled[l] -= 1. - scaled[s];
+            if scaled[l] < 1. {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
 
-    // Calc total cost and highest cost
-    let total_cost: usize = inverted_costs.iter().sum();
+        // The remaining columns are full (up to rounding errors), so their
+        // probability stays at 1.
+        Self { prob, alias }
+    }
+
+    /// Returns the number of weights.
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Returns `true`, if the table contains no weights (never the case for
+    /// tables created with [`AliasTable::new`]).
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    /// Samples one index.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let i = rng.gen_range(0..self.prob.len());
+        if rng.gen::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+
+    /// Samples `amount` indices in parallel. A single seed is drawn from
+    /// `rng`, and every chunk of samples gets its own stream of this seed, so
+    /// the result does not depend on the number of threads.
+    pub fn sample_many(&self, amount: usize, rng: &mut GaRng) -> Vec<usize> {
+        let seed: u64 = rng.gen();
+        let chunks = amount.div_ceil(SAMPLE_CHUNK_SIZE);
+
+        (0..chunks)
+            .into_par_iter()
+            .flat_map_iter(|chunk| {
+                let mut rng = GaRng::seed_from_u64(seed);
+                rng.set_stream(chunk as u64);
+
+                let size =
+                    SAMPLE_CHUNK_SIZE.min(amount - chunk * SAMPLE_CHUNK_SIZE);
+                (0..size).map(move |_| self.sample(&mut rng))
+            })
+            .collect()
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Converts the objective values of the individuals to `f64`.
+fn values<Ov: ObjectiveValue + Into<T>, Ge, T: ToF64>(
+    individuals: &[(Ge, Ov)],
+) -> Vec<f64> {
+    individuals.iter().map(|(_, ov)| ov.clone().into().to_f64()).collect()
+}
 
-    // Calculate proportion
-    let proportions: Vec<f32> =
-        inverted_costs.iter().map(|x| *x as f32 / total_cost as f32).collect();
+/// Weights for fitness proportional selection: the squared distance of each
+/// objective value to the worst (last) one.
+fn proportional_weights(values: &[f64]) -> Vec<f64> {
+    let worst = *values.last().unwrap();
+    values.iter().map(|x| (x - worst).powi(2)).collect()
+}
 
-    // Accumulate
-    let mut acc: f32 = 0.;
-    let mut roulette_wheel = vec![];
-    for item in proportions {
-        let value = item + acc;
-        roulette_wheel.push(value);
+/// Accumulates the weights to a "roulette wheel" of sections in [0, 1]. If the
+/// weights do not sum up to a positive value, all sections have the same size.
+fn accumulate(weights: &[f64]) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
 
-        acc = value;
+    if !(total.is_finite() && total > 0.) {
+        let n = weights.len() as f64;
+        return (1..=weights.len()).map(|i| i as f64 / n).collect();
     }
 
+    let mut acc = 0.;
+    let mut roulette_wheel: Vec<f64> = weights
+        .iter()
+        .map(|w| {
+            acc += w / total;
+            acc
+        })
+        .collect();
+
     let last = roulette_wheel.last_mut().unwrap();
     *last = 1.;
 
-    // Selection
-    let mut selection: Vec<&(Ge, Ov)> = vec![];
-    let mut selected_indices = HashSet::<usize>::new();
-    let mut rng = rand::thread_rng();
-    let interval = rand::distributions::Uniform::new_inclusive(0., 1.);
-
-    for _ in 0..amount {
-        // Random value
-        let value = interval.sample(&mut rng);
-
-        for (i, section) in roulette_wheel.iter().enumerate() {
-            if &value <= section {
-                selection.push(&individuals[i]);
-                selected_indices.insert(i);
-                break;
-            }
-        }
-    }
+    roulette_wheel
+}
 
-    // Return
-    (selection, selected_indices.len())
+/// Selects `amount` individuals by spinning a roulette wheel with sections
+/// proportional to the given weights. The spins are sampled from an alias
+/// table in constant time each.
+fn roulette<'a, Ov, Ge>(
+    weights: &[f64],
+    amount: usize,
+    individuals: &'a [(Ge, Ov)],
+    rng: &mut GaRng,
+) -> (Vec<&'a (Ge, Ov)>, usize) {
+    let indices = AliasTable::new(weights).sample_many(amount, rng);
+    collect(&indices, individuals)
 }
 
-fn tournament_usize<
-    Ov: ObjectiveValue + Into<usize>,
-    Ctx: Context,
-    Ge: Genotype<Ctx>,
->(
-    tournament_size: usize,
+/// Selects `amount` individuals with evenly spaced pointers on a roulette
+/// wheel with sections proportional to the given weights. The selection is
+/// shuffled afterwards, so that the pairs of parents are random.
+fn stochastic_universal_sampling<'a, Ov, Ge>(
+    weights: &[f64],
     amount: usize,
-    individuals: &[(Ge, Ov)],
-) -> (Vec<&(Ge, Ov)>, usize) {
-    let mut selection: Vec<&(Ge, Ov)> = vec![];
-    let mut selected_indices = HashSet::<usize>::new();
-    let mut rng = rand::thread_rng();
-    let interval =
-        rand::distributions::Uniform::new_inclusive(0, individuals.len() - 1);
+    individuals: &'a [(Ge, Ov)],
+    rng: &mut GaRng,
+) -> (Vec<&'a (Ge, Ov)>, usize) {
+    let roulette_wheel = accumulate(weights);
+
+    let mut indices: Vec<usize> = Vec::with_capacity(amount);
+    if amount == 0 {
+        return (vec![], 0);
+    }
 
-    for _ in 0..amount {
-        // Create tournament participant list
-        let mut tournament: Vec<(&(Ge, Ov), usize)> = vec![];
+    let step = 1. / amount as f64;
+    let start = rand::distributions::Uniform::new(0., step).sample(rng);
 
-        // Pick participants
-        for _ in 0..tournament_size {
-            let index = interval.sample(&mut rng);
-            tournament.push((&individuals[index], index));
+    let mut i = 0;
+    for k in 0..amount {
+        let pointer = start + k as f64 * step;
+        while i < roulette_wheel.len() - 1 && roulette_wheel[i] < pointer {
+            i += 1;
         }
 
-        // Get the best from the tournament
-        tournament.sort_by_key(|(_, x)| *x);
-
-        // Store tournament winner and register index
-        let winner = tournament.first().unwrap();
-        selection.push(winner.0);
-        selected_indices.insert(winner.1);
+        indices.push(i);
     }
 
+    indices.shuffle(rng);
+
     // Return
-    (selection, selected_indices.len())
+    collect(&indices, individuals)
 }
 
-fn random_usize<
-    Ov: ObjectiveValue + Into<usize>,
-    Ctx: Context,
-    Ge: Genotype<Ctx>,
->(
+fn tournament<'a, Ov, Ge>(
+    tournament_size: usize,
     amount: usize,
-    individuals: &[(Ge, Ov)],
-) -> (Vec<&(Ge, Ov)>, usize) {
-    let mut selection: Vec<&(Ge, Ov)> = vec![];
-    let mut selected_indices = HashSet::<usize>::new();
-    let mut rng = rand::thread_rng();
+    individuals: &'a [(Ge, Ov)],
+    rng: &mut GaRng,
+) -> (Vec<&'a (Ge, Ov)>, usize) {
     let interval =
         rand::distributions::Uniform::new_inclusive(0, individuals.len() - 1);
 
-    for _ in 0..amount {
-        let index = interval.sample(&mut rng);
-        selection.push(&individuals[index]);
-        selected_indices.insert(index);
-    }
+    // Pick the participants of each tournament. The individuals are sorted,
+    // so the participant with the lowest index wins.
+    let indices: Vec<usize> = (0..amount)
+        .map(|_| {
+            (0..tournament_size.max(1))
+                .map(|_| interval.sample(rng))
+                .min()
+                .unwrap()
+        })
+        .collect();
+
+    // Return
+    collect(&indices, individuals)
+}
+
+/// Selects `amount` individuals uniformly at random from the first `n`
+/// individuals.
+fn random<'a, Ov, Ge>(
+    amount: usize,
+    n: usize,
+    individuals: &'a [(Ge, Ov)],
+    rng: &mut GaRng,
+) -> (Vec<&'a (Ge, Ov)>, usize) {
+    let interval = rand::distributions::Uniform::new_inclusive(0, n - 1);
+    let indices: Vec<usize> =
+        (0..amount).map(|_| interval.sample(&mut *rng)).collect();
 
     // Return
-    (selection, selected_indices.len())
+    collect(&indices, individuals)
+}
+
+/// Maps the selected indices to the individuals and counts the distinct
+/// selections (with a bit per individual instead of a hash set).
+fn collect<'a, Ov, Ge>(
+    indices: &[usize],
+    individuals: &'a [(Ge, Ov)],
+) -> (Vec<&'a (Ge, Ov)>, usize) {
+    let mut selected = vec![false; individuals.len()];
+    let mut distinct = 0;
+
+    let selection = indices
+        .iter()
+        .map(|i| {
+            if !std::mem::replace(&mut selected[*i], true) {
+                distinct += 1;
+            }
+
+            &individuals[*i]
+        })
+        .collect();
+
+    (selection, distinct)
 }
 
 /// Probability function for the "linear rank selection with configurable
@@ -222,68 +502,4 @@ fn linear_selection_probability(r: usize, n: usize, sp: f32) -> f32 {
     }
 }
 
-fn linear_rank_usize<
-    Ov: ObjectiveValue + Into<usize>,
-    Ctx: Context,
-    Ge: Genotype<Ctx>,
->(
-    selection_pressure: f32,
-    amount: usize,
-    individuals: &[(Ge, Ov)],
-) -> (Vec<&(Ge, Ov)>, usize) {
-    // Get population size
-    let pop_size = individuals.len();
-
-    // Define probability function (linear ranking)
-    // let probability = |rank: usize, pop_size: usize| -> f32 {
-    //     let p = pop_size as f32;
-    //     let r = rank as f32;
-    //     (2. * (p - r)) / (p * (p + 1.))
-    // };
-
-    // Calculate proportion
-    let proportions: Vec<f32> = individuals
-        .iter()
-        .enumerate()
-        .map(|(i, _)| {
-            linear_selection_probability(i, pop_size, selection_pressure)
-        })
-        .collect();
-
-    // Accumulate
-    let mut acc: f32 = 0.;
-    let mut roulette_wheel = vec![];
-    for item in proportions {
-        let value = item + acc;
-        roulette_wheel.push(value);
-
-        acc = value;
-    }
-
-    let last = roulette_wheel.last_mut().unwrap();
-    *last = 1.;
-
-    // Selection
-    let mut selection: Vec<&(Ge, Ov)> = vec![];
-    let mut selected_indices = HashSet::<usize>::new();
-    let mut rng = rand::thread_rng();
-    let interval = rand::distributions::Uniform::new_inclusive(0., 1.);
-
-    for _ in 0..amount {
-        // Random value
-        let value = interval.sample(&mut rng);
-
-        for (i, section) in roulette_wheel.iter().enumerate() {
-            if &value <= section {
-                selection.push(&individuals[i]);
-                selected_indices.insert(i);
-                break;
-            }
-        }
-    }
-
-    // Return
-    (selection, selected_indices.len())
-}
-
 ////////////////////////////////////////////////////////////////////////////////
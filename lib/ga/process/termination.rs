@@ -15,6 +15,7 @@ pub trait Termination<Ov: ObjectiveValue>: Send + Sync {
 }
 
 // Implementation //////////////////////////////////////////////////////////////
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Terminate<Ov: ObjectiveValue> {
     /// Stop the GA after the given amount of generations
     Generations(usize),
@@ -26,6 +27,18 @@ pub enum Terminate<Ov: ObjectiveValue> {
     /// Stop after max amount of generations or after reaching the target
     /// objective value.
     GenOrOv(usize, Ov),
+
+    /// Stop if every component of the best individual's objective value is
+    /// as good or better as the target of the same index (see
+    /// [`ObjectiveValue::components`]). For example, `Components(vec![0.,
+    /// f64::INFINITY])` stops at the first feasible solution of a
+    /// [`HardSoft`](crate::encoding::HardSoft) objective value. The number of
+    /// targets must match the number of components.
+    Components(Vec<f64>),
+
+    /// Stop after max amount of generations or after reaching the target
+    /// components.
+    GenOrComponents(usize, Vec<f64>),
 }
 
 impl<Ov: ObjectiveValue> Termination<Ov> for Terminate<Ov> {
@@ -40,6 +53,12 @@ impl<Ov: ObjectiveValue> Termination<Ov> for Terminate<Ov> {
             Self::GenOrOv(gen_limit, target) => {
                 generation_num >= *gen_limit || current_best <= target
             }
+
+            Self::Components(targets) => reached(current_best, targets),
+
+            Self::GenOrComponents(gen_limit, targets) => {
+                generation_num >= *gen_limit || reached(current_best, targets)
+            }
         }
     }
 
@@ -48,6 +67,10 @@ impl<Ov: ObjectiveValue> Termination<Ov> for Terminate<Ov> {
             Self::Generations(n) => format!("g-{n}"),
             Self::ObjectiveValue(ov) => format!("ov-{}", ov.to_usize()),
             Self::GenOrOv(g, ov) => format!("g-{}-ov-{}", g, ov.to_usize()),
+            Self::Components(targets) => format!("cmp-{}", join(targets)),
+            Self::GenOrComponents(g, targets) => {
+                format!("g-{}-cmp-{}", g, join(targets))
+            }
         }
     }
 
@@ -56,10 +79,37 @@ impl<Ov: ObjectiveValue> Termination<Ov> for Terminate<Ov> {
             Self::Generations(g) => Some(*g),
             Self::ObjectiveValue(_) => None,
             Self::GenOrOv(g, _) => Some(*g),
+            Self::Components(_) => None,
+            Self::GenOrComponents(g, _) => Some(*g),
         }
     }
 }
 
 // Functions ///////////////////////////////////////////////////////////////////
 
+/// Returns `true`, if every component of the objective value reached its
+/// target (objective values without components never reach the targets).
+///
+/// # Panics
+/// Panics if the number of targets does not match the number of components.
+fn reached<Ov: ObjectiveValue>(objective_value: &Ov, targets: &[f64]) -> bool {
+    let components = objective_value.components();
+    if components.is_empty() {
+        return false;
+    }
+
+    assert_eq!(
+        components.len(),
+        targets.len(),
+        "the number of targets must match the number of components"
+    );
+
+    components.iter().zip(targets).all(|(c, target)| c <= target)
+}
+
+fn join(targets: &[f64]) -> String {
+    let targets: Vec<String> = targets.iter().map(|t| t.to_string()).collect();
+    targets.join("-")
+}
+
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,165 @@
+//! Per-phase profiling of the algorithm execution.
+//!
+//! Every generation is split into named [`Phase`]s, which are always timed
+//! (independent of the `rerun_logger` feature). The [`Profile`] contains the
+//! durations of the phases in the last generation as well as the cumulative
+//! durations of the whole execution. It is part of the runtime data and the
+//! report (see [`Report::profile`](crate::report::Report::profile)).
+//!
+//! Restarts (see [`crate::restart`]) and the notification of observers are
+//! not part of any phase.
+
+// Imports /////////////////////////////////////////////////////////////////////
+use std::time::Duration;
+
+// Phase ///////////////////////////////////////////////////////////////////////
+
+/// Number of phases.
+pub const PHASES: usize = 9;
+
+/// Phase of a generation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Selection of the parents (incl. fitness sharing).
+    Selection = 0,
+
+    /// Crossover, mutation and rejection.
+    Variation = 1,
+
+    /// Objective value calculations of the offspring (incl. cache lookups
+    /// and incremental evaluations) and of the initial population.
+    Evaluation = 2,
+
+    /// Local search of the offspring (incl. its objective value
+    /// calculations).
+    LocalSearch = 3,
+
+    /// Replacement (incl. deterministic crowding and restricted tournament
+    /// selection).
+    Replacement = 4,
+
+    /// Sorting the new population.
+    Sort = 5,
+
+    /// Inserting the new population into the cache.
+    Cache = 6,
+
+    /// Runtime data and genotype metrics.
+    Stats = 7,
+
+    /// Execution of the dynamics.
+    Dynamics = 8,
+}
+
+impl Phase {
+    pub const ALL: [Phase; PHASES] = [
+        Self::Selection,
+        Self::Variation,
+        Self::Evaluation,
+        Self::LocalSearch,
+        Self::Replacement,
+        Self::Sort,
+        Self::Cache,
+        Self::Stats,
+        Self::Dynamics,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Selection => "selection",
+            Self::Variation => "variation",
+            Self::Evaluation => "evaluation",
+            Self::LocalSearch => "local_search",
+            Self::Replacement => "replacement",
+            Self::Sort => "sort",
+            Self::Cache => "cache",
+            Self::Stats => "stats",
+            Self::Dynamics => "dynamics",
+        }
+    }
+}
+
+// Profile /////////////////////////////////////////////////////////////////////
+
+/// Durations of the phases of the algorithm execution (indexed by
+/// [`Phase`]).
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Profile {
+    /// Durations of the phases in the last generation.
+    pub generation: [Duration; PHASES],
+
+    /// Cumulative durations of the phases.
+    pub total: [Duration; PHASES],
+
+    /// Number of objective value calculations in the evaluation phase.
+    pub evaluations: usize,
+}
+
+impl Profile {
+    /// Resets the durations of the last generation.
+    pub fn start_generation(&mut self) {
+        self.generation = [Duration::ZERO; PHASES];
+    }
+
+    /// Adds the given duration to a phase.
+    pub fn record(&mut self, phase: Phase, duration: Duration) {
+        self.generation[phase as usize] += duration;
+        self.total[phase as usize] += duration;
+    }
+
+    /// Adds the objective value calculations of the evaluation phase.
+    pub fn record_evaluations(&mut self, evaluations: usize) {
+        self.evaluations += evaluations;
+    }
+
+    /// Cumulative duration of all phases.
+    pub fn total_duration(&self) -> Duration {
+        self.total.iter().sum()
+    }
+
+    /// Share of a phase of the cumulative duration of all phases (0 to 1).
+    pub fn share(&self, phase: Phase) -> f64 {
+        let total = self.total_duration().as_secs_f64();
+        if total > 0. {
+            self.total[phase as usize].as_secs_f64() / total
+        } else {
+            0.
+        }
+    }
+
+    /// Objective value calculations per second of the evaluation phase.
+    pub fn evaluation_throughput(&self) -> f64 {
+        let seconds = self.total[Phase::Evaluation as usize].as_secs_f64();
+        if seconds > 0. {
+            self.evaluations as f64 / seconds
+        } else {
+            0.
+        }
+    }
+}
+
+impl std::fmt::Display for Profile {
+    /// Formats the cumulative durations and shares of all phases (one line
+    /// per phase) and the evaluation throughput.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for phase in Phase::ALL {
+            writeln!(
+                f,
+                "{:<12} {:>12.3} ms {:>6.1} %",
+                phase.name(),
+                self.total[phase as usize].as_secs_f64() * 1000.,
+                self.share(phase) * 100.,
+            )?;
+        }
+
+        write!(
+            f,
+            "{:<12} {:>12.0} /s",
+            "throughput",
+            self.evaluation_throughput()
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
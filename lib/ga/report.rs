@@ -1,6 +1,16 @@
 // Imports /////////////////////////////////////////////////////////////////////
-use crate::encoding::{Context, Genotype, ObjectiveValue};
-use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use crate::checkpoint::Error;
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::adaptive::OperatorStats,
+    pareto::MultiObjective,
+    profiling::Profile,
+    self_adaptation::StrategyStats,
+};
+#[cfg(feature = "serde")]
+use std::path::Path;
+use std::{fmt::Write, marker::PhantomData};
 
 // Report //////////////////////////////////////////////////////////////////////
 /// This struct represents the return value of the algorithm and therefore
@@ -8,7 +18,13 @@ use std::marker::PhantomData;
 /// objective values), but also valuable metrics and a detailed log of all
 /// generations, which can be used in post-analyzation of each algorithm
 /// execution.
+///
+/// The log and a summary of the report can be exported as CSV (see
+/// [`Report::log_csv`] and [`Report::summary_csv`]). With the `serde` feature,
+/// reports can be stored as JSON and loaded again for analysis (see
+/// [`Report::save`] and [`load_all`]).
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report<Ov, Ctx, Ge>
 where
     Ov: ObjectiveValue,
@@ -22,20 +38,228 @@ where
 
     pub generation: usize,
 
-    /// Total runtime of the algorithm in SECONDS.
-    pub runtime: usize,
+    /// Total runtime of the algorithm in MILLISECONDS.
+    pub runtime_ms: u64,
+
+    /// Runtime until the best solution was found in MILLISECONDS.
+    pub time_to_best_ms: u64,
+
+    /// Generation of the last improvement of the best solution.
+    pub last_improvement: usize,
+
+    /// Total number of objective value calculations.
+    pub evaluations: usize,
+
+    /// Durations of the phases of the algorithm execution (see
+    /// [`crate::profiling`]).
+    pub profile: Profile,
+
+    /// Seed of the random number generators. Executing the algorithm again
+    /// with this seed (and the same parameters) reproduces this report.
+    pub seed: u64,
+
+    /// Number of chromosomes of the initial population per source (see
+    /// [`crate::initializer`]).
+    pub initial_sources: Vec<(String, usize)>,
+
+    /// Usage and credit of the operators of the operator portfolio (see
+    /// [`crate::operators::adaptive`]).
+    pub operators: Vec<OperatorStats>,
 
     pub parameter_identifier: String,
     pub dynamics_identifier: Option<String>,
 
     pub log: Vec<ReportLog>,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub ctx: PhantomData<Ctx>,
 }
 
+impl<Ov, Ctx, Ge> Report<Ov, Ctx, Ge>
+where
+    Ov: ObjectiveValue,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+{
+    /// Returns the summary (metadata and final result) of the report.
+    pub fn summary(&self) -> Summary {
+        Summary {
+            seed: self.seed,
+            generation: self.generation,
+            best: self.population.first().map(|(_, ov)| ov.to_usize()),
+            runtime_ms: self.runtime_ms,
+            time_to_best_ms: self.time_to_best_ms,
+            last_improvement: self.last_improvement,
+            evaluations: self.evaluations,
+            parameter_identifier: self.parameter_identifier.clone(),
+            dynamics_identifier: self.dynamics_identifier.clone(),
+        }
+    }
+
+    /// Returns the runtime until the best objective value first reached the
+    /// given target (see [`ObjectiveValue::to_usize`]) in MILLISECONDS, or
+    /// `None` if the target was never reached.
+    pub fn time_to_target_ms(&self, target: usize) -> Option<u64> {
+        self.log.iter().find(|l| l.best <= target).map(|l| l.runtime_ms)
+    }
+
+    /// Returns the summary as CSV (header and one row, separated by `;`).
+    pub fn summary_csv(&self) -> String {
+        let s = self.summary();
+
+        let mut csv = String::from(
+            "seed;generation;best;runtime_ms;time_to_best_ms;\
+            last_improvement;evaluations;parameter_identifier;\
+            dynamics_identifier\n",
+        );
+
+        let _ = writeln!(
+            csv,
+            "{};{};{};{};{};{};{};{};{}",
+            s.seed,
+            s.generation,
+            opt(s.best),
+            s.runtime_ms,
+            s.time_to_best_ms,
+            s.last_improvement,
+            s.evaluations,
+            s.parameter_identifier,
+            s.dynamics_identifier.unwrap_or_default(),
+        );
+
+        csv
+    }
+
+    /// Returns the log as CSV (one row per generation, separated by `;`).
+    /// Composite objective values get one column per component
+    /// (`component_best_<i>` and `component_mean_<i>`). With self-adaptation,
+    /// the statistics of the strategy parameters are appended
+    /// (`strategy_rate_<stat>` and `strategy_sigma_<stat>`).
+    pub fn log_csv(&self) -> String {
+        let components =
+            self.log.iter().map(|l| l.component_best.len()).max().unwrap_or(0);
+        let strategy = self.log.iter().any(|l| l.strategy.is_some());
+
+        let mut csv = String::from(
+            "generation;runtime_ms;best;worst;mean;median;variance;std_dev;\
+            diversity;unique_genotypes;mean_distance;mean_distance_to_best;\
+            restart;events",
+        );
+        for i in 0..components {
+            let _ = write!(csv, ";component_best_{i};component_mean_{i}");
+        }
+        if strategy {
+            for parameter in ["rate", "sigma"] {
+                for stat in ["mean", "std_dev", "min", "max"] {
+                    let _ = write!(csv, ";strategy_{parameter}_{stat}");
+                }
+            }
+        }
+        csv.push('\n');
+
+        for l in &self.log {
+            let _ = write!(
+                csv,
+                "{};{};{};{};{};{};{};{};{};{};{};{};{};{}",
+                l.generation,
+                l.runtime_ms,
+                l.best,
+                l.worst,
+                l.mean,
+                l.median,
+                l.variance,
+                l.std_dev,
+                l.diversity,
+                l.unique_genotypes,
+                opt(l.mean_distance),
+                opt(l.mean_distance_to_best),
+                l.restart.as_deref().unwrap_or_default(),
+                l.events.join("|"),
+            );
+            for i in 0..components {
+                let _ = write!(
+                    csv,
+                    ";{};{}",
+                    opt(l.component_best.get(i)),
+                    opt(l.component_mean.get(i)),
+                );
+            }
+            if strategy {
+                let s = l.strategy;
+                for value in [
+                    s.map(|s| s.rate_mean),
+                    s.map(|s| s.rate_std_dev),
+                    s.map(|s| s.rate_min),
+                    s.map(|s| s.rate_max),
+                    s.map(|s| s.sigma_mean),
+                    s.map(|s| s.sigma_std_dev),
+                    s.map(|s| s.sigma_min),
+                    s.map(|s| s.sigma_max),
+                ] {
+                    let _ = write!(csv, ";{}", opt(value));
+                }
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Ov, Ctx, Ge> Report<Ov, Ctx, Ge>
+where
+    Ov: ObjectiveValue + serde::Serialize + serde::de::DeserializeOwned,
+    Ctx: Context,
+    Ge: Genotype<Ctx> + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Returns the report as JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Writes the report as JSON to the given file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = std::io::BufWriter::new(file);
+        serde_json::to_writer(&mut writer, self)?;
+        std::io::Write::flush(&mut writer)?;
+
+        Ok(())
+    }
+
+    /// Reads a report from the given JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = std::fs::File::open(path)?;
+        let reader = std::io::BufReader::new(file);
+
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+impl<Ov, Ctx, Ge> Report<Ov, Ctx, Ge>
+where
+    Ov: MultiObjective,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+{
+    /// Returns the Pareto front (all non-dominated individuals) of the last
+    /// generation. See [`crate::pareto`] for multi-objective optimization.
+    pub fn pareto_front(&self) -> Vec<&(Ge, Ov)> {
+        crate::pareto::pareto_front(&self.population)
+    }
+}
+
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReportLog {
     pub generation: usize,
+
+    /// Runtime of the algorithm at the end of this generation in
+    /// MILLISECONDS.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub runtime_ms: u64,
+
     pub best: usize,
     pub worst: usize,
 
@@ -45,6 +269,96 @@ pub struct ReportLog {
     pub std_dev: f64,
 
     pub diversity: f64,
+
+    /// Best and mean value of every component of composite objective values
+    /// (see [`ObjectiveValue::components`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub component_best: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub component_mean: Vec<f64>,
+
+    /// Genotype-level diversity metrics (see
+    /// [`RuntimeData`](crate::runtime_data::RuntimeData)).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub unique_genotypes: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mean_distance: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mean_distance_to_best: Option<f64>,
+
+    /// Statistics of the strategy parameters (see
+    /// [`crate::self_adaptation`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strategy: Option<StrategyStats>,
+
+    /// Identifier of the restart, if the population was restarted at the end
+    /// of this generation (see [`crate::restart`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restart: Option<String>,
+
+    /// Events of the dynamics at the end of this generation (e.g. the
+    /// transitions of a [`StateMachine`](crate::dynamics::StateMachine)).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub events: Vec<String>,
+}
+
+// Summary /////////////////////////////////////////////////////////////////////
+
+/// Metadata and final result of an algorithm execution (see
+/// [`Report::summary`]).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Summary {
+    pub seed: u64,
+    pub generation: usize,
+
+    /// Objective value of the best solution (see
+    /// [`ObjectiveValue::to_usize`]).
+    pub best: Option<usize>,
+
+    pub runtime_ms: u64,
+    pub time_to_best_ms: u64,
+    pub last_improvement: usize,
+    pub evaluations: usize,
+
+    pub parameter_identifier: String,
+    pub dynamics_identifier: Option<String>,
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Formats optional values for CSV (`None` is an empty field).
+pub(crate) fn opt<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(String::new, |x| x.to_string())
+}
+
+/// Loads all reports (`*.json` files) in the given directory and its
+/// subdirectories, e.g. all runs of one configuration. The reports are sorted
+/// by their path.
+#[cfg(feature = "serde")]
+pub fn load_all<Ov, Ctx, Ge>(
+    dir: impl AsRef<Path>,
+) -> Result<Vec<Report<Ov, Ctx, Ge>>, Error>
+where
+    Ov: ObjectiveValue + serde::Serialize + serde::de::DeserializeOwned,
+    Ctx: Context,
+    Ge: Genotype<Ctx> + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut paths = vec![];
+    let mut dirs = vec![dir.as_ref().to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+
+    paths.into_iter().map(Report::load).collect()
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,111 @@
+//! Restart (cataclysm) strategies, which re-initialize the population when it
+//! stagnates.
+//!
+//! A restart consists of a [`Trigger`], which decides when the population is
+//! restarted, and a [`Strategy`], which decides how the population is
+//! restarted. The best individuals (`elite`) always survive a restart. New
+//! chromosomes are created by the initializer of the algorithm (see
+//! [`crate::initializer`]).
+//!
+//! Restarts are configured in the parameters (see
+//! [`parameters::Builder::set_restart`](crate::parameters::Builder::set_restart)).
+//! Every restart is recorded in the report log
+//! ([`ReportLog::restart`](crate::report::ReportLog::restart)) and observers
+//! are notified with [`Event::Restart`](crate::observer::Event::Restart).
+
+// Trigger /////////////////////////////////////////////////////////////////////
+
+/// Decides when the population is restarted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Trigger {
+    /// The best solution did not improve for the given number of generations
+    /// (since the last improvement or restart).
+    Stagnation(usize),
+
+    /// The diversity of the population fell below the given threshold.
+    Diversity(f64),
+
+    /// Every given number of generations.
+    Schedule(usize),
+}
+
+impl Trigger {
+    /// Returns `true`, if the trigger fires in the given generation. `since`
+    /// is the generation of the last improvement or restart (whichever is
+    /// later).
+    pub fn fires(
+        &self,
+        generation: usize,
+        since: usize,
+        diversity: f64,
+    ) -> bool {
+        match self {
+            Self::Stagnation(n) => generation.saturating_sub(since) >= *n,
+            Self::Diversity(threshold) => diversity < *threshold,
+            Self::Schedule(n) => *n > 0 && generation.is_multiple_of(*n),
+        }
+    }
+
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::Stagnation(n) => format!("stag-{n}"),
+            Self::Diversity(x) => format!("div-{:.4}", x),
+            Self::Schedule(n) => format!("sched-{n}"),
+        }
+    }
+}
+
+// Strategy ////////////////////////////////////////////////////////////////////
+
+/// Decides how the population is restarted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strategy {
+    /// All individuals except the elite are replaced by new chromosomes.
+    Full,
+
+    /// All individuals except the elite are mutated with the given mutation
+    /// rate (using the mutation operator of the algorithm).
+    Cataclysm(f32),
+
+    /// The population size is doubled (up to the given maximum population
+    /// size) and all individuals except the elite are replaced by new
+    /// chromosomes (IPOP-style restart).
+    DoublePopulation(usize),
+}
+
+impl Strategy {
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::Full => "full".into(),
+            Self::Cataclysm(rate) => format!("cat-{:.4}", rate),
+            Self::DoublePopulation(max) => format!("ipop-{max}"),
+        }
+    }
+}
+
+// Restart /////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Restart {
+    pub trigger: Trigger,
+    pub strategy: Strategy,
+
+    /// Number of best individuals, which survive the restart (at least 1).
+    pub elite: usize,
+}
+
+impl Restart {
+    pub fn identifier(&self) -> String {
+        format!(
+            "{}-{}-eli-{}",
+            self.trigger.identifier(),
+            self.strategy.identifier(),
+            self.elite
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
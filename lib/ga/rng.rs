@@ -0,0 +1,79 @@
+//! This module provides the source of randomness for the genetic algorithm
+//! runtime. All random decisions of an algorithm execution (initialization,
+//! selection, crossover, mutation and dynamics) draw their random numbers from
+//! generators which are derived from one single seed.
+//! Therefore, executing an algorithm twice with the same seed (and the same
+//! parameters) yields exactly the same generations and the same report.
+//!
+//! The crossover and mutation step is executed in parallel. To keep the
+//! results independent of the amount of threads (and the order in which rayon
+//! schedules the work), every pair of parents gets its own random number
+//! generator, which is derived from the seed, the generation and the index of
+//! the pair.
+
+// Imports /////////////////////////////////////////////////////////////////////
+use rand::{RngCore, SeedableRng};
+
+// Types ///////////////////////////////////////////////////////////////////////
+
+/// The random number generator used throughout the genetic algorithm
+/// framework. ChaCha8 is a portable and reproducible generator, which
+/// additionally supports 2^64 independent streams per seed.
+pub type GaRng = rand_chacha::ChaCha8Rng;
+
+// Seeder //////////////////////////////////////////////////////////////////////
+
+/// The seeder stores the seed of an algorithm execution and derives all
+/// random number generators needed by the runtime from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Seeder {
+    seed: u64,
+}
+
+impl Seeder {
+    /// Create a new seeder. If no seed is given, a random seed is drawn from
+    /// the operating system's entropy source. This seed can be retrieved with
+    /// [`Seeder::seed`] (and is stored in the report), so that every execution
+    /// can be reproduced afterwards.
+    pub fn new(seed: Option<u64>) -> Self {
+        let seed = seed.unwrap_or_else(|| rand::rngs::OsRng.next_u64());
+        Self { seed }
+    }
+
+    /// Returns the seed of this seeder.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the main random number generator. It is used for all random
+    /// decisions which are executed sequentially (initialization, selection
+    /// and dynamics).
+    pub fn main(&self) -> GaRng {
+        GaRng::seed_from_u64(self.seed)
+    }
+
+    /// Returns the random number generator for the pair of parents with the
+    /// index `index` in the given generation. The generator only depends on
+    /// the seed, the generation and the index.
+    pub fn stream(&self, generation: usize, index: usize) -> GaRng {
+        let key = splitmix64(self.seed ^ splitmix64(generation as u64 + 1));
+
+        let mut rng = GaRng::seed_from_u64(key);
+        rng.set_stream(index as u64);
+
+        rng
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// SplitMix64 finalizer, used to decorrelate seeds which only differ in a few
+/// bits (like consecutive generation numbers).
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -1,18 +1,39 @@
 // Imports /////////////////////////////////////////////////////////////////////
 use crate::{
+    cache::CacheStats,
     encoding::{Context, Genotype, ObjectiveValue},
-    operators::{Crossover, Mutation},
+    operators::{adaptive::Bandit, Crossover, Mutation},
     parameters::Parameters,
     process::{
         rejection::Rejection, replacement::Replacement, selection::Selection,
         termination::Termination,
     },
+    profiling::Profile,
+    rng::GaRng,
+    self_adaptation::StrategyStats,
 };
 
+use hashbrown::HashSet;
+use rand::Rng;
+use rayon::prelude::*;
 use shannon_entropy::normalized_shannon_entropy;
 use statrs::statistics::{Data, Distribution, Median};
 
+// Constants ///////////////////////////////////////////////////////////////////
+
+/// Maximum number of pairs of individuals, which are used to calculate the
+/// mean pairwise distance. Larger populations are sampled.
+pub const MAX_DISTANCE_PAIRS: usize = 5_000;
+
 // Runtime Data ////////////////////////////////////////////////////////////////
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "Ov: serde::Serialize",
+        deserialize = "Ov: serde::de::DeserializeOwned"
+    ))
+)]
 pub struct RuntimeData<
     Ov: ObjectiveValue + Into<T>,
     Ctx: Context,
@@ -37,29 +58,112 @@ pub struct RuntimeData<
     pub std_dev: f64,
     pub diversity: f64,
 
+    /// Best and mean value of every component of composite objective values
+    /// (see [`ObjectiveValue::components`]), e.g. of the infeasibility and
+    /// objective value of [`HardSoft`](crate::encoding::HardSoft). The best
+    /// components are the ones of the best individual.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub component_best: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub component_mean: Vec<f64>,
+
+    /// Number of distinct genotypes in the population.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub unique_genotypes: usize,
+
+    /// Mean pairwise genotype distance (see [`Genotype::distance`]), sampled
+    /// for large populations (see [`MAX_DISTANCE_PAIRS`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mean_distance: Option<f64>,
+
+    /// Mean genotype distance of the individuals to the best individual.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mean_distance_to_best: Option<f64>,
+
+    /// Statistics of the strategy parameters of the population (see
+    /// [`crate::self_adaptation`]), if the individuals carry any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strategy: Option<StrategyStats>,
+
     pub selection_corrected: usize,
     pub distinct_selections: usize,
     // pub offspring_mean: f32,
     pub cache_hits: usize,
-    pub execution_times: Vec<u128>,
+    pub cache_misses: usize,
+    pub cache_evictions: usize,
+
+    /// Number of entries in the cache.
+    pub cache_size: usize,
+
+    /// Durations of the phases of the algorithm execution (see
+    /// [`crate::profiling`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub profile: Profile,
+
+    /// Total number of objective value calculations (including the local
+    /// search, excluding cache hits).
+    pub evaluations: usize,
+
+    /// Number of objective value calculations of the local search.
+    pub local_search_evaluations: usize,
+
+    /// Number of incremental objective value calculations (see
+    /// [`Phenotype::evaluate_delta`](crate::encoding::Phenotype::evaluate_delta)).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub delta_evaluations: usize,
+
+    /// Number of chromosomes of the initial population per source (see
+    /// [`crate::initializer`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub initial_sources: Vec<(String, usize)>,
+
+    /// Number of restarts of the population (see [`crate::restart`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restarts: usize,
+
+    /// Generation of the last restart.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub last_restart: usize,
+
+    /// Adaptation states of the crossover and mutation operators of the
+    /// operator portfolio (see [`crate::operators::adaptive`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub crossover_bandit: Option<Bandit>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mutation_bandit: Option<Bandit>,
 
     /// True, when the current generation improved on the best solution.
     pub success: bool,
     pub last_success: usize,
 
+    /// Runtime of the algorithm until the last improvement of the best
+    /// solution (in generation `last_success`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub time_to_best: std::time::Duration,
+
     /// Moving average calculated by a PT1-lowpass filter function.
     pub success_rate_pt1: f32,
 
     // PhantomData
+    #[cfg_attr(feature = "serde", serde(skip))]
     objective_value: std::marker::PhantomData<Ov>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     context: std::marker::PhantomData<Ctx>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     genotype: std::marker::PhantomData<Ge>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     crossover: std::marker::PhantomData<Cr>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     mutation: std::marker::PhantomData<Mu>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     t: std::marker::PhantomData<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     selection: std::marker::PhantomData<Se>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     rejection: std::marker::PhantomData<Re>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     replacement: std::marker::PhantomData<Rp>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     termination: std::marker::PhantomData<Te>,
 }
 
@@ -104,6 +208,9 @@ impl<
         let variance = dataset.variance().unwrap_or(0.);
         let std_dev = dataset.std_dev().unwrap_or(0.);
 
+        let (component_best, component_mean) =
+            component_stats(initial_population);
+
         Self {
             generation,
             population_size,
@@ -117,14 +224,35 @@ impl<
             std_dev,
             diversity,
 
+            component_best,
+            component_mean,
+
+            unique_genotypes: 0,
+            mean_distance: None,
+            mean_distance_to_best: None,
+            strategy: None,
+
             selection_corrected: 0,
             distinct_selections: 0,
 
             cache_hits: 0,
-            execution_times: vec![],
+            cache_misses: 0,
+            cache_evictions: 0,
+            cache_size: 0,
+            profile: Profile::default(),
+
+            evaluations: population_size,
+            local_search_evaluations: 0,
+            delta_evaluations: 0,
+            initial_sources: vec![],
+            restarts: 0,
+            last_restart: 0,
+            crossover_bandit: None,
+            mutation_bandit: None,
 
             success: false,
             last_success: 0,
+            time_to_best: std::time::Duration::ZERO,
             success_rate_pt1: 0.,
 
             objective_value: std::marker::PhantomData,
@@ -150,7 +278,7 @@ impl<
         elite: usize,
         selection_corrected: usize,
         distinct_selections: usize,
-        cache_hits: usize,
+        cache_stats: CacheStats,
     ) {
         self.selection_corrected = selection_corrected;
         self.distinct_selections = distinct_selections;
@@ -195,27 +323,91 @@ impl<
         self.variance = dataset.variance().unwrap_or(0.);
         self.std_dev = dataset.std_dev().unwrap_or(0.);
 
+        (self.component_best, self.component_mean) =
+            component_stats(population);
+
         // Update elite
         self.elite = elite;
 
-        // Update cache hits
-        self.cache_hits = cache_hits;
+        // Update cache statistics
+        self.cache_hits = cache_stats.hits;
+        self.cache_misses = cache_stats.misses;
+        self.cache_evictions = cache_stats.evictions;
+        self.cache_size = cache_stats.size;
     }
 
-    #[cfg(feature = "log_runtimes")]
-    pub fn update_execution_times(
+    /// Updates the genotype-level diversity metrics (unique genotypes and
+    /// distances) and the statistics of the strategy parameters. The `rng` is
+    /// only used to sample pairs of individuals in large populations.
+    pub fn update_genotype_metrics(
         &mut self,
-        execution_times: Vec<std::time::Duration>,
+        population: &[(Ge, Ov)],
+        rng: &mut GaRng,
     ) {
-        // Convert
-        let times_in_microseconds = execution_times
-            .into_iter()
-            .map(|t| t.as_micros())
-            .collect::<Vec<u128>>();
-
-        // Set
-        self.execution_times = times_in_microseconds;
+        let n = population.len();
+        self.strategy = StrategyStats::of(population);
+        self.unique_genotypes =
+            population.iter().map(|(ge, _)| ge).collect::<HashSet<_>>().len();
+
+        // Distances are optional
+        let best = &population[0].0;
+        if best.distance(best).is_none() {
+            self.mean_distance = None;
+            self.mean_distance_to_best = None;
+            return;
+        }
+
+        // Mean of the distances of the given pairs (summed up sequentially to
+        // keep the result independent of the amount of threads)
+        let mean = |pairs: Vec<(usize, usize)>| {
+            let distances: Vec<f64> = pairs
+                .par_iter()
+                .map(|(a, b)| {
+                    population[*a].0.distance(&population[*b].0).unwrap_or(0.)
+                })
+                .collect();
+
+            distances.iter().sum::<f64>() / distances.len().max(1) as f64
+        };
+
+        self.mean_distance_to_best =
+            Some(mean((1..n).map(|i| (0, i)).collect()));
+
+        let pairs: Vec<(usize, usize)> =
+            if n * (n - 1) / 2 <= MAX_DISTANCE_PAIRS {
+                (0..n).flat_map(|a| ((a + 1)..n).map(move |b| (a, b))).collect()
+            } else {
+                (0..MAX_DISTANCE_PAIRS)
+                    .map(|_| {
+                        let a = rng.gen_range(0..n);
+                        let b = rng.gen_range(0..n - 1);
+                        (a, if b >= a { b + 1 } else { b })
+                    })
+                    .collect()
+            };
+
+        self.mean_distance = Some(mean(pairs));
     }
 }
 
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Returns the components of the best individual and the mean of every
+/// component of a population (sorted ascendingly by objective value).
+fn component_stats<Ge, Ov: ObjectiveValue>(
+    population: &[(Ge, Ov)],
+) -> (Vec<f64>, Vec<f64>) {
+    let best = population.first().map_or(vec![], |(_, ov)| ov.components());
+
+    let mut mean = vec![0.; best.len()];
+    for (_, ov) in population {
+        for (sum, component) in mean.iter_mut().zip(ov.components()) {
+            *sum += component;
+        }
+    }
+    mean.iter_mut().for_each(|sum| *sum /= population.len() as f64);
+
+    (best, mean)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,237 @@
+//! Self-adaptation of the mutation parameters in the style of evolution
+//! strategies.
+//!
+//! Every individual carries its own strategy parameters (mutation rate and
+//! standard deviation `sigma`, see [`Strategy`]), which the genotype exposes
+//! with [`Genotype::strategy`] and [`Genotype::strategy_mut`]. Before the
+//! mutation of an offspring:
+//! 1. The offspring inherits the strategy parameters: an offspring which is
+//!    equal to one of its parents keeps the parameters of this parent, all
+//!    other offspring get the mean of the parameters of both parents
+//!    (intermediate recombination).
+//! 2. The strategy parameters are mutated log-normally:
+//!    `x' = x * exp(tau_global * N(0, 1) + tau * N_x(0, 1))`, where the first
+//!    random number is shared by the rate and `sigma`.
+//! 3. The offspring is mutated with its own rate (passed to
+//!    [`Mutation::exec`](crate::operators::Mutation::exec)). Mutation
+//!    operators which need a standard deviation read `sigma` from the
+//!    chromosome.
+//!
+//! The strategy parameters of the initial individuals (and of the individuals
+//! created by restarts) are set to the mutation rate of the parameters and the
+//! `initial_sigma`. Self-adaptation is configured in the parameters (see
+//! [`parameters::Builder::set_self_adaptation`](crate::parameters::Builder::set_self_adaptation)),
+//! the statistics of the strategy parameters of the population are recorded
+//! in the runtime data
+//! ([`RuntimeData::strategy`](crate::runtime_data::RuntimeData::strategy)).
+//!
+//! As the strategy parameters are part of the genotype, they affect its
+//! equality (e.g. for the cache and the number of unique genotypes).
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype},
+    rng::GaRng,
+};
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+// Strategy ////////////////////////////////////////////////////////////////////
+
+/// Strategy parameters of an individual.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Strategy {
+    pub rate: f32,
+    pub sigma: f64,
+}
+
+/// Strategy parameters are compared bitwise (they are never NaN).
+impl Eq for Strategy {}
+
+impl std::hash::Hash for Strategy {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.rate.to_bits().hash(state);
+        self.sigma.to_bits().hash(state);
+    }
+}
+
+impl Strategy {
+    /// Intermediate recombination (mean of both parents).
+    pub fn recombine(&self, other: &Self) -> Self {
+        Self {
+            rate: (self.rate + other.rate) / 2.,
+            sigma: (self.sigma + other.sigma) / 2.,
+        }
+    }
+}
+
+// SelfAdaptation //////////////////////////////////////////////////////////////
+
+/// Configuration of the self-adaptation (see the module documentation).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptation {
+    /// Learning rate of the random number shared by all strategy parameters.
+    pub tau_global: f64,
+
+    /// Learning rate of the random numbers of the single strategy parameters.
+    pub tau: f64,
+
+    /// Bounds (min, max) of the mutation rate.
+    pub rate_bounds: (f32, f32),
+
+    /// Bounds (min, max) of the standard deviation.
+    pub sigma_bounds: (f64, f64),
+
+    /// Standard deviation of the initial individuals.
+    pub initial_sigma: f64,
+}
+
+impl SelfAdaptation {
+    /// Recommended learning rates for chromosomes of the given length
+    /// (`tau_global = 1 / sqrt(2 n)` and `tau = 1 / sqrt(2 sqrt(n))`).
+    pub fn for_length(length: usize) -> Self {
+        let n = length.max(1) as f64;
+
+        Self {
+            tau_global: 1. / (2. * n).sqrt(),
+            tau: 1. / (2. * n.sqrt()).sqrt(),
+            rate_bounds: (1. / (10. * n as f32), 0.5),
+            sigma_bounds: (1e-9, f64::MAX),
+            initial_sigma: 1.,
+        }
+    }
+
+    pub fn identifier(&self) -> String {
+        format!(
+            "sa-{:.3}-{:.3}-{:?}-{:?}-{}",
+            self.tau_global,
+            self.tau,
+            self.rate_bounds,
+            self.sigma_bounds,
+            self.initial_sigma
+        )
+    }
+
+    /// Sets the strategy parameters of a new individual.
+    pub fn initialize<Ctx: Context, Ge: Genotype<Ctx>>(
+        &self,
+        chromosome: &mut Ge,
+        rate: f32,
+    ) {
+        if let Some(strategy) = chromosome.strategy_mut() {
+            *strategy = Strategy {
+                rate: rate.clamp(self.rate_bounds.0, self.rate_bounds.1),
+                sigma: self.initial_sigma,
+            };
+        }
+    }
+
+    /// Mutates the strategy parameters log-normally.
+    pub fn mutate(&self, strategy: &mut Strategy, rng: &mut GaRng) {
+        let global = self.tau_global * rng.sample::<f64, _>(StandardNormal);
+        let rate = self.tau * rng.sample::<f64, _>(StandardNormal);
+        let sigma = self.tau * rng.sample::<f64, _>(StandardNormal);
+
+        let (min, max) = self.rate_bounds;
+        strategy.rate =
+            (strategy.rate * (global + rate).exp() as f32).clamp(min, max);
+
+        let (min, max) = self.sigma_bounds;
+        strategy.sigma =
+            (strategy.sigma * (global + sigma).exp()).clamp(min, max);
+    }
+
+    /// Inherits (see the module documentation) and mutates the strategy
+    /// parameters of an offspring and returns its mutation rate. `parent` is
+    /// the index of the parent, which is equal to the offspring (if there is
+    /// one).
+    pub fn adapt<Ctx: Context, Ge: Genotype<Ctx>>(
+        &self,
+        offspring: &mut Ge,
+        parents: [&Ge; 2],
+        parent: Option<usize>,
+        rng: &mut GaRng,
+    ) -> Option<f32> {
+        let (Some(a), Some(b)) =
+            (parents[0].strategy().copied(), parents[1].strategy().copied())
+        else {
+            return None;
+        };
+
+        let strategy = offspring.strategy_mut()?;
+        *strategy = match parent {
+            Some(0) => a,
+            Some(_) => b,
+            None => a.recombine(&b),
+        };
+        self.mutate(strategy, rng);
+
+        Some(strategy.rate)
+    }
+}
+
+// StrategyStats ///////////////////////////////////////////////////////////////
+
+/// Statistics of the strategy parameters of the population.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StrategyStats {
+    pub rate_mean: f64,
+    pub rate_std_dev: f64,
+    pub rate_min: f64,
+    pub rate_max: f64,
+
+    pub sigma_mean: f64,
+    pub sigma_std_dev: f64,
+    pub sigma_min: f64,
+    pub sigma_max: f64,
+}
+
+impl StrategyStats {
+    /// Returns the statistics of the individuals with strategy parameters, or
+    /// `None` if there are none.
+    pub fn of<Ctx: Context, Ge: Genotype<Ctx>, Ov>(
+        population: &[(Ge, Ov)],
+    ) -> Option<Self> {
+        let strategies: Vec<&Strategy> =
+            population.iter().filter_map(|(ge, _)| ge.strategy()).collect();
+        if strategies.is_empty() {
+            return None;
+        }
+
+        let rates: Vec<f64> =
+            strategies.iter().map(|s| s.rate as f64).collect();
+        let sigmas: Vec<f64> = strategies.iter().map(|s| s.sigma).collect();
+        let (rate_mean, rate_std_dev, rate_min, rate_max) = moments(&rates);
+        let (sigma_mean, sigma_std_dev, sigma_min, sigma_max) =
+            moments(&sigmas);
+
+        Some(Self {
+            rate_mean,
+            rate_std_dev,
+            rate_min,
+            rate_max,
+            sigma_mean,
+            sigma_std_dev,
+            sigma_min,
+            sigma_max,
+        })
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Mean, (population) standard deviation, minimum and maximum of the values.
+fn moments(values: &[f64]) -> (f64, f64, f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    (mean, variance.sqrt(), min, max)
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,112 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{Context, Genotype, ObjectiveValue},
+    operators::{Crossover, Mutation},
+    process::{
+        rejection::Rejection, replacement::Replacement, selection::Selection,
+        termination::Termination,
+    },
+    report::ReportLog,
+    rng::{GaRng, Seeder},
+    runtime_data::RuntimeData,
+};
+
+// State ///////////////////////////////////////////////////////////////////////
+
+/// The state of an algorithm execution, which changes from generation to
+/// generation. It is created by [`crate::Algorithm::init`] and can be
+/// inspected between generations with [`crate::Algorithm::state`].
+pub struct State<
+    Ov: ObjectiveValue + Into<T>,
+    Ctx: Context,
+    Ge: Genotype<Ctx>,
+    Cr: Crossover<Ctx, Ge>,
+    Mu: Mutation<Ctx, Ge>,
+    T,
+    Se: Selection<Ov, Ctx, Ge, T>,
+    Re: Rejection<Ov, Ctx, Ge>,
+    Rp: Replacement<(Ge, Ov)>,
+    Te: Termination<Ov>,
+> {
+    pub(crate) population: Vec<(Ge, Ov)>,
+    pub(crate) rtd: RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>,
+    pub(crate) report_log: Vec<ReportLog>,
+
+    pub(crate) seeder: Seeder,
+    pub(crate) rng: GaRng,
+
+    pub(crate) parameter_identifier: String,
+    pub(crate) dynamics_identifier: Option<String>,
+
+    /// Start of the runtime measurement.
+    pub(crate) start: std::time::Instant,
+
+    /// Runtime of previous executions (before the algorithm was resumed from
+    /// a checkpoint).
+    pub(crate) elapsed: std::time::Duration,
+}
+
+impl<
+        Ov: ObjectiveValue + Into<T>,
+        Ctx: Context,
+        Ge: Genotype<Ctx>,
+        Cr: Crossover<Ctx, Ge>,
+        Mu: Mutation<Ctx, Ge>,
+        T,
+        Se: Selection<Ov, Ctx, Ge, T>,
+        Re: Rejection<Ov, Ctx, Ge>,
+        Rp: Replacement<(Ge, Ov)>,
+        Te: Termination<Ov>,
+    > State<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te>
+{
+    /// The current population sorted ascendingly by objective value, so that
+    /// the best individual is the FIRST one in the list.
+    pub fn population(&self) -> &[(Ge, Ov)] {
+        &self.population
+    }
+
+    /// Runtime data (metrics) of the current generation.
+    pub fn runtime_data(
+        &self,
+    ) -> &RuntimeData<Ov, Ctx, Ge, Cr, Mu, T, Se, Re, Rp, Te> {
+        &self.rtd
+    }
+
+    /// The current generation.
+    pub fn generation(&self) -> usize {
+        self.rtd.generation
+    }
+
+    /// Log of all generations executed so far.
+    pub fn log(&self) -> &[ReportLog] {
+        &self.report_log
+    }
+
+    /// Seed of the algorithm execution.
+    pub fn seed(&self) -> u64 {
+        self.seeder.seed()
+    }
+
+    /// Total runtime of the algorithm execution.
+    pub fn runtime(&self) -> std::time::Duration {
+        self.elapsed + self.start.elapsed()
+    }
+
+    /// Updates the best and worst objective values of the runtime data with
+    /// the (sorted) population, after individuals were added from outside of
+    /// the generation. An improvement counts as a success of the current
+    /// generation.
+    pub(crate) fn update_best_and_worst(&mut self) {
+        let rtd = &mut self.rtd;
+        let best = &self.population.first().expect("empty population").1;
+        if *best < rtd.best {
+            rtd.best = best.clone();
+            rtd.success = true;
+            rtd.last_success = rtd.generation;
+            rtd.time_to_best = self.elapsed + self.start.elapsed();
+        }
+        rtd.worst = self.population.last().expect("empty population").1.clone();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
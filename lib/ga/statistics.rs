@@ -0,0 +1,240 @@
+//! Non-parametric statistical tests and effect sizes, which are used to
+//! compare the results of algorithm configurations (e.g. by the racing
+//! procedure of the [`crate::tuning`] module and by [`crate::analysis`]). All
+//! tests use the normal (or chi-squared) approximation of their test statistic
+//! and are two-sided.
+
+// Imports /////////////////////////////////////////////////////////////////////
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+
+// Test Result /////////////////////////////////////////////////////////////////
+
+/// Result of a statistical test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TestResult {
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// Result of a test, which could not be executed (e.g. too few samples).
+    fn inconclusive() -> Self {
+        Self { statistic: 0., p_value: 1. }
+    }
+
+    pub fn is_significant(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Returns the ranks (starting at 1) of the values. Equal values get the mean
+/// of their ranks.
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+    let mut ranks = vec![0.; values.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+
+        // Ranks i+1 ..= j+1 are tied
+        let rank = (i + j + 2) as f64 / 2.;
+        for index in &order[i..=j] {
+            ranks[*index] = rank;
+        }
+
+        i = j + 1;
+    }
+
+    ranks
+}
+
+/// Sum of `t^3 - t` over all groups of `t` tied values (used for the tie
+/// corrections of rank-based tests).
+pub(crate) fn tie_sum(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|group| (group.len().pow(3) - group.len()) as f64)
+        .sum()
+}
+
+/// Two-sided p-value of a standard normally distributed test statistic.
+pub(crate) fn normal_p_value(z: f64) -> f64 {
+    let normal = Normal::new(0., 1.).expect("valid normal distribution");
+    (2. * (1. - normal.cdf(z.abs()))).clamp(0., 1.)
+}
+
+/// Friedman test: are there differences between the treatments (columns)?
+/// Every block (row) contains the values of all treatments, which are ranked
+/// within the block.
+pub fn friedman(blocks: &[Vec<f64>]) -> TestResult {
+    let n = blocks.len();
+    let k = blocks.first().map_or(0, |b| b.len());
+    if n == 0 || k < 2 {
+        return TestResult::inconclusive();
+    }
+
+    let mut rank_sums = vec![0.; k];
+    let mut ties = 0.;
+    for block in blocks {
+        for (sum, rank) in rank_sums.iter_mut().zip(ranks(block)) {
+            *sum += rank;
+        }
+        ties += tie_sum(block);
+    }
+
+    let (n, k) = (n as f64, k as f64);
+    let statistic = 12. / (n * k * (k + 1.))
+        * rank_sums.iter().map(|r| r * r).sum::<f64>()
+        - 3. * n * (k + 1.);
+
+    // Tie correction (all values of all blocks equal: no differences)
+    let correction = 1. - ties / (n * (k.powi(3) - k));
+    if correction <= 0. {
+        return TestResult::inconclusive();
+    }
+    let statistic = statistic / correction;
+
+    let chi_squared =
+        ChiSquared::new(k - 1.).expect("valid chi-squared distribution");
+    let p_value = (1. - chi_squared.cdf(statistic)).clamp(0., 1.);
+
+    TestResult { statistic, p_value }
+}
+
+/// Wilcoxon signed-rank test: do the paired samples `x` and `y` differ? The
+/// statistic is the standardized sum of the ranks of the positive differences
+/// `x - y` (zero differences are dropped).
+pub fn wilcoxon_signed_rank(x: &[f64], y: &[f64]) -> TestResult {
+    let differences: Vec<f64> =
+        x.iter().zip(y).map(|(a, b)| a - b).filter(|d| *d != 0.).collect();
+
+    let n = differences.len() as f64;
+    if n == 0. {
+        return TestResult::inconclusive();
+    }
+
+    let magnitudes: Vec<f64> = differences.iter().map(|d| d.abs()).collect();
+    let w_plus: f64 = ranks(&magnitudes)
+        .into_iter()
+        .zip(&differences)
+        .filter(|(_, d)| **d > 0.)
+        .map(|(rank, _)| rank)
+        .sum();
+
+    let mean = n * (n + 1.) / 4.;
+    let variance =
+        n * (n + 1.) * (2. * n + 1.) / 24. - tie_sum(&magnitudes) / 48.;
+    if variance <= 0. {
+        return TestResult::inconclusive();
+    }
+
+    // Continuity correction
+    let deviation = ((w_plus - mean).abs() - 0.5).max(0.);
+    let statistic = deviation.copysign(w_plus - mean) / variance.sqrt();
+
+    TestResult { statistic, p_value: normal_p_value(statistic) }
+}
+
+/// Mann-Whitney U test (Wilcoxon rank-sum test): do the independent samples
+/// `x` and `y` differ? The statistic is the standardized U statistic of `x`
+/// (positive if the values of `x` tend to be larger).
+pub fn mann_whitney_u(x: &[f64], y: &[f64]) -> TestResult {
+    if x.is_empty() || y.is_empty() {
+        return TestResult::inconclusive();
+    }
+
+    let combined: Vec<f64> = x.iter().chain(y).copied().collect();
+    let rank_sum: f64 = ranks(&combined)[..x.len()].iter().sum();
+
+    let (n1, n2) = (x.len() as f64, y.len() as f64);
+    let n = n1 + n2;
+    let u = rank_sum - n1 * (n1 + 1.) / 2.;
+
+    let mean = n1 * n2 / 2.;
+    let variance =
+        n1 * n2 / 12. * ((n + 1.) - tie_sum(&combined) / (n * (n - 1.)));
+    if variance <= 0. {
+        return TestResult::inconclusive();
+    }
+
+    // Continuity correction
+    let deviation = ((u - mean).abs() - 0.5).max(0.);
+    let statistic = deviation.copysign(u - mean) / variance.sqrt();
+
+    TestResult { statistic, p_value: normal_p_value(statistic) }
+}
+
+/// Vargha-Delaney A12 effect size: the probability that a value of `x` is
+/// larger than a value of `y` (ties count half). 0.5 means no effect.
+pub fn vargha_delaney_a12(x: &[f64], y: &[f64]) -> f64 {
+    if x.is_empty() || y.is_empty() {
+        return 0.5;
+    }
+
+    let wins: f64 = x
+        .iter()
+        .flat_map(|a| y.iter().map(move |b| (a, b)))
+        .map(|(a, b)| match a.total_cmp(b) {
+            std::cmp::Ordering::Greater => 1.,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.,
+        })
+        .sum();
+
+    wins / (x.len() * y.len()) as f64
+}
+
+/// Magnitude of an A12 effect size (thresholds by Vargha and Delaney).
+pub fn effect_magnitude(a12: f64) -> &'static str {
+    match (a12 - 0.5).abs() {
+        d if d < 0.06 => "negligible",
+        d if d < 0.14 => "small",
+        d if d < 0.21 => "medium",
+        _ => "large",
+    }
+}
+
+/// Holm-Bonferroni correction: returns the adjusted p-values (in the order of
+/// the given p-values) of a family of tests.
+pub fn holm(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|a, b| p_values[*a].total_cmp(&p_values[*b]));
+
+    let mut adjusted = vec![0.; m];
+    let mut max = 0_f64;
+    for (i, index) in order.into_iter().enumerate() {
+        // The adjusted p-values must not decrease
+        max = max.max(((m - i) as f64 * p_values[index]).min(1.));
+        adjusted[index] = max;
+    }
+
+    adjusted
+}
+
+/// Returns the `q`-quantile (0 to 1) of the values (linear interpolation
+/// between the closest ranks), or `None` if there are no values.
+pub fn quantile(values: &[f64], q: f64) -> Option<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let last = sorted.len().checked_sub(1)?;
+    let position = q.clamp(0., 1.) * last as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    let fraction = position - lower as f64;
+
+    Some(sorted[lower] + fraction * (sorted[upper] - sorted[lower]))
+}
+
+////////////////////////////////////////////////////////////////////////////////
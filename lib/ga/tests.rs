@@ -0,0 +1,2022 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    encoding::{self, Context, Encoding, Genotype, ObjectiveValue, Phenotype},
+    operators::{self, Crossover, Mutation},
+    parameters::Parameters,
+    process::{
+        rejection::Reject, replacement::Replace, selection::Select,
+        termination::Terminate,
+    },
+    report::Report,
+    rng::GaRng,
+    self_adaptation::Strategy,
+};
+use hashbrown::HashSet;
+use rand::Rng;
+
+// Toy Encoding ////////////////////////////////////////////////////////////////
+// "OneMax" as minimization problem: the cost of a bit string is the amount of
+// zeros it contains.
+
+const LEN: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Cost(usize);
+
+impl From<Cost> for usize {
+    fn from(value: Cost) -> Self {
+        value.0
+    }
+}
+
+impl From<Cost> for f64 {
+    fn from(value: Cost) -> Self {
+        value.0 as f64
+    }
+}
+
+impl ObjectiveValue for Cost {
+    fn calc_average(values: &[Self]) -> f32 {
+        values.iter().map(|x| x.0 as f32).sum::<f32>() / values.len() as f32
+    }
+
+    fn calc_distribution(values: &[Self]) -> Vec<usize> {
+        let mut dist = vec![0; LEN + 1];
+        values.iter().for_each(|x| dist[x.0] += 1);
+        dist
+    }
+
+    fn to_usize(&self) -> usize {
+        self.0
+    }
+}
+
+struct Ctx;
+impl Context for Ctx {}
+
+#[cfg(feature = "serde")]
+impl crate::checkpoint::ContextState for Ctx {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Chromosome(Vec<bool>);
+
+impl Genotype<Ctx> for Chromosome {
+    fn generate(amount: usize, _ctx: &Ctx, rng: &mut GaRng) -> Vec<Self> {
+        (0..amount)
+            .map(|_| Self((0..LEN).map(|_| rng.gen()).collect()))
+            .collect()
+    }
+
+    fn calc_diversity<Ov: ObjectiveValue>(
+        _population: &[(Self, Ov)],
+    ) -> Vec<usize> {
+        vec![]
+    }
+
+    fn distance(&self, other: &Self) -> Option<f64> {
+        Some(self.0.iter().zip(&other.0).filter(|(a, b)| a != b).count() as f64)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Ph(usize);
+
+impl Phenotype<Cost, Ctx, Chromosome> for Ph {
+    fn derive(&self, chromosome: &Chromosome, _ctx: &Ctx) -> Self {
+        Self(chromosome.0.iter().filter(|x| !**x).count())
+    }
+
+    fn evaluate(&self, _ctx: &Ctx) -> Cost {
+        Cost(self.0)
+    }
+
+    fn evaluate_delta(
+        &self,
+        parent: &Chromosome,
+        parent_ov: &Cost,
+        chromosome: &Chromosome,
+        changed: &[usize],
+        _ctx: &Ctx,
+    ) -> Option<Cost> {
+        let zeros = changed.iter().filter(|i| !chromosome.0[**i]).count();
+        let ones = changed.iter().filter(|i| !parent.0[**i]).count();
+        Some(Cost(parent_ov.0 + zeros - ones))
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Cx;
+impl Crossover<Ctx, Chromosome> for Cx {
+    fn exec(
+        &self,
+        parent_0: &Chromosome,
+        parent_1: &Chromosome,
+        rate: Option<f32>,
+        rng: &mut GaRng,
+        _context: &Ctx,
+    ) -> (Chromosome, Chromosome) {
+        let (a, b) =
+            operators::crossover::uniform(&parent_0.0, &parent_1.0, rate, rng);
+
+        (
+            Chromosome(a.into_iter().cloned().collect()),
+            Chromosome(b.into_iter().cloned().collect()),
+        )
+    }
+
+    fn identifier(&self) -> String {
+        "uni".into()
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Mu;
+impl Mutation<Ctx, Chromosome> for Mu {
+    fn exec(
+        &self,
+        chromosome: &mut Chromosome,
+        rate: f32,
+        rng: &mut GaRng,
+        context: &Ctx,
+    ) {
+        self.exec_tracked(chromosome, rate, rng, context);
+    }
+
+    fn exec_tracked(
+        &self,
+        chromosome: &mut Chromosome,
+        rate: f32,
+        rng: &mut GaRng,
+        _context: &Ctx,
+    ) -> Option<Vec<usize>> {
+        let mut changed = vec![];
+        for (i, gene) in chromosome.0.iter_mut().enumerate() {
+            if rng.gen::<f32>() < rate {
+                *gene = !*gene;
+                changed.push(i);
+            }
+        }
+
+        Some(changed)
+    }
+
+    fn identifier(&self) -> String {
+        "flip".into()
+    }
+}
+
+// Self-adaptive variant: the chromosome carries its own strategy parameters
+// (see `crate::self_adaptation`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Adaptive(Vec<bool>, Strategy);
+
+impl Genotype<Ctx> for Adaptive {
+    fn generate(amount: usize, ctx: &Ctx, rng: &mut GaRng) -> Vec<Self> {
+        let strategy = Strategy { rate: 0., sigma: 0. };
+        Chromosome::generate(amount, ctx, rng)
+            .into_iter()
+            .map(|c| Self(c.0, strategy))
+            .collect()
+    }
+
+    fn calc_diversity<Ov: ObjectiveValue>(
+        _population: &[(Self, Ov)],
+    ) -> Vec<usize> {
+        vec![]
+    }
+
+    fn strategy(&self) -> Option<&Strategy> {
+        Some(&self.1)
+    }
+
+    fn strategy_mut(&mut self) -> Option<&mut Strategy> {
+        Some(&mut self.1)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AdaptivePh(usize);
+
+impl Phenotype<Cost, Ctx, Adaptive> for AdaptivePh {
+    fn derive(&self, chromosome: &Adaptive, _ctx: &Ctx) -> Self {
+        Self(chromosome.0.iter().filter(|x| !**x).count())
+    }
+
+    fn evaluate(&self, _ctx: &Ctx) -> Cost {
+        Cost(self.0)
+    }
+}
+
+impl Crossover<Ctx, Adaptive> for Cx {
+    fn exec(
+        &self,
+        parent_0: &Adaptive,
+        parent_1: &Adaptive,
+        rate: Option<f32>,
+        rng: &mut GaRng,
+        _context: &Ctx,
+    ) -> (Adaptive, Adaptive) {
+        let (a, b) =
+            operators::crossover::uniform(&parent_0.0, &parent_1.0, rate, rng);
+
+        (
+            Adaptive(a.into_iter().cloned().collect(), parent_0.1),
+            Adaptive(b.into_iter().cloned().collect(), parent_1.1),
+        )
+    }
+
+    fn identifier(&self) -> String {
+        "uni".into()
+    }
+}
+
+impl Mutation<Ctx, Adaptive> for Mu {
+    fn exec(
+        &self,
+        chromosome: &mut Adaptive,
+        rate: f32,
+        rng: &mut GaRng,
+        _context: &Ctx,
+    ) {
+        // The rate of the individual is passed to the mutation
+        assert_eq!(rate, chromosome.1.rate);
+
+        for gene in chromosome.0.iter_mut() {
+            if rng.gen::<f32>() < rate {
+                *gene = !*gene;
+            }
+        }
+    }
+
+    fn identifier(&self) -> String {
+        "flip".into()
+    }
+}
+
+// Bi-objective variant: the number of zeros (1st objective) and the number of
+// ones at even positions (2nd objective) are minimized. The Pareto front
+// consists of all chromosomes with ones at all odd positions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct BiCost(usize, usize);
+
+impl From<BiCost> for usize {
+    fn from(value: BiCost) -> Self {
+        value.0 + value.1
+    }
+}
+
+impl ObjectiveValue for BiCost {
+    fn calc_average(values: &[Self]) -> f32 {
+        values.iter().map(|x| x.to_usize() as f32).sum::<f32>()
+            / values.len() as f32
+    }
+
+    fn calc_distribution(_values: &[Self]) -> Vec<usize> {
+        vec![]
+    }
+
+    fn to_usize(&self) -> usize {
+        self.0 + self.1
+    }
+}
+
+impl crate::pareto::MultiObjective for BiCost {
+    fn objectives(&self) -> Vec<f64> {
+        vec![self.0 as f64, self.1 as f64]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BiPh(BiCost);
+
+impl Phenotype<BiCost, Ctx, Chromosome> for BiPh {
+    fn derive(&self, chromosome: &Chromosome, _ctx: &Ctx) -> Self {
+        let zeros = chromosome.0.iter().filter(|x| !**x).count();
+        let ones = chromosome.0.iter().step_by(2).filter(|x| **x).count();
+        Self(BiCost(zeros, ones))
+    }
+
+    fn evaluate(&self, _ctx: &Ctx) -> BiCost {
+        self.0
+    }
+}
+
+// Helpers /////////////////////////////////////////////////////////////////////
+
+type Alg = crate::Algorithm<
+    Cost,
+    Ctx,
+    Chromosome,
+    Ph,
+    Cx,
+    Mu,
+    usize,
+    Select,
+    Reject,
+    Replace,
+    Terminate<Cost>,
+    (),
+    (),
+>;
+
+fn algorithm(seed: Option<u64>) -> Alg {
+    let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+        encoding::Builder::new().set_context(Ctx).set_phenotype(Ph(0)).build();
+
+    let mut builder = crate::parameters::Builder::for_encoding(&encoding)
+        .set_population_size(50)
+        .set_crossover_rate(Some(0.8))
+        .set_mutation_rate(0.05)
+        .set_selection(Select::Tournament(2))
+        .set_crossover(Cx)
+        .set_mutation(Mu)
+        .set_rejection(Reject::None)
+        .set_replacement(Replace::EliteAbsolute(1))
+        .set_termination(Terminate::Generations(30));
+
+    if let Some(seed) = seed {
+        builder = builder.set_seed(seed);
+    }
+
+    let parameters: Parameters<
+        Cost,
+        Ctx,
+        Chromosome,
+        Cx,
+        Mu,
+        usize,
+        Select,
+        Reject,
+        Replace,
+        Terminate<Cost>,
+    > = builder.build();
+
+    crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(parameters)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .build()
+}
+
+fn run(seed: Option<u64>) -> Report<Cost, Ctx, Chromosome> {
+    algorithm(seed).run()
+}
+
+fn log_of(report: &Report<Cost, Ctx, Chromosome>) -> Vec<(usize, usize, f64)> {
+    report.log.iter().map(|l| (l.best, l.worst, l.mean)).collect()
+}
+
+// Tests ///////////////////////////////////////////////////////////////////////
+
+#[test]
+fn same_seed_same_report() {
+    let a = run(Some(7));
+    let b = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap()
+        .install(|| run(Some(7)));
+
+    assert_eq!(a.seed, 7);
+    assert_eq!(a.population, b.population);
+    assert_eq!(log_of(&a), log_of(&b));
+}
+
+#[test]
+fn random_seed_is_reported() {
+    let a = run(None);
+    let b = run(Some(a.seed));
+
+    assert_eq!(a.population, b.population);
+    assert_eq!(log_of(&a), log_of(&b));
+}
+
+#[test]
+fn step_wise_execution() {
+    let a = run(Some(5));
+
+    let mut alg = algorithm(Some(5));
+    alg.init();
+    while !alg.is_terminated() {
+        alg.step();
+        assert_eq!(alg.state().generation(), alg.state().log().len());
+    }
+    let b = alg.finish();
+
+    assert_eq!(a.population, b.population);
+    assert_eq!(log_of(&a), log_of(&b));
+}
+
+#[test]
+fn inject_individuals() {
+    let mut alg = algorithm(Some(5));
+    alg.init();
+    alg.inject(vec![Chromosome(vec![true; LEN])]);
+
+    let state = alg.state();
+    assert_eq!(state.population().len(), 50);
+    assert_eq!(state.population()[0].1, Cost(0));
+
+    // The optimum counts immediately
+    let rtd = state.runtime_data();
+    assert_eq!(rtd.best, Cost(0));
+    assert!(rtd.success);
+    assert_eq!(rtd.worst, state.population()[49].1);
+
+    // Surplus individuals are ignored
+    let evaluations = rtd.evaluations;
+    alg.inject(vec![Chromosome(vec![false; LEN]); 60]);
+
+    let state = alg.state();
+    assert_eq!(state.population().len(), 50);
+    assert_eq!(state.runtime_data().evaluations, evaluations + 50);
+    assert_eq!(state.runtime_data().best, Cost(0));
+    assert_eq!(state.runtime_data().worst, Cost(LEN));
+}
+
+#[test]
+fn islands_are_reproducible() {
+    use crate::islands::{Emigrate, Immigrate, Migration, Topology};
+
+    let run_islands = || {
+        crate::islands::Builder::new()
+            .set_islands(vec![
+                algorithm(None),
+                algorithm(None),
+                algorithm(None),
+            ])
+            .set_migration(Migration {
+                interval: 5,
+                size: 3,
+                topology: Topology::Random,
+                emigrate: Emigrate::Diverse,
+                immigrate: Immigrate::ReplaceWorstIfBetter,
+            })
+            .set_seed(11)
+            .build()
+            .run()
+    };
+
+    let a = run_islands();
+    let b = run_islands();
+
+    assert_eq!(a.len(), 3);
+    for (a, b) in a.iter().zip(b.iter()) {
+        assert_eq!(a.population, b.population);
+        assert_eq!(log_of(a), log_of(b));
+    }
+}
+
+#[test]
+#[should_panic(expected = "the island model needs at least one island")]
+fn islands_must_not_be_empty() {
+    use crate::islands::{Emigrate, Immigrate, Migration, Topology};
+
+    crate::islands::Builder::new()
+        .set_islands(Vec::<Alg>::new())
+        .set_migration(Migration {
+            interval: 5,
+            size: 3,
+            topology: Topology::Ring,
+            emigrate: Emigrate::Best,
+            immigrate: Immigrate::ReplaceWorst,
+        })
+        .build();
+}
+
+#[test]
+fn immigrants_update_the_runtime_data() {
+    use crate::islands::Immigrate;
+
+    let mut alg = algorithm(Some(4));
+    alg.init();
+    alg.step();
+    assert!(alg.state().runtime_data().best > Cost(0));
+
+    // An optimal immigrant replaces the worst resident
+    let optimum = (Chromosome(vec![true; LEN]), Cost(0));
+    let mut rng = crate::rng::Seeder::new(Some(0)).main();
+    alg.immigrate(vec![optimum.clone()], &Immigrate::ReplaceWorst, &mut rng);
+
+    let rtd = alg.state().runtime_data();
+    assert_eq!(alg.state().population()[0], optimum);
+    assert_eq!(rtd.best, Cost(0));
+    assert!(rtd.success);
+    assert_eq!(rtd.last_success, rtd.generation);
+    #[cfg(feature = "cache")]
+    assert_eq!(alg.cache.get(&optimum.0), Some(Cost(0)));
+}
+
+#[test]
+fn observers_are_notified() {
+    use crate::observer::{Csv, Event, Observer};
+    use crate::runtime_data::RuntimeData;
+    use std::sync::{Arc, Mutex};
+
+    // Records the names of all events
+    struct Recorder(Arc<Mutex<Vec<&'static str>>>);
+
+    impl
+        Observer<
+            Cost,
+            Ctx,
+            Chromosome,
+            Cx,
+            Mu,
+            usize,
+            Select,
+            Reject,
+            Replace,
+            Terminate<Cost>,
+        > for Recorder
+    {
+        fn notify(
+            &mut self,
+            event: Event,
+            _rtd: &RuntimeData<
+                Cost,
+                Ctx,
+                Chromosome,
+                Cx,
+                Mu,
+                usize,
+                Select,
+                Reject,
+                Replace,
+                Terminate<Cost>,
+            >,
+            _population: &[(Chromosome, Cost)],
+        ) {
+            self.0.lock().unwrap().push(event.name());
+        }
+    }
+
+    let path = std::env::temp_dir()
+        .join(format!("ga-observer-{}.csv", std::process::id()));
+    let events = Arc::new(Mutex::new(vec![]));
+
+    let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+        encoding::Builder::new().set_context(Ctx).set_phenotype(Ph(0)).build();
+
+    crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(algorithm(Some(9)).params)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .add_observer(Recorder(events.clone()))
+        .add_observer(Csv::create(&path).unwrap())
+        .build()
+        .run();
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.first(), Some(&"start"));
+    assert_eq!(events.last(), Some(&"finish"));
+    assert_eq!(events.iter().filter(|x| **x == "generation").count(), 30);
+    assert!(events.contains(&"improvement"));
+
+    // Header, initial population and one row per generation
+    let csv = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(csv.lines().count(), 32);
+}
+
+#[test]
+fn failing_observers_are_disabled() {
+    use crate::observer::{Csv, JsonLines};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    // Counts the write attempts, all of which fail
+    struct Failing(Arc<AtomicUsize>);
+
+    impl std::io::Write for Failing {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Err(std::io::ErrorKind::Other.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Err(std::io::ErrorKind::Other.into())
+        }
+    }
+
+    let csv = Arc::new(AtomicUsize::new(0));
+    let json = Arc::new(AtomicUsize::new(0));
+
+    let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+        encoding::Builder::new().set_context(Ctx).set_phenotype(Ph(0)).build();
+
+    // The algorithm execution continues
+    let report = crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(algorithm(Some(9)).params)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .add_observer(Csv::new(Failing(csv.clone())))
+        .add_observer(JsonLines::new(Failing(json.clone())))
+        .build()
+        .run();
+    assert_eq!(report.generation, 30);
+
+    // Only the first write is attempted
+    assert_eq!(csv.load(Ordering::Relaxed), 1);
+    assert_eq!(json.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn memetic_local_search() {
+    use crate::operators::{
+        local_search::{Evaluator, Mode, Policy},
+        LocalSearch,
+    };
+
+    // Sets random bits, keeping the best result (4 evaluations)
+    struct Climb;
+
+    impl LocalSearch<Cost, Ctx, Chromosome> for Climb {
+        fn exec(
+            &self,
+            chromosome: &Chromosome,
+            objective_value: &Cost,
+            evaluator: &mut Evaluator<Chromosome, Cost>,
+            rng: &mut GaRng,
+            _context: &Ctx,
+        ) -> (Chromosome, Cost) {
+            let mut best = (chromosome.clone(), *objective_value);
+            for _ in 0..4 {
+                let mut x = best.0.clone();
+                x.0[rng.gen_range(0..LEN)] = true;
+
+                let y = evaluator.evaluate(&x);
+                if y < best.1 {
+                    best = (x, y);
+                }
+            }
+
+            best
+        }
+
+        fn identifier(&self) -> String {
+            "climb".into()
+        }
+    }
+
+    let algorithm = |policy: Policy, mode: Mode| {
+        let mut params = algorithm(Some(5)).params;
+        params.local_search =
+            Some(crate::operators::local_search::Config { policy, mode });
+
+        let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+            encoding::Builder::new()
+                .set_context(Ctx)
+                .set_phenotype(Ph(0))
+                .build();
+
+        crate::Builder::new()
+            .set_encoding(encoding)
+            .set_parameters(params)
+            .set_dynamics::<()>(None)
+            .set_custom_logger::<()>(None)
+            .set_local_search(Climb)
+            .build()
+    };
+
+    let true_cost = |ge: &Chromosome| ge.0.iter().filter(|x| !**x).count();
+
+    // Lamarckian: the improved chromosomes are written back
+    let mut alg = algorithm(Policy::All, Mode::Lamarckian);
+    alg.init();
+    (0..5).for_each(|_| alg.step());
+
+    // 5 generations with 50 offspring (25 pairs)
+    let rtd = &alg.state().rtd;
+    assert_eq!(rtd.local_search_evaluations, 5 * 50 * 4);
+    #[cfg(not(feature = "cache"))] // (cache hits are not evaluated)
+    assert_eq!(rtd.evaluations, 50 + 5 * 50 + 5 * 50 * 4);
+    assert!(alg.parameter_identifier().ends_with("LS:climb-all-lam"));
+
+    // The local search is timed in its own phase
+    let phase = crate::profiling::Phase::LocalSearch as usize;
+    assert!(rtd.profile.total[phase] > std::time::Duration::ZERO);
+
+    let report = alg.finish();
+    assert!(report.population.iter().all(|(ge, ov)| true_cost(ge) == ov.0));
+
+    // Baldwinian: only the objective values are improved (so the objective
+    // values of the parents can't be used for the incremental evaluation)
+    let mut alg = algorithm(Policy::All, Mode::Baldwinian);
+    alg.init();
+    while !alg.is_terminated() {
+        alg.step();
+    }
+    assert_eq!(alg.state().rtd.delta_evaluations, 0);
+
+    let report = alg.finish();
+    assert!(report.population.iter().any(|(ge, ov)| true_cost(ge) > ov.0));
+
+    // Policies restrict the local search to some offspring
+    for policy in [Policy::Elite, Policy::Probability(0.25)] {
+        let mut alg = algorithm(policy, Mode::Lamarckian);
+        alg.init();
+        alg.step();
+
+        let evaluations = alg.state().rtd.local_search_evaluations;
+        assert!(evaluations < 50 * 4);
+    }
+}
+
+#[test]
+fn population_seeding() {
+    use crate::initializer::Mix;
+
+    // Heuristic: chromosomes with a single zero
+    let heuristic = Mix::new().add("heuristic", 0.2, |n, _ctx, rng| {
+        (0..n)
+            .map(|_| {
+                let mut genes = vec![true; LEN];
+                genes[rng.gen_range(0..LEN)] = false;
+                Chromosome(genes)
+            })
+            .collect()
+    });
+
+    let optimum = Chromosome(vec![true; LEN]);
+    let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+        encoding::Builder::new().set_context(Ctx).set_phenotype(Ph(0)).build();
+
+    let mut alg = crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(algorithm(Some(3)).params)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .set_initializer(heuristic)
+        .add_seed_chromosomes(vec![optimum.clone(); 3])
+        .build();
+
+    assert!(alg
+        .parameter_identifier()
+        .ends_with("IN:seed-3-mix[heuristic-0.20]"));
+    assert!(!algorithm(Some(3)).parameter_identifier().contains("IN:"));
+
+    alg.init();
+
+    // The proportion refers to the 47 chromosomes created by the initializer
+    let sources = [("seed", 3), ("heuristic", 9), ("random", 38)]
+        .map(|(name, n)| (name.to_string(), n));
+    assert_eq!(alg.state().rtd.initial_sources, sources);
+
+    let population = alg.state().population();
+    assert!(population[..3].iter().all(|x| *x == (optimum.clone(), Cost(0))));
+    assert!(population[3..12].iter().all(|(_, ov)| *ov == Cost(1)));
+
+    assert_eq!(alg.finish().initial_sources, sources);
+}
+
+#[test]
+fn population_restarts() {
+    use crate::restart::{Restart, Strategy, Trigger};
+
+    let run = |trigger: Trigger, strategy: Strategy| {
+        let mut params = algorithm(Some(4)).params;
+        params.restart = Some(Restart { trigger, strategy, elite: 2 });
+
+        let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+            encoding::Builder::new()
+                .set_context(Ctx)
+                .set_phenotype(Ph(0))
+                .build();
+
+        crate::Builder::new()
+            .set_encoding(encoding)
+            .set_parameters(params)
+            .set_dynamics::<()>(None)
+            .set_custom_logger::<()>(None)
+            .build()
+            .run()
+    };
+
+    let restarts = |report: &Report<Cost, Ctx, Chromosome>| {
+        report
+            .log
+            .iter()
+            .filter(|l| l.restart.is_some())
+            .map(|l| l.generation)
+            .collect::<Vec<_>>()
+    };
+
+    // Fixed schedule (30 generations)
+    let report = run(Trigger::Schedule(10), Strategy::Full);
+    assert_eq!(restarts(&report), vec![10, 20, 30]);
+    assert_eq!(report.log[9].restart.as_deref(), Some("sched-10-full-eli-2"));
+
+    // IPOP: the population size doubles with every restart (up to the
+    // maximum)
+    let report = run(Trigger::Schedule(10), Strategy::DoublePopulation(1000));
+    assert_eq!(report.population.len(), 50 * 2 * 2 * 2);
+    let report = run(Trigger::Schedule(10), Strategy::DoublePopulation(300));
+    assert_eq!(report.population.len(), 300);
+
+    // The elite survives, so the best solution never gets worse
+    let report = run(Trigger::Schedule(5), Strategy::Cataclysm(0.5));
+    assert!(report.log.windows(2).all(|w| w[1].best <= w[0].best));
+
+    // Stagnation: restarts are at least 3 generations apart
+    let report = run(Trigger::Stagnation(3), Strategy::Full);
+    assert!(!restarts(&report).is_empty());
+    assert!(restarts(&report).windows(2).all(|w| w[1] - w[0] >= 3));
+
+    // Diversity below 2 (normalized entropy is at most 1)
+    let report = run(Trigger::Diversity(2.), Strategy::Full);
+    assert_eq!(restarts(&report).len(), 30);
+}
+
+#[test]
+fn genotype_metrics() {
+    use crate::runtime_data::RuntimeData;
+    use rand::SeedableRng;
+
+    let report = algorithm(Some(4)).run();
+    for log in &report.log {
+        assert!(log.unique_genotypes >= 1 && log.unique_genotypes <= 50);
+        assert!(log
+            .mean_distance
+            .is_some_and(|d| (0. ..=LEN as f64).contains(&d)));
+        assert!(log.mean_distance_to_best.is_some());
+    }
+
+    // Large populations are sampled: random chromosomes differ in about half
+    // of their genes
+    let mut rng = GaRng::seed_from_u64(0);
+    let population: Vec<(Chromosome, Cost)> =
+        Chromosome::generate(500, &Ctx, &mut rng)
+            .into_iter()
+            .map(|c| (c, Cost(0)))
+            .collect();
+
+    let mut rtd = RuntimeData::init(&population, &algorithm(None).params);
+    rtd.update_genotype_metrics(&population, &mut rng);
+    assert_eq!(rtd.unique_genotypes, 500);
+    assert!((rtd.mean_distance.unwrap() - LEN as f64 / 2.).abs() < 0.5);
+    assert!((rtd.mean_distance_to_best.unwrap() - LEN as f64 / 2.).abs() < 1.);
+}
+
+#[test]
+fn niching() {
+    use crate::niching::{self, Niching};
+
+    let run = |niching: Niching| {
+        let mut alg = algorithm(Some(4));
+        alg.params.niching = Some(niching);
+        alg.params.selection = Select::Random;
+        (alg.parameter_identifier(), alg.run())
+    };
+
+    // Crowding and restricted tournaments never lose the best solution
+    for niching in [
+        Niching::DeterministicCrowding,
+        Niching::RestrictedTournament(5),
+        Niching::Sharing { radius: 8., alpha: 1. },
+    ] {
+        let (identifier, report) = run(niching);
+        assert!(identifier.ends_with(&format!("NI:{}", niching.identifier())));
+        assert_eq!(report.population.len(), 50);
+        if niching != (Niching::Sharing { radius: 8., alpha: 1. }) {
+            assert!(report.log.windows(2).all(|w| w[1].best <= w[0].best));
+        }
+    }
+
+    // Sharing: the crowded niche of the best chromosome falls behind a
+    // distinct (worse) chromosome
+    let best = (Chromosome(vec![true; LEN]), Cost(0));
+    let other = (Chromosome(vec![false; LEN]), Cost(1));
+    let third = (Chromosome((0..LEN).map(|i| i % 2 == 0).collect()), Cost(2));
+    let population =
+        [vec![best; 5], vec![other.clone()], vec![third; 4]].concat();
+
+    let counts = niching::niche_counts(&population, 4., 1.);
+    assert_eq!(counts[0], 5.);
+    assert_eq!(counts[5], 1.);
+    assert_eq!(niching::share(&population, 4., 1.)[0], other);
+}
+
+#[test]
+#[should_panic(expected = "niching requires `Genotype::distance`")]
+fn niching_requires_distance() {
+    let encoding: Encoding<Cost, Ctx, Adaptive, AdaptivePh> =
+        encoding::Builder::new()
+            .set_context(Ctx)
+            .set_phenotype(AdaptivePh(0))
+            .build();
+    let parameters: Parameters<
+        Cost,
+        Ctx,
+        Adaptive,
+        Cx,
+        Mu,
+        usize,
+        Select,
+        Reject,
+        Replace,
+        Terminate<Cost>,
+    > = crate::parameters::Builder::for_encoding(&encoding)
+        .set_population_size(10)
+        .set_crossover_rate(None)
+        .set_mutation_rate(0.05)
+        .set_selection(Select::Random)
+        .set_crossover(Cx)
+        .set_mutation(Mu)
+        .set_rejection(Reject::None)
+        .set_replacement(Replace::EliteAbsolute(1))
+        .set_termination(Terminate::Generations(1))
+        .set_niching(crate::niching::Niching::DeterministicCrowding)
+        .build();
+
+    // The missing distance is detected before the first generation
+    crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(parameters)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .build()
+        .init();
+}
+
+#[test]
+fn adaptive_operator_selection() {
+    use crate::operators::adaptive::{Bandit, Portfolio, Strategy};
+    use rand::SeedableRng;
+
+    let mut alg = algorithm(Some(4));
+    alg.operator_portfolio = Some(
+        Portfolio::new(Strategy::Ucb(0.5))
+            .add_crossover(Cx)
+            .add_crossover(Cx)
+            .add_mutation(Mu),
+    );
+    assert!(alg.parameter_identifier().ends_with("OP:ucb-0.50[uni|uni][flip]"));
+
+    // 25 pairs of parents in 30 generations
+    let report = alg.run();
+    assert_eq!(report.operators.len(), 3);
+    for kind in ["crossover", "mutation"] {
+        let stats = report.operators.iter().filter(|s| s.kind == kind);
+        assert_eq!(stats.clone().map(|s| s.usage).sum::<usize>(), 25 * 30);
+        assert!((stats.map(|s| s.probability).sum::<f64>() - 1.).abs() < 1e-9);
+    }
+
+    // The probabilities follow the rewards (operator 0 is always successful)
+    let rewards = [(0, 1.), (1, 0.), (2, 0.)];
+    for strategy in [
+        Strategy::ProbabilityMatching { p_min: 0.1, alpha: 0.5 },
+        Strategy::AdaptivePursuit { p_min: 0.1, alpha: 0.5, beta: 0.5 },
+    ] {
+        let mut bandit = Bandit::new(3);
+        for _ in 0..20 {
+            bandit.update(&strategy, &rewards);
+        }
+        assert!((bandit.probability[0] - 0.8).abs() < 1e-3);
+        assert!((bandit.probability[1] - 0.1).abs() < 1e-3);
+    }
+
+    let mut bandit = Bandit::new(3);
+    bandit.update(&Strategy::Ucb(0.1), &rewards);
+    let arms =
+        bandit.assign(&Strategy::Ucb(0.1), 10, &mut GaRng::seed_from_u64(0));
+    assert!(arms.iter().filter(|arm| **arm == 0).count() >= 8);
+}
+
+#[test]
+fn operator_credit_before_local_search() {
+    use crate::operators::{
+        adaptive::{Portfolio, Strategy},
+        local_search::{Config, Evaluator, Mode, Policy},
+        LocalSearch,
+    };
+
+    // Sets all bits (the optimum)
+    struct Optimize;
+
+    impl LocalSearch<Cost, Ctx, Chromosome> for Optimize {
+        fn exec(
+            &self,
+            _chromosome: &Chromosome,
+            _objective_value: &Cost,
+            evaluator: &mut Evaluator<Chromosome, Cost>,
+            _rng: &mut GaRng,
+            _context: &Ctx,
+        ) -> (Chromosome, Cost) {
+            let x = Chromosome(vec![true; LEN]);
+            let y = evaluator.evaluate(&x);
+            (x, y)
+        }
+
+        fn identifier(&self) -> String {
+            "optimize".into()
+        }
+    }
+
+    // The offspring are copies of their parents, which are only improved by
+    // the local search, so the operators are never credited
+    let mut alg = algorithm(Some(8));
+    alg.params.crossover_rate = Some(0.);
+    alg.params.mutation_rate = 0.;
+    alg.params.local_search =
+        Some(Config { policy: Policy::All, mode: Mode::Lamarckian });
+    alg.local_search = Some(Box::new(Optimize));
+    alg.operator_portfolio = Some(
+        Portfolio::new(Strategy::Ucb(0.5)).add_crossover(Cx).add_mutation(Mu),
+    );
+
+    alg.init();
+    alg.step();
+    assert!(alg.state().population().iter().any(|(_, ov)| *ov == Cost(0)));
+
+    let report = alg.finish();
+    assert!(report.operators.iter().all(|s| s.usage == 25 && s.credit == 0.));
+}
+
+#[test]
+fn batch_and_delta_evaluation() {
+    let mut alg = algorithm(Some(6));
+    alg.init();
+    while !alg.is_terminated() {
+        alg.step();
+    }
+    assert!(alg.state().runtime_data().delta_evaluations > 0);
+
+    // Incrementally evaluated offspring have the same objective values as
+    // offspring evaluated from scratch
+    for (chromosome, ov) in alg.state().population() {
+        assert_eq!(Ph(0).derive(chromosome, &Ctx).evaluate(&Ctx), *ov);
+    }
+}
+
+#[test]
+fn selection_schemes() {
+    use crate::process::selection::Selection;
+
+    let population: Vec<(Chromosome, Cost)> =
+        (0..10).map(|x| (Chromosome(vec![]), Cost(x))).collect();
+
+    // Counts how often each individual is selected
+    let counts = |select: Select| {
+        let mut rng = crate::rng::Seeder::new(Some(0)).main();
+        let (selection, _) = <Select as Selection<_, _, _, f64>>::exec(
+            &select,
+            1000,
+            &population,
+            &mut rng,
+        );
+
+        let mut counts = [0; 10];
+        selection.iter().for_each(|(_, ov)| counts[ov.0] += 1);
+        counts
+    };
+
+    for select in [
+        Select::StochasticUniversalSampling,
+        Select::Boltzmann(2.),
+        Select::ExponentialRank(0.8),
+    ] {
+        let counts = counts(select);
+        assert_eq!(counts.iter().sum::<usize>(), 1000);
+        assert!(counts[0] > counts[5] && counts[5] >= counts[9]);
+    }
+
+    let truncation = counts(Select::Truncation(0.3));
+    assert!(truncation[..3].iter().all(|x| *x > 0));
+    assert!(truncation[3..].iter().all(|x| *x == 0));
+
+    // Low temperature: (almost) only the best individual is selected
+    assert!(counts(Select::Boltzmann(0.01))[0] == 1000);
+
+    // A higher selection pressure always selects the best individual more
+    // often
+    for select in [
+        Select::Tournament(3),
+        Select::LinearRank(1.5),
+        Select::Truncation(0.5),
+        Select::Boltzmann(0.5),
+        Select::ExponentialRank(0.5),
+    ] {
+        let with_pressure = |pressure: f64| {
+            let mut select = select.clone();
+            assert!(
+                <Select as Selection<Cost, Ctx, Chromosome, f64>>::set_pressure(
+                    &mut select,
+                    pressure
+                )
+            );
+            let actual =
+                <Select as Selection<Cost, Ctx, Chromosome, f64>>::pressure(
+                    &select,
+                );
+            assert_eq!(actual, Some(pressure));
+
+            counts(select)[0]
+        };
+
+        assert!(with_pressure(1.) < with_pressure(2.));
+    }
+}
+
+#[test]
+fn alias_sampling() {
+    use crate::process::selection::AliasTable;
+
+    let mut rng = crate::rng::Seeder::new(Some(0)).main();
+    let table = AliasTable::new(&[1., 0., 3., 4.]);
+
+    // Relative frequencies approximate the normalized weights
+    let mut counts = [0usize; 4];
+    let samples = table.sample_many(80_000, &mut rng);
+    samples.iter().for_each(|i| counts[*i] += 1);
+
+    assert_eq!(counts[1], 0);
+    for (count, p) in counts.iter().zip([0.125, 0., 0.375, 0.5]) {
+        assert!((*count as f64 / 80_000. - p).abs() < 0.01);
+    }
+
+    // Reproducible (independent of the thread scheduling)
+    let mut rng = crate::rng::Seeder::new(Some(0)).main();
+    assert_eq!(table.sample_many(80_000, &mut rng), samples);
+
+    // Degenerated weights fall back to a uniform distribution
+    let table = AliasTable::new(&[0., 0.]);
+    let samples = table.sample_many(1000, &mut rng);
+    assert!(samples.contains(&0) && samples.contains(&1));
+}
+
+#[test]
+fn generation_models() {
+    use crate::process::replacement::Replacement;
+
+    let exec = |replace: Replace, offspring: Vec<usize>| {
+        let mut population: Vec<((), usize)> =
+            (0..6).map(|x| ((), x * 10)).collect();
+        let offspring = offspring.into_iter().map(|x| ((), x)).collect();
+
+        let mut rng = crate::rng::Seeder::new(Some(0)).main();
+        replace.exec(&mut population, offspring, &mut rng);
+
+        population.sort();
+        population.into_iter().map(|(_, x)| x).collect::<Vec<_>>()
+    };
+
+    let sizes = |replace: Replace| {
+        <Replace as Replacement<((), usize)>>::selection_size(&replace, 6)
+    };
+
+    assert_eq!(sizes(Replace::SteadyStateWorst(3)), (3, 4));
+    assert_eq!(sizes(Replace::MuCommaLambda(9)), (9, 10));
+
+    assert_eq!(
+        exec(Replace::SteadyStateWorst(2), vec![5, 55]),
+        vec![0, 5, 10, 20, 30, 55]
+    );
+    assert_eq!(
+        exec(Replace::MuPlusLambda(3), vec![5, 15, 100]),
+        vec![0, 5, 10, 15, 20, 30]
+    );
+    assert_eq!(
+        exec(Replace::MuCommaLambda(7), vec![70, 60, 50, 40, 30, 20, 10]),
+        vec![10, 20, 30, 40, 50, 60]
+    );
+    assert_eq!(
+        exec(Replace::WorstIfBetter(3), vec![45, 35, 60]),
+        vec![0, 10, 20, 30, 35, 40]
+    );
+
+    // The best individual is never replaced
+    let population = exec(Replace::SteadyStateRandom(5), vec![99; 5]);
+    assert_eq!(population[0], 0);
+    assert_eq!(population.iter().filter(|x| **x == 99).count(), 5);
+}
+
+#[test]
+#[should_panic(expected = "can not preserve the population size 50")]
+fn mu_comma_lambda_requires_enough_offspring() {
+    let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+        encoding::Builder::new().set_context(Ctx).set_phenotype(Ph(0)).build();
+    let _: Parameters<
+        Cost,
+        Ctx,
+        Chromosome,
+        Cx,
+        Mu,
+        usize,
+        Select,
+        Reject,
+        Replace,
+        Terminate<Cost>,
+    > = crate::parameters::Builder::for_encoding(&encoding)
+        .set_population_size(50)
+        .set_crossover_rate(None)
+        .set_mutation_rate(0.05)
+        .set_selection(Select::Random)
+        .set_crossover(Cx)
+        .set_mutation(Mu)
+        .set_rejection(Reject::None)
+        .set_replacement(Replace::MuCommaLambda(10))
+        .set_termination(Terminate::Generations(1))
+        .build();
+}
+
+#[test]
+fn bounded_cache() {
+    use crate::cache::{Cache, CacheStats};
+
+    let a = Chromosome(vec![true; LEN]);
+    let b = Chromosome(vec![false; LEN]);
+    let c = Chromosome((0..LEN).map(|i| i % 2 == 0).collect());
+
+    let mut cache = Cache::new(2);
+    cache.insert(&a, &Cost(0));
+    cache.insert(&b, &Cost(LEN));
+    assert_eq!(cache.get(&a), Some(Cost(0)));
+
+    // Cache is full: `b` was not referenced and is evicted
+    cache.insert(&c, &Cost(LEN / 2));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&b), None);
+    assert_eq!(cache.get(&c), Some(Cost(LEN / 2)));
+
+    assert_eq!(
+        cache.take_stats(),
+        CacheStats { hits: 2, misses: 1, evictions: 1, size: 2 }
+    );
+}
+
+#[test]
+fn non_dominated_sorting() {
+    use crate::pareto::{crowding_distance, non_dominated_sort};
+
+    let objectives = vec![
+        vec![1., 4.],
+        vec![2., 2.],
+        vec![4., 1.],
+        vec![3., 3.],
+        vec![4., 4.],
+    ];
+
+    let fronts = non_dominated_sort(&objectives);
+    assert_eq!(fronts, vec![vec![0, 1, 2], vec![3], vec![4]]);
+
+    let distance = crowding_distance(&objectives, &fronts[0]);
+    assert!(distance[0].is_infinite() && distance[2].is_infinite());
+    assert_eq!(distance[1], 2.);
+}
+
+#[test]
+fn nsga2_finds_pareto_front() {
+    let encoding: Encoding<BiCost, Ctx, Chromosome, BiPh> =
+        encoding::Builder::new()
+            .set_context(Ctx)
+            .set_phenotype(BiPh(BiCost(0, 0)))
+            .build();
+
+    let parameters: Parameters<
+        BiCost,
+        Ctx,
+        Chromosome,
+        Cx,
+        Mu,
+        usize,
+        crate::pareto::Select,
+        Reject,
+        crate::pareto::Replace,
+        Terminate<BiCost>,
+    > = crate::parameters::Builder::for_encoding(&encoding)
+        .set_population_size(60)
+        .set_crossover_rate(Some(0.8))
+        .set_mutation_rate(0.03)
+        .set_selection(crate::pareto::Select::CrowdedTournament(2))
+        .set_crossover(Cx)
+        .set_mutation(Mu)
+        .set_rejection(Reject::None)
+        .set_replacement(crate::pareto::Replace::Nsga2)
+        .set_termination(Terminate::Generations(100))
+        .set_seed(1)
+        .build();
+
+    let report = crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(parameters)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .build()
+        .run();
+
+    let front = report.pareto_front();
+    assert!(front.iter().all(|(_, ov)| ov.0 + ov.1 == LEN / 2));
+
+    // Crowding distance keeps the front spread out
+    let distinct = front.iter().map(|(_, ov)| ov).collect::<HashSet<_>>();
+    assert!(distinct.len() > LEN / 4);
+}
+
+#[test]
+fn hard_soft_objective() {
+    use crate::encoding::HardSoft;
+
+    // Hard constraints: ones in the first half, soft: ones in the second half
+    #[derive(Clone, Debug)]
+    struct HsPh(HardSoft);
+
+    impl Phenotype<HardSoft, Ctx, Chromosome> for HsPh {
+        fn derive(&self, chromosome: &Chromosome, _ctx: &Ctx) -> Self {
+            let (hard, soft) = chromosome.0.split_at(LEN / 2);
+            let zeros = |genes: &[bool]| genes.iter().filter(|x| !**x).count();
+            Self(HardSoft::new(zeros(hard), zeros(soft)))
+        }
+
+        fn evaluate(&self, _ctx: &Ctx) -> HardSoft {
+            self.0
+        }
+    }
+
+    // Lexicographic order, which is preserved by the scalar conversions
+    let (a, b) = (HardSoft::new(0, 100), HardSoft::new(1, 0));
+    assert!(a < b);
+    assert!(usize::from(a) < usize::from(b) && f64::from(a) < f64::from(b));
+
+    let encoding: Encoding<HardSoft, Ctx, Chromosome, HsPh> =
+        encoding::Builder::new()
+            .set_context(Ctx)
+            .set_phenotype(HsPh(HardSoft::default()))
+            .build();
+
+    let parameters: Parameters<
+        HardSoft,
+        Ctx,
+        Chromosome,
+        Cx,
+        Mu,
+        f64,
+        Select,
+        Reject,
+        Replace,
+        Terminate<HardSoft>,
+    > = crate::parameters::Builder::for_encoding(&encoding)
+        .set_population_size(50)
+        .set_crossover_rate(Some(0.8))
+        .set_mutation_rate(0.05)
+        .set_selection(Select::Boltzmann(1_000_000.))
+        .set_crossover(Cx)
+        .set_mutation(Mu)
+        .set_rejection(Reject::None)
+        .set_replacement(Replace::EliteAbsolute(1))
+        .set_termination(Terminate::GenOrComponents(
+            500,
+            vec![0., f64::INFINITY],
+        ))
+        .set_seed(2)
+        .build();
+
+    let report = crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(parameters)
+        .set_dynamics::<()>(None)
+        .set_custom_logger::<()>(None)
+        .build()
+        .run();
+
+    // Stops at the first feasible solution
+    assert!(report.population[0].1.is_feasible());
+    assert!(report.generation < 500);
+    assert!(report.parameter_identifier.ends_with("TE:g-500-cmp-0-inf"));
+
+    // Statistics per component
+    let last = report.log.last().unwrap();
+    assert_eq!(
+        last.component_best,
+        vec![0., report.population[0].1.objective as f64]
+    );
+    assert_eq!(last.component_mean.len(), 2);
+    assert!(report.log[..report.log.len() - 1]
+        .iter()
+        .all(|l| l.component_best[0] > 0.));
+}
+
+#[test]
+#[should_panic(expected = "the number of targets must match")]
+fn component_targets_must_match() {
+    use crate::{encoding::HardSoft, process::termination::Termination};
+
+    let best = HardSoft { infeasibility: 0, objective: 0 };
+    Terminate::Components(vec![]).stop(0, &best);
+}
+
+#[test]
+fn report_export() {
+    let report = run(Some(8));
+
+    #[cfg(not(feature = "cache"))] // (cache hits are not evaluated)
+    assert_eq!(report.evaluations, report.log.len() * 50 + 50);
+    assert!(report.time_to_best_ms <= report.runtime_ms);
+    assert!(report.last_improvement <= report.generation);
+    assert_eq!(
+        report.log[report.last_improvement.max(1) - 1].best,
+        report.log.last().unwrap().best
+    );
+
+    // Header and one row per generation
+    let csv = report.log_csv();
+    assert_eq!(csv.lines().count(), report.log.len() + 1);
+    assert!(csv.lines().all(|l| l.split(';').count() == 14));
+
+    let summary = report.summary_csv();
+    let row: Vec<&str> = summary.lines().nth(1).unwrap().split(';').collect();
+    assert_eq!(row[0], "8");
+    assert_eq!(row[2], report.population[0].1 .0.to_string());
+
+    // Reports can be loaded again
+    #[cfg(feature = "serde")]
+    {
+        let dir = std::env::temp_dir()
+            .join(format!("ga-reports-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("run_1")).unwrap();
+        report.save(dir.join("run_0.json")).unwrap();
+        run(Some(9)).save(dir.join("run_1").join("report.json")).unwrap();
+
+        let reports: Vec<Report<Cost, Ctx, Chromosome>> =
+            crate::report::load_all(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].population, report.population);
+        assert_eq!(reports[0].summary(), report.summary());
+        assert_eq!(reports[1].seed, 9);
+    }
+}
+
+#[test]
+fn phase_profiling() {
+    use crate::profiling::Phase;
+    use std::time::Duration;
+
+    let report = run(Some(10));
+    let profile = &report.profile;
+
+    for phase in [
+        Phase::Selection,
+        Phase::Variation,
+        Phase::Evaluation,
+        Phase::Replacement,
+        Phase::Sort,
+        Phase::Stats,
+    ] {
+        assert!(profile.total[phase as usize] > Duration::ZERO);
+        assert!(
+            profile.generation[phase as usize] <= profile.total[phase as usize]
+        );
+    }
+
+    let shares: f64 = Phase::ALL.iter().map(|p| profile.share(*p)).sum();
+    assert!((shares - 1.).abs() < 1e-9);
+    assert!(
+        profile.total_duration().as_millis() <= report.runtime_ms as u128 + 1
+    );
+
+    #[cfg(not(feature = "cache"))] // (cache hits are not evaluated)
+    assert_eq!(profile.evaluations, report.evaluations);
+    assert!(profile.evaluation_throughput() > 0.);
+}
+
+#[cfg(not(feature = "log_dynamics"))] // (exec needs a rerun logger)
+#[test]
+fn generic_dynamics() {
+    use crate::{
+        dynamics::{Dynamic, Parameter, Standard},
+        runtime_data::RuntimeData,
+    };
+    use rand::SeedableRng;
+
+    let mut rng = GaRng::seed_from_u64(0);
+    let population: Vec<(Chromosome, Cost)> =
+        Chromosome::generate(50, &Ctx, &mut rng)
+            .into_iter()
+            .map(|c| (c, Cost(0)))
+            .collect();
+    let mut params = algorithm(None).params;
+    let mut rtd = RuntimeData::init(&population, &params);
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+    let linear_rank = |selection: &Select| match selection {
+        Select::LinearRank(sp) => *sp,
+        _ => panic!("linear rank selection expected"),
+    };
+
+    // Cosine schedule
+    let cos = Standard::MutRateCos {
+        base: 0.01,
+        amplitude: 0.005,
+        wavelength: 100,
+        bounds: None,
+    };
+    cos.setup(&mut rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(close(params.mutation_rate, 0.01));
+    rtd.generation = 50;
+    assert!(cos.exec(&rtd, &mut params, &mut Ctx, &mut rng));
+    assert!(close(params.mutation_rate, 0.005));
+    assert!(!cos.exec(&rtd, &mut params, &mut Ctx, &mut rng));
+
+    // Success rule
+    let rule =
+        Standard::SuccessRule { target: 0.2, factor: 2., bounds: (0.001, 0.5) };
+    rtd.success_rate_pt1 = 0.5;
+    rule.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(close(params.mutation_rate, 0.01));
+    rtd.success_rate_pt1 = 0.;
+    rule.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+    rule.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(close(params.mutation_rate, 0.0025));
+
+    // Target diversity
+    let controller =
+        Standard::TargetDiversity { target: 0.5, gain: 1., bounds: (0., 1.) };
+    rtd.diversity = 0.5;
+    assert!(!controller.exec(&rtd, &mut params, &mut Ctx, &mut rng));
+    assert!(close(params.mutation_rate, 0.0025));
+    rtd.diversity = 0.;
+    controller.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(close(params.mutation_rate, 0.0025 * 0.5_f32.exp()));
+
+    // Selection pressure ramp (tournament size)
+    let ramp = Standard::PressureRamp { from: 2., to: 6., generations: 100 };
+    ramp.setup(&mut rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(matches!(params.selection, Select::Tournament(2)));
+    assert!(ramp.exec(&rtd, &mut params, &mut Ctx, &mut rng));
+    assert!(matches!(params.selection, Select::Tournament(4)));
+    rtd.generation = 200;
+    ramp.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(matches!(params.selection, Select::Tournament(6)));
+
+    // Selection pressure on stagnation (linear rank selection)
+    let stagnation = Standard::PressureOnStagnation {
+        initial: 1.,
+        interval: 10,
+        step: 0.25,
+        max: 1.5,
+        reset: 30,
+    };
+    params.selection = Select::LinearRank(1.2);
+    stagnation.setup(&mut rtd, &mut params, &mut Ctx, &mut rng);
+    rtd.last_success = 0;
+    for (generation, sp) in [(10, 1.25), (15, 1.25), (20, 1.5), (40, 1.)] {
+        rtd.generation = generation;
+        stagnation.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+        assert!(close(linear_rank(&params.selection), sp));
+    }
+
+    // Piecewise-linear schedule
+    let schedule = Standard::PiecewiseLinear {
+        parameter: Parameter::MutationRate,
+        points: vec![(0, 0.1), (100, 0.), (200, 0.05)],
+    };
+    schedule.setup(&mut rtd, &mut params, &mut Ctx, &mut rng);
+    assert!(close(params.mutation_rate, 0.1));
+    for (generation, rate) in [(50, 0.05), (150, 0.025), (300, 0.05)] {
+        rtd.generation = generation;
+        schedule.exec(&rtd, &mut params, &mut Ctx, &mut rng);
+        assert!(close(params.mutation_rate, rate));
+    }
+    assert_eq!(schedule.identifier(), "pwl-mut-rate-0:0.1-100:0-200:0.05");
+}
+
+#[test]
+fn state_machine_dynamic() {
+    use crate::dynamics::{Condition, Overrides, StateMachine};
+
+    let machine: StateMachine<Select, Cx, Mu, Replace> = StateMachine::new()
+        .add_state(
+            "explore",
+            Overrides {
+                selection: Some(Select::Tournament(2)),
+                mutation_rate: Some(0.2),
+                ..Overrides::default()
+            },
+        )
+        .add_state(
+            "exploit",
+            Overrides {
+                selection_pressure: Some(4.),
+                mutation_rate: Some(0.01),
+                ..Overrides::default()
+            },
+        )
+        .add_transition("explore", "exploit", Condition::GenerationsInState(5))
+        .add_transition(
+            "exploit",
+            "explore",
+            Condition::Any(vec![
+                Condition::GenerationsInState(10),
+                Condition::DiversityBelow(-1.),
+            ]),
+        );
+
+    // Build from a config
+    #[cfg(feature = "serde")]
+    let machine: StateMachine<_, _, _, _> =
+        serde_json::from_str(&serde_json::to_string(&machine).unwrap())
+            .unwrap();
+
+    let params = algorithm(Some(3)).params;
+    let dynamics = crate::dynamics::Builder::for_parameters(&params)
+        .set(vec![machine])
+        .build();
+    let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+        encoding::Builder::new().set_context(Ctx).set_phenotype(Ph(0)).build();
+
+    let mut alg = crate::Builder::new()
+        .set_encoding(encoding)
+        .set_parameters(params)
+        .set_dynamics(Some(dynamics))
+        .set_custom_logger::<()>(None)
+        .build();
+
+    alg.init();
+    assert_eq!(alg.parameters().mutation_rate, 0.2);
+    for _ in 0..5 {
+        alg.step();
+    }
+    assert_eq!(alg.parameters().mutation_rate, 0.01);
+    assert!(matches!(alg.parameters().selection, Select::Tournament(4)));
+    while !alg.is_terminated() {
+        alg.step();
+    }
+    let report = alg.finish();
+    assert!(report
+        .dynamics_identifier
+        .as_ref()
+        .unwrap()
+        .contains("explore(mr=0.2,se=trn-2),exploit(mr=0.01,sp=4)"));
+
+    // Every transition is recorded in the generation in which it was taken
+    let transitions: Vec<(usize, &str)> = report
+        .log
+        .iter()
+        .flat_map(|l| l.events.iter().map(|e| (l.generation, e.as_str())))
+        .collect();
+    assert_eq!(
+        transitions[..3],
+        [
+            (5, "explore -> exploit"),
+            (15, "exploit -> explore"),
+            (20, "explore -> exploit")
+        ]
+    );
+    assert!(report.log_csv().contains(";explore -> exploit"));
+}
+
+#[test]
+fn hyperparameter_tuning() {
+    use crate::tuning::{Configuration, Race, Space, Tuner};
+
+    // Synthetic cost: the operator "a" is best, the optimal x is 3 + shift
+    let space = Space::new()
+        .add_categorical("operator", ["a", "b", "c"])
+        .add_real("x", 0., 10.);
+    let shifts = [0., 1.];
+    let tuner = Tuner::new(space, &shifts, |c: &Configuration, shift, seed| {
+        let noise = (seed % 100) as f64 / 1000.;
+        [0., 5., 10.][c.index("operator")]
+            + (c.real("x") - 3. - shift).abs()
+            + noise
+    })
+    .set_repetitions(4)
+    .set_seed(1);
+
+    let grid = tuner.grid(5);
+    assert_eq!(grid.entries.len(), 15);
+    assert!(grid.entries.iter().all(|e| e.runs.len() == 8));
+    let best = grid.best().unwrap();
+    assert_eq!(best.configuration.label("operator"), "a");
+    assert_eq!(best.configuration.real("x"), 2.5);
+    assert_eq!(best.instance_costs(1).len(), 4);
+    assert_eq!(grid.to_csv().lines().count(), 16);
+
+    let random = tuner.random(10);
+    assert_eq!(random.entries.len(), 10);
+    assert_eq!(random, tuner.random(10));
+
+    let race = tuner.race(&Race::default());
+    let best = race.best().unwrap();
+    assert_eq!(best.configuration.label("operator"), "a");
+    assert!((best.configuration.real("x") - 3.5).abs() < 1.);
+    assert_eq!(best.runs.len(), 8);
+    assert!(race.entries.iter().any(|e| e.runs.len() < 8));
+    assert!(race
+        .entries
+        .iter()
+        .filter_map(|e| e.eliminated.map(|blocks| (blocks, e.runs.len())))
+        .all(|(blocks, runs)| blocks <= runs));
+}
+
+#[test]
+fn friedman_and_wilcoxon_known_answers() {
+    use crate::statistics::{friedman, ranks, tie_sum, wilcoxon_signed_rank};
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+    // Ties share the mean of their ranks
+    assert_eq!(ranks(&[3., 1., 2., 2.]), [4., 1., 2.5, 2.5]);
+    assert_eq!(tie_sum(&[1., 2., 2., 3., 3., 3.]), 6. + 24.);
+
+    // Friedman: rank sums 5, 8 and 11 of 4 blocks (2 degrees of freedom, so
+    // p = exp(-statistic / 2))
+    let blocks = [[1., 2., 3.], [1., 3., 2.], [1., 2., 3.], [2., 1., 3.]];
+    let result = friedman(&blocks.map(Vec::from));
+    assert!(close(result.statistic, 4.5));
+    assert!(close(result.p_value, 0.105399));
+
+    // One tied pair: 6.125 / (1 - 6 / (4 * (27 - 3)))
+    let blocks = [[1., 1., 3.], [1., 2., 3.], [1., 2., 3.], [2., 1., 3.]];
+    let result = friedman(&blocks.map(Vec::from));
+    assert!(close(result.statistic, 6.533333));
+    assert!(close(result.p_value, 0.038133));
+
+    // Wilcoxon (Darwin's Zea mays differences): W+ = 96, mean 60, variance
+    // 310
+    let x = [
+        6., 8., 14., 16., 23., 24., 28., 29., 41., -48., 49., 56., 60., -67.,
+        75.,
+    ];
+    let result = wilcoxon_signed_rank(&x, &[0.; 15]);
+    assert!(close(result.statistic, 2.016265));
+    assert!(close(result.p_value, 0.043772));
+
+    // Zero differences are dropped, ties reduce the variance by 30 / 48:
+    // W+ = 20.5, mean 14, variance 34.375
+    let x = [1., -2., 2., 3., 0., -3., 3., 4.];
+    let result = wilcoxon_signed_rank(&[0.; 8], &x);
+    assert!(close(result.statistic, -1.023363));
+    assert!(close(result.p_value, 0.306136));
+}
+
+#[test]
+fn mann_whitney_known_answers() {
+    use crate::statistics::mann_whitney_u;
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+    // Complete separation: U = 0, mean 10, variance 20 / 12 * 10
+    let result = mann_whitney_u(&[1., 2., 3., 4.], &[5., 6., 7., 8., 9.]);
+    assert!(close(result.statistic, -2.327015));
+    assert!(close(result.p_value, 0.019964));
+
+    // The statistic is antisymmetric
+    let result = mann_whitney_u(&[5., 6., 7., 8., 9.], &[1., 2., 3., 4.]);
+    assert!(close(result.statistic, 2.327015));
+
+    // Two groups of three ties: U = 3, variance 20 / 12 * (10 - 48 / 72)
+    let result = mann_whitney_u(&[1., 2., 2., 3.], &[2., 3., 3., 4., 5.]);
+    assert!(close(result.statistic, -1.648051));
+    assert!(close(result.p_value, 0.099342));
+}
+
+#[test]
+fn comparison_analysis() {
+    use crate::{
+        analysis::compare,
+        statistics::{holm, quantile},
+    };
+
+    let adjusted = holm(&[0.01, 0.04, 0.03]);
+    for (p, expected) in adjusted.into_iter().zip([0.03, 0.06, 0.06]) {
+        assert!((p - expected).abs() < 1e-12);
+    }
+    assert_eq!(quantile(&[4., 1., 3., 2.], 0.5), Some(2.5));
+
+    // Group "b" is clearly worse than "a", group "c" equals "a"
+    let variant = |id: &'static str, offset: usize| {
+        (0..10).map(move |seed| {
+            let mut report = run(Some(seed));
+            report.parameter_identifier = id.into();
+            report.population[0].1 .0 += offset;
+            report
+        })
+    };
+    let a: Vec<_> = (0..10).map(|seed| run(Some(seed))).collect();
+    let target = a.iter().map(|r| r.population[0].1 .0).max().unwrap();
+    let reports: Vec<_> =
+        a.into_iter().chain(variant("b", LEN)).chain(variant("c", 0)).collect();
+
+    let comparison = compare(&reports, target, 0.05);
+
+    assert_eq!(comparison.groups.len(), 3);
+    assert!(comparison.groups.iter().all(|g| g.runs() == 10));
+    assert_eq!(comparison.groups[0].success_rate, 1.);
+    assert_eq!(comparison.groups[1].success_rate, 0.);
+    assert!(comparison.groups[0].time_to_target_ms.is_some());
+    assert!(comparison.groups[1].time_to_target_ms.is_none());
+
+    let pairs = &comparison.pairs;
+    assert_eq!(pairs.len(), 3);
+    assert!(pairs.iter().all(|p| p.p_holm >= p.test.p_value));
+    assert_eq!((pairs[0].a, pairs[0].b), (0, 1));
+    assert_eq!(pairs[0].a12, 0.);
+    assert_eq!(pairs[0].winner(0.05), Some(0));
+    assert_eq!((pairs[1].a, pairs[1].b), (0, 2));
+    assert_eq!(pairs[1].a12, 0.5);
+    assert_eq!(pairs[1].winner(0.05), None);
+
+    assert_eq!(comparison.groups_csv().lines().count(), 4);
+    assert_eq!(comparison.pairs_csv().lines().count(), 4);
+    assert_eq!(comparison.to_string().lines().count(), 1 + 3 + 1 + 1 + 3);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn resume_from_checkpoint() {
+    let path = std::env::temp_dir()
+        .join(format!("ga-checkpoint-{}.json", std::process::id()));
+
+    // Uninterrupted execution (writes a checkpoint at generation 20)
+    let a = algorithm(Some(3)).run_with_checkpoints(&path, 20).unwrap();
+
+    // Resume from generation 20
+    let b = algorithm(Some(3)).resume(&path, 0).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(b.generation, 30);
+    assert_eq!(a.population, b.population);
+    assert_eq!(log_of(&a), log_of(&b));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn resume_state_machine() {
+    use crate::{
+        checkpoint::Error,
+        dynamics::{Condition, Overrides, StateMachine},
+    };
+
+    let path = std::env::temp_dir()
+        .join(format!("ga-checkpoint-sm-{}.json", std::process::id()));
+
+    // The checkpoint (generation 10) is written in the middle of a state
+    let build = || {
+        let machine: StateMachine<Select, Cx, Mu, Replace> =
+            StateMachine::new()
+                .add_state(
+                    "explore",
+                    Overrides {
+                        mutation_rate: Some(0.2),
+                        ..Overrides::default()
+                    },
+                )
+                .add_state(
+                    "exploit",
+                    Overrides {
+                        mutation_rate: Some(0.01),
+                        ..Overrides::default()
+                    },
+                )
+                .add_transition(
+                    "explore",
+                    "exploit",
+                    Condition::GenerationsInState(7),
+                )
+                .add_transition(
+                    "exploit",
+                    "explore",
+                    Condition::GenerationsInState(6),
+                );
+
+        let params = algorithm(Some(3)).params;
+        let dynamics = crate::dynamics::Builder::for_parameters(&params)
+            .set(vec![machine])
+            .build();
+        let encoding: Encoding<Cost, Ctx, Chromosome, Ph> =
+            encoding::Builder::new()
+                .set_context(Ctx)
+                .set_phenotype(Ph(0))
+                .build();
+
+        crate::Builder::new()
+            .set_encoding(encoding)
+            .set_parameters(params)
+            .set_dynamics(Some(dynamics))
+            .set_custom_logger::<()>(None)
+            .build()
+    };
+
+    let a = build().run_with_checkpoints(&path, 10).unwrap();
+    let b = build().resume(&path, 0).unwrap();
+
+    // Checkpoints which don't match the dynamics are rejected
+    let mismatch = |result: Result<_, Error>| {
+        assert!(matches!(result, Err(Error::Mismatch(_))));
+    };
+    mismatch(algorithm(Some(3)).resume(&path, 0));
+
+    let mut checkpoint: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    checkpoint["dynamics"] = serde_json::json!([null]);
+    std::fs::write(&path, checkpoint.to_string()).unwrap();
+    mismatch(build().resume(&path, 0));
+    std::fs::remove_file(&path).unwrap();
+
+    let events = |report: &Report<Cost, Ctx, Chromosome>| {
+        report
+            .log
+            .iter()
+            .flat_map(|l| l.events.iter().map(|e| (l.generation, e.clone())))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(events(&a)[1], (13, "exploit -> explore".to_string()));
+    assert_eq!(events(&a), events(&b));
+    assert_eq!(a.population, b.population);
+    assert_eq!(log_of(&a), log_of(&b));
+}
+
+#[test]
+fn self_adaptation() {
+    use crate::self_adaptation::SelfAdaptation;
+
+    let run = |seed: u64| {
+        let encoding: Encoding<Cost, Ctx, Adaptive, AdaptivePh> =
+            encoding::Builder::new()
+                .set_context(Ctx)
+                .set_phenotype(AdaptivePh(0))
+                .build();
+        let parameters: Parameters<
+            Cost,
+            Ctx,
+            Adaptive,
+            Cx,
+            Mu,
+            usize,
+            Select,
+            Reject,
+            Replace,
+            Terminate<Cost>,
+        > = crate::parameters::Builder::for_encoding(&encoding)
+            .set_population_size(50)
+            .set_crossover_rate(Some(0.8))
+            .set_mutation_rate(0.05)
+            .set_selection(Select::Tournament(2))
+            .set_crossover(Cx)
+            .set_mutation(Mu)
+            .set_rejection(Reject::None)
+            .set_replacement(Replace::EliteAbsolute(1))
+            .set_termination(Terminate::Generations(30))
+            .set_seed(seed)
+            .set_self_adaptation(SelfAdaptation::for_length(LEN))
+            .build();
+
+        let mut alg = crate::Builder::new()
+            .set_encoding(encoding)
+            .set_parameters(parameters)
+            .set_dynamics::<()>(None)
+            .set_custom_logger::<()>(None)
+            .build();
+
+        alg.init();
+        let initial = alg.state().rtd.strategy.unwrap();
+        assert_eq!(
+            (initial.rate_mean as f32, initial.rate_std_dev),
+            (0.05, 0.)
+        );
+        assert_eq!(initial.sigma_mean, 1.);
+
+        while !alg.is_terminated() {
+            alg.step();
+        }
+        alg.finish()
+    };
+
+    let report = run(5);
+    assert!(report.parameter_identifier.contains("_SA:sa-"));
+
+    // The statistics of the strategy parameters are exported
+    let csv = report.log_csv();
+    let header = csv.lines().next().unwrap();
+    assert!(header.ends_with(";strategy_sigma_min;strategy_sigma_max"));
+    assert!(csv.lines().all(|l| l.split(';').count() == 22));
+
+    // The strategy parameters evolve within their bounds
+    let bounds = SelfAdaptation::for_length(LEN).rate_bounds;
+    for log in &report.log {
+        let strategy = log.strategy.unwrap();
+        assert!(strategy.rate_std_dev > 0. && strategy.sigma_std_dev > 0.);
+        assert!(bounds.0 as f64 <= strategy.rate_min);
+        assert!(strategy.rate_max <= bounds.1 as f64);
+    }
+    for (chromosome, _) in &report.population {
+        assert!((bounds.0..=bounds.1).contains(&chromosome.1.rate));
+    }
+
+    // Reproducible
+    assert_eq!(report.population, run(5).population);
+}
+
+////////////////////////////////////////////////////////////////////////////////
@@ -13,7 +13,7 @@ use crate::{
 use rerun::{RecordingStream, RecordingStreamBuilder, Scalar};
 
 #[cfg(feature = "log_runtimes")]
-use rerun::BarChart;
+use crate::profiling::{Phase, Profile};
 
 // Constants ///////////////////////////////////////////////////////////////////
 const GENERATION_TIME_SEQ: &str = "generation";
@@ -117,17 +117,13 @@ impl RerunLogger {
                 &self.rec,
                 rtd.generation,
                 rtd.cache_hits,
-                rtd.selection_corrected - rtd.cache_hits,
+                rtd.cache_misses,
             );
         };
 
         #[cfg(feature = "log_runtimes")]
         {
-            execution_times(
-                &self.rec,
-                rtd.generation,
-                rtd.execution_times.clone(),
-            );
+            execution_times(&self.rec, rtd.generation, &rtd.profile);
         };
     }
 
@@ -152,6 +148,13 @@ impl RerunLogger {
             self.rec.log("mutation/rate", &Scalar::new(mutation_rate as f64));
     }
 
+    #[cfg(feature = "log_dynamics")]
+    pub fn log_selection_pressure(&self, generation: usize, pressure: f64) {
+        self.rec.set_time_sequence(GENERATION_TIME_SEQ, generation as u32);
+
+        let _ = self.rec.log("selection/pressure", &Scalar::new(pressure));
+    }
+
     #[cfg(feature = "log_dynamics")]
     pub fn log_text(&self, generation: usize, text: &str) {
         self.rec.set_time_sequence(GENERATION_TIME_SEQ, generation as u32);
@@ -278,21 +281,23 @@ fn cache_hits(
     let _ = rec.log("internal/cache_misses", &Scalar::new(cache_misses as f64));
 }
 
-/// Log execution times
+/// Log execution times of the phases of the last generation (in
+/// microseconds)
 #[cfg(feature = "log_runtimes")]
 fn execution_times(
     rec: &RecordingStream,
     generation: usize,
-    execution_times: Vec<u128>,
+    profile: &Profile,
 ) {
     rec.set_time_sequence(GENERATION_TIME_SEQ, generation as u32);
 
-    let _ = rec.log(
-        "internal/execution_times",
-        &BarChart::new(
-            execution_times.into_iter().map(|x| x as u64).collect::<Vec<u64>>(),
-        ),
-    );
+    for phase in Phase::ALL {
+        let micros = profile.generation[phase as usize].as_micros();
+        let _ = rec.log(
+            format!("internal/execution_times/{}", phase.name()),
+            &Scalar::new(micros as f64),
+        );
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
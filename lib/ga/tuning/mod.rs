@@ -0,0 +1,474 @@
+//! Automated tuning of algorithm parameters.
+//!
+//! A [`Space`] declares the tuned parameters (categorical labels and numeric
+//! ranges). The [`Tuner`] evaluates configurations of this space on a set of
+//! instances with a user-defined function, which maps a configuration, an
+//! instance and a seed to a cost (e.g. the best objective value after a fixed
+//! number of generations; lower is better). Three procedures are available:
+//! - [`Tuner::grid`] evaluates all combinations of discretized values.
+//! - [`Tuner::random`] evaluates uniformly sampled configurations.
+//! - [`Tuner::race`] executes an iterated racing procedure (similar to irace):
+//!   every race evaluates a set of configurations block by block (one block is
+//!   one run per instance and repetition) and drops configurations which are
+//!   significantly worse than the best one (Friedman test followed by Wilcoxon
+//!   signed-rank tests, see [`crate::statistics`]). The survivors (elites) of
+//!   a race are kept for the next race, whose new configurations are sampled
+//!   around the elites.
+//!
+//! All configurations are evaluated with the same seeds per block (common
+//! random numbers), so that the results are reproducible and the
+//! configurations can be compared pairwise. The runs of a block are executed
+//! in parallel.
+//!
+//! Example:
+//! ```rust ,ignore
+//! let space = Space::new()
+//!     .add_integer("population_size", 100, 2000)
+//!     .add_log_real("mutation_rate", 0.001, 0.1);
+//!
+//! let ranking = Tuner::new(space, &instances, |configuration, instance, seed| {
+//!     run(configuration, instance, seed) // returns the cost
+//! })
+//! .set_repetitions(2)
+//! .set_seed(42)
+//! .race(&Race::default());
+//!
+//! println!("{}", ranking.best().unwrap().configuration.identifier());
+//! ```
+
+// Modules /////////////////////////////////////////////////////////////////////
+mod space;
+pub use space::*;
+
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::{
+    rng::Seeder,
+    statistics::{friedman, ranks, wilcoxon_signed_rank},
+};
+use rand::Rng;
+use rayon::prelude::*;
+
+// Race ////////////////////////////////////////////////////////////////////////
+
+/// Parameters of the iterated racing procedure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Race {
+    /// Number of races.
+    pub iterations: usize,
+
+    /// Number of configurations per race (incl. the elites of the previous
+    /// race).
+    pub configurations: usize,
+
+    /// Maximum number of elites, which are passed on to the next race.
+    pub elites: usize,
+
+    /// Number of blocks after which the statistical tests start (the
+    /// Wilcoxon signed-rank test needs at least 6 blocks to reach a p-value
+    /// below 0.05).
+    pub first_test: usize,
+
+    /// Significance level of the statistical tests.
+    pub alpha: f64,
+}
+
+impl Default for Race {
+    fn default() -> Self {
+        Self {
+            iterations: 3,
+            configurations: 12,
+            elites: 3,
+            first_test: 6,
+            alpha: 0.05,
+        }
+    }
+}
+
+// Ranking /////////////////////////////////////////////////////////////////////
+
+/// Result of a single run.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Run {
+    pub instance: usize,
+    pub repetition: usize,
+    pub seed: u64,
+    pub cost: f64,
+}
+
+/// Evaluated configuration.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+    pub configuration: Configuration,
+
+    /// Runs in block order (a configuration which was eliminated has fewer
+    /// runs than the others).
+    pub runs: Vec<Run>,
+
+    pub mean_cost: f64,
+
+    /// Mean rank of the configuration within the blocks it was evaluated on
+    /// (compared to the other configurations of the same race).
+    pub mean_rank: f64,
+
+    /// Number of blocks after which the configuration was eliminated (an
+    /// elite of a previous race keeps all its runs).
+    pub eliminated: Option<usize>,
+}
+
+impl Entry {
+    fn new(configuration: Configuration) -> Self {
+        Self {
+            configuration,
+            runs: Vec::new(),
+            mean_cost: 0.,
+            mean_rank: 0.,
+            eliminated: None,
+        }
+    }
+
+    /// Costs of all runs on the given instance.
+    pub fn instance_costs(&self, instance: usize) -> Vec<f64> {
+        self.runs
+            .iter()
+            .filter(|run| run.instance == instance)
+            .map(|run| run.cost)
+            .collect()
+    }
+
+    /// Mean cost of the runs on the given instance.
+    pub fn instance_mean(&self, instance: usize) -> Option<f64> {
+        let costs = self.instance_costs(instance);
+        (!costs.is_empty())
+            .then(|| costs.iter().sum::<f64>() / costs.len() as f64)
+    }
+}
+
+/// Ranked configurations: configurations which were never eliminated come
+/// first, followed by configurations with more runs; ties are broken by the
+/// mean rank and the mean cost. The configurations of earlier races (which
+/// did not become elites) follow the configurations of the last race.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ranking {
+    pub entries: Vec<Entry>,
+
+    /// Number of instances.
+    pub instances: usize,
+}
+
+impl Ranking {
+    pub fn best(&self) -> Option<&Entry> {
+        self.entries.first()
+    }
+
+    /// Returns the ranking as CSV (`;`-separated): rank, configuration, mean
+    /// cost, mean rank, runs, eliminated and the mean cost per instance.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "rank;configuration;mean_cost;mean_rank;runs;eliminated",
+        );
+        for instance in 0..self.instances {
+            csv += &format!(";instance_{instance}");
+        }
+        csv += "\n";
+
+        for (rank, entry) in self.entries.iter().enumerate() {
+            csv += &format!(
+                "{};{};{};{};{};{}",
+                rank + 1,
+                entry.configuration.identifier(),
+                entry.mean_cost,
+                entry.mean_rank,
+                entry.runs.len(),
+                entry.eliminated.map(|b| b.to_string()).unwrap_or_default(),
+            );
+            for instance in 0..self.instances {
+                let mean = entry.instance_mean(instance);
+                csv += &format!(
+                    ";{}",
+                    mean.map(|m| m.to_string()).unwrap_or_default()
+                );
+            }
+            csv += "\n";
+        }
+
+        csv
+    }
+}
+
+// Tuner ///////////////////////////////////////////////////////////////////////
+
+/// Evaluates configurations of a parameter space on a set of instances (see
+/// the module documentation).
+pub struct Tuner<'a, I, F> {
+    space: Space,
+    instances: &'a [I],
+    evaluate: F,
+    repetitions: usize,
+    seed: u64,
+}
+
+impl<'a, I, F> Tuner<'a, I, F>
+where
+    I: Sync,
+    F: Fn(&Configuration, &I, u64) -> f64 + Sync,
+{
+    /// Creates a tuner with one repetition per instance and a random seed.
+    pub fn new(space: Space, instances: &'a [I], evaluate: F) -> Self {
+        assert!(!instances.is_empty(), "the tuner needs at least one instance");
+
+        Self {
+            space,
+            instances,
+            evaluate,
+            repetitions: 1,
+            seed: Seeder::new(None).seed(),
+        }
+    }
+
+    /// Number of runs per configuration and instance (with different seeds).
+    pub fn set_repetitions(mut self, repetitions: usize) -> Self {
+        self.repetitions = repetitions.max(1);
+        self
+    }
+
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn space(&self) -> &Space {
+        &self.space
+    }
+
+    /// Evaluates all configurations of [`Space::grid`] on all blocks.
+    pub fn grid(&self, levels: usize) -> Ranking {
+        let entries =
+            self.space.grid(levels).into_iter().map(Entry::new).collect();
+        self.ranking(self.execute(entries, None))
+    }
+
+    /// Evaluates `n` uniformly sampled configurations on all blocks.
+    pub fn random(&self, n: usize) -> Ranking {
+        let mut rng = Seeder::new(Some(self.seed)).main();
+        let entries =
+            (0..n).map(|_| Entry::new(self.space.sample(&mut rng))).collect();
+        self.ranking(self.execute(entries, None))
+    }
+
+    /// Executes the iterated racing procedure.
+    pub fn race(&self, race: &Race) -> Ranking {
+        let mut rng = Seeder::new(Some(self.seed)).main();
+        let mut elites: Vec<Entry> = Vec::new();
+        let mut archive: Vec<Vec<Entry>> = Vec::new();
+        let mut entries: Vec<Entry> = Vec::new();
+
+        for iteration in 0..race.iterations {
+            // The neighbourhood of the elites shrinks with every race
+            let spread = 0.5_f64.powi(iteration as i32);
+
+            entries = std::mem::take(&mut elites);
+            let weights: Vec<usize> = (1..=entries.len()).rev().collect();
+            let total: usize = weights.iter().sum();
+
+            while entries.len() < race.configurations.max(1) {
+                let mut configuration = None;
+                for _ in 0..10 {
+                    let candidate = if total == 0 {
+                        self.space.sample(&mut rng)
+                    } else {
+                        // Better elites are chosen more often as parent
+                        let mut r = rng.gen_range(0..total);
+                        let parent = weights
+                            .iter()
+                            .position(|w| {
+                                let hit = r < *w;
+                                r = r.saturating_sub(*w);
+                                hit
+                            })
+                            .expect("valid parent");
+                        self.space.sample_near(
+                            &entries[parent].configuration,
+                            spread,
+                            &mut rng,
+                        )
+                    };
+
+                    let duplicate = entries
+                        .iter()
+                        .chain(archive.iter().flatten())
+                        .any(|e| e.configuration == candidate);
+                    configuration = Some(candidate);
+                    if !duplicate {
+                        break;
+                    }
+                }
+
+                entries.push(Entry::new(configuration.expect("sampled")));
+            }
+
+            entries = self.execute(entries, Some(race));
+            sort(&mut entries);
+
+            if iteration + 1 < race.iterations {
+                let survivors = entries
+                    .iter()
+                    .take_while(|e| e.eliminated.is_none())
+                    .count()
+                    .min(race.elites.max(1));
+                archive.push(entries.split_off(survivors));
+                elites = std::mem::take(&mut entries);
+            }
+        }
+
+        entries.extend(archive.into_iter().rev().flatten());
+
+        Ranking { entries, instances: self.instances.len() }
+    }
+
+    /// Number of blocks (runs per configuration).
+    fn blocks(&self) -> usize {
+        self.instances.len() * self.repetitions
+    }
+
+    /// Seed of the runs of a block (the same for all configurations).
+    fn block_seed(&self, block: usize) -> u64 {
+        Seeder::new(Some(self.seed)).stream(block, 0).gen()
+    }
+
+    /// Evaluates the entries block by block (entries which already contain
+    /// the run of a block are not evaluated again). If a race is given, the
+    /// entries are tested after every block (starting at the first test) and
+    /// significantly worse entries are eliminated.
+    fn execute(
+        &self,
+        mut entries: Vec<Entry>,
+        race: Option<&Race>,
+    ) -> Vec<Entry> {
+        for block in 0..self.blocks() {
+            let instance = block % self.instances.len();
+            let repetition = block / self.instances.len();
+            let seed = self.block_seed(block);
+
+            let pending: Vec<usize> = (0..entries.len())
+                .filter(|i| entries[*i].eliminated.is_none())
+                .filter(|i| entries[*i].runs.len() <= block)
+                .collect();
+
+            let evaluate = &self.evaluate;
+            let instances = self.instances;
+            let costs: Vec<f64> = pending
+                .par_iter()
+                .map(|i| {
+                    evaluate(
+                        &entries[*i].configuration,
+                        &instances[instance],
+                        seed,
+                    )
+                })
+                .collect();
+
+            for (i, cost) in pending.into_iter().zip(costs) {
+                entries[i].runs.push(Run { instance, repetition, seed, cost });
+            }
+
+            if let Some(race) = race {
+                if block + 1 >= race.first_test {
+                    eliminate(&mut entries, block + 1, race.alpha);
+                }
+            }
+        }
+
+        statistics(&mut entries);
+        entries
+    }
+
+    fn ranking(&self, mut entries: Vec<Entry>) -> Ranking {
+        sort(&mut entries);
+        Ranking { entries, instances: self.instances.len() }
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Eliminates the alive entries which are significantly worse than the best
+/// alive entry after the given number of blocks.
+fn eliminate(entries: &mut [Entry], blocks: usize, alpha: f64) {
+    let alive: Vec<usize> = (0..entries.len())
+        .filter(|i| entries[*i].eliminated.is_none())
+        .collect();
+    if alive.len() < 2 {
+        return;
+    }
+
+    let costs: Vec<Vec<f64>> = (0..blocks)
+        .map(|b| alive.iter().map(|i| entries[*i].runs[b].cost).collect())
+        .collect();
+    if !friedman(&costs).is_significant(alpha) {
+        return;
+    }
+
+    let mut rank_sums = vec![0.; alive.len()];
+    for block in &costs {
+        for (sum, rank) in rank_sums.iter_mut().zip(ranks(block)) {
+            *sum += rank;
+        }
+    }
+    let best = (0..alive.len())
+        .min_by(|a, b| rank_sums[*a].total_cmp(&rank_sums[*b]))
+        .expect("alive entries");
+
+    let column =
+        |j: usize| -> Vec<f64> { costs.iter().map(|block| block[j]).collect() };
+    let best_costs = column(best);
+    for j in 0..alive.len() {
+        if j == best || rank_sums[j] <= rank_sums[best] {
+            continue;
+        }
+
+        if wilcoxon_signed_rank(&column(j), &best_costs).is_significant(alpha) {
+            entries[alive[j]].eliminated = Some(blocks);
+        }
+    }
+}
+
+/// Calculates the mean costs and mean ranks of the entries.
+fn statistics(entries: &mut [Entry]) {
+    let blocks = entries.iter().map(|e| e.runs.len()).max().unwrap_or(0);
+
+    let mut rank_sums = vec![0.; entries.len()];
+    for block in 0..blocks {
+        let evaluated: Vec<usize> = (0..entries.len())
+            .filter(|i| entries[*i].runs.len() > block)
+            .collect();
+        let costs: Vec<f64> =
+            evaluated.iter().map(|i| entries[*i].runs[block].cost).collect();
+
+        for (i, rank) in evaluated.into_iter().zip(ranks(&costs)) {
+            rank_sums[i] += rank;
+        }
+    }
+
+    for (entry, rank_sum) in entries.iter_mut().zip(rank_sums) {
+        let n = entry.runs.len().max(1) as f64;
+        entry.mean_cost = entry.runs.iter().map(|r| r.cost).sum::<f64>() / n;
+        entry.mean_rank = rank_sum / n;
+    }
+}
+
+/// Sorts the entries (see [`Ranking`]).
+fn sort(entries: &mut [Entry]) {
+    entries.sort_by(|a, b| {
+        a.eliminated
+            .is_some()
+            .cmp(&b.eliminated.is_some())
+            .then(b.runs.len().cmp(&a.runs.len()))
+            .then(a.mean_rank.total_cmp(&b.mean_rank))
+            .then(a.mean_cost.total_cmp(&b.mean_cost))
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
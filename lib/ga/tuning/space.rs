@@ -0,0 +1,346 @@
+// Imports /////////////////////////////////////////////////////////////////////
+use crate::rng::GaRng;
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+
+// Domain //////////////////////////////////////////////////////////////////////
+
+/// Domain of a tuned parameter.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Domain {
+    /// One of the given labels (e.g. the selection method).
+    Categorical(Vec<String>),
+
+    /// Integer in `min..=max` (e.g. the population size).
+    Integer { min: i64, max: i64 },
+
+    /// Real number in `min..=max` (e.g. the mutation rate). A logarithmic
+    /// domain is sampled and discretized on a logarithmic scale (`min` has to
+    /// be positive).
+    Real { min: f64, max: f64, log: bool },
+}
+
+impl Domain {
+    /// Returns `levels` evenly spaced values of the domain (all labels of a
+    /// categorical domain).
+    fn levels(&self, levels: usize) -> Vec<Value> {
+        let positions: Vec<f64> = match levels {
+            0 | 1 => vec![0.],
+            _ => (0..levels).map(|i| i as f64 / (levels - 1) as f64).collect(),
+        };
+
+        match self {
+            Self::Categorical(labels) => labels
+                .iter()
+                .enumerate()
+                .map(|(index, label)| Value::Categorical {
+                    index,
+                    label: label.clone(),
+                })
+                .collect(),
+            Self::Integer { min, max } => {
+                let mut values: Vec<i64> = positions
+                    .iter()
+                    .map(|t| *min + (t * (*max - *min) as f64).round() as i64)
+                    .collect();
+                values.dedup();
+                values.into_iter().map(Value::Integer).collect()
+            }
+            Self::Real { .. } => positions
+                .iter()
+                .map(|t| Value::Real(self.at_position(*t)))
+                .collect(),
+        }
+    }
+
+    /// Draws a uniformly distributed value.
+    fn sample(&self, rng: &mut GaRng) -> Value {
+        match self {
+            Self::Categorical(labels) => {
+                let index = rng.gen_range(0..labels.len());
+                Value::Categorical { index, label: labels[index].clone() }
+            }
+            Self::Integer { min, max } => {
+                Value::Integer(rng.gen_range(*min..=*max))
+            }
+            Self::Real { .. } => {
+                Value::Real(self.at_position(rng.gen::<f64>()))
+            }
+        }
+    }
+
+    /// Draws a value in the neighbourhood of `value`. Numeric values are
+    /// normally distributed around `value` with a standard deviation of
+    /// `spread` times the width of the domain, categorical values are
+    /// re-drawn with the probability `spread`.
+    fn sample_near(
+        &self,
+        value: &Value,
+        spread: f64,
+        rng: &mut GaRng,
+    ) -> Value {
+        match (self, value) {
+            (Self::Categorical(_), Value::Categorical { .. }) => {
+                if rng.gen_bool(spread.clamp(0., 1.)) {
+                    self.sample(rng)
+                } else {
+                    value.clone()
+                }
+            }
+            (Self::Integer { min, max }, Value::Integer(x)) => {
+                let sd = spread * (*max - *min) as f64;
+                let x = *x as f64 + normal(sd, rng);
+                Value::Integer((x.round() as i64).clamp(*min, *max))
+            }
+            (Self::Real { .. }, Value::Real(x)) => {
+                let t = self.position(*x) + normal(spread, rng);
+                Value::Real(self.at_position(t.clamp(0., 1.)))
+            }
+            _ => panic!("value {value:?} does not belong to domain {self:?}"),
+        }
+    }
+
+    /// Maps a position in `0..=1` onto a real domain.
+    fn at_position(&self, t: f64) -> f64 {
+        match self {
+            Self::Real { min, max, log: false } => min + t * (max - min),
+            Self::Real { min, max, log: true } => {
+                (min.ln() + t * (max.ln() - min.ln())).exp()
+            }
+            _ => unreachable!("only real domains are mapped"),
+        }
+    }
+
+    /// Maps a value of a real domain onto `0..=1`.
+    fn position(&self, x: f64) -> f64 {
+        let t = match self {
+            Self::Real { min, max, log: false } => (x - min) / (max - min),
+            Self::Real { min, max, log: true } => {
+                (x.ln() - min.ln()) / (max.ln() - min.ln())
+            }
+            _ => unreachable!("only real domains are mapped"),
+        };
+
+        if t.is_finite() {
+            t
+        } else {
+            0.
+        }
+    }
+}
+
+// Value ///////////////////////////////////////////////////////////////////////
+
+/// Value of a tuned parameter.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    Categorical { index: usize, label: String },
+    Integer(i64),
+    Real(f64),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Categorical { label, .. } => write!(f, "{label}"),
+            Self::Integer(x) => write!(f, "{x}"),
+            Self::Real(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+// Space ///////////////////////////////////////////////////////////////////////
+
+/// Parameter space, which is declared parameter by parameter:
+/// ```rust ,ignore
+/// let space = Space::new()
+///     .add_integer("population_size", 100, 2000)
+///     .add_log_real("mutation_rate", 0.001, 0.1)
+///     .add_categorical("replace", ["elitist", "generational"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Space {
+    pub parameters: Vec<(String, Domain)>,
+}
+
+impl Space {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_categorical<S: Into<String>>(
+        mut self,
+        name: &str,
+        labels: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+        assert!(
+            !labels.is_empty(),
+            "parameter {name} needs at least one label"
+        );
+
+        self.parameters.push((name.into(), Domain::Categorical(labels)));
+        self
+    }
+
+    pub fn add_integer(mut self, name: &str, min: i64, max: i64) -> Self {
+        assert!(min <= max, "parameter {name} has an empty range");
+
+        self.parameters.push((name.into(), Domain::Integer { min, max }));
+        self
+    }
+
+    pub fn add_real(mut self, name: &str, min: f64, max: f64) -> Self {
+        assert!(min <= max, "parameter {name} has an empty range");
+
+        self.parameters
+            .push((name.into(), Domain::Real { min, max, log: false }));
+        self
+    }
+
+    pub fn add_log_real(mut self, name: &str, min: f64, max: f64) -> Self {
+        assert!(
+            0. < min && min <= max,
+            "parameter {name} has an invalid range"
+        );
+
+        self.parameters
+            .push((name.into(), Domain::Real { min, max, log: true }));
+        self
+    }
+
+    /// Returns all combinations of the parameter values. Numeric parameters
+    /// are discretized into `levels` evenly spaced values (including the
+    /// bounds), categorical parameters contribute all their labels.
+    pub fn grid(&self, levels: usize) -> Vec<Configuration> {
+        let mut configurations = vec![Configuration::default()];
+
+        for (name, domain) in &self.parameters {
+            let values = domain.levels(levels);
+
+            configurations = configurations
+                .into_iter()
+                .flat_map(|configuration| {
+                    values.iter().map(move |value| {
+                        let mut configuration = configuration.clone();
+                        configuration
+                            .values
+                            .push((name.clone(), value.clone()));
+                        configuration
+                    })
+                })
+                .collect();
+        }
+
+        configurations
+    }
+
+    /// Draws a uniformly distributed configuration.
+    pub fn sample(&self, rng: &mut GaRng) -> Configuration {
+        Configuration {
+            values: self
+                .parameters
+                .iter()
+                .map(|(name, domain)| (name.clone(), domain.sample(rng)))
+                .collect(),
+        }
+    }
+
+    /// Draws a configuration in the neighbourhood of `parent` (see the
+    /// iterated racing of the [`Tuner`](super::Tuner)). The `spread` (0 to 1)
+    /// controls the size of the neighbourhood.
+    pub fn sample_near(
+        &self,
+        parent: &Configuration,
+        spread: f64,
+        rng: &mut GaRng,
+    ) -> Configuration {
+        Configuration {
+            values: self
+                .parameters
+                .iter()
+                .zip(&parent.values)
+                .map(|((name, domain), (_, value))| {
+                    (name.clone(), domain.sample_near(value, spread, rng))
+                })
+                .collect(),
+        }
+    }
+}
+
+// Configuration ///////////////////////////////////////////////////////////////
+
+/// One point of the parameter space (the values are in the order in which the
+/// parameters were declared).
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Configuration {
+    pub values: Vec<(String, Value)>,
+}
+
+impl Configuration {
+    pub fn get(&self, name: &str) -> &Value {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value)
+            .unwrap_or_else(|| panic!("unknown parameter {name}"))
+    }
+
+    /// Index of the label of a categorical parameter.
+    pub fn index(&self, name: &str) -> usize {
+        match self.get(name) {
+            Value::Categorical { index, .. } => *index,
+            value => panic!("parameter {name} is not categorical ({value:?})"),
+        }
+    }
+
+    /// Label of a categorical parameter.
+    pub fn label(&self, name: &str) -> &str {
+        match self.get(name) {
+            Value::Categorical { label, .. } => label,
+            value => panic!("parameter {name} is not categorical ({value:?})"),
+        }
+    }
+
+    pub fn integer(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Value::Integer(x) => *x,
+            value => panic!("parameter {name} is not an integer ({value:?})"),
+        }
+    }
+
+    pub fn real(&self, name: &str) -> f64 {
+        match self.get(name) {
+            Value::Real(x) => *x,
+            value => {
+                panic!("parameter {name} is not a real number ({value:?})")
+            }
+        }
+    }
+
+    /// Returns an identifier of the form `name=value,name=value,...`.
+    pub fn identifier(&self) -> String {
+        self.values
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+// Functions ///////////////////////////////////////////////////////////////////
+
+/// Draws a normally distributed number with mean 0.
+fn normal(sd: f64, rng: &mut GaRng) -> f64 {
+    if sd > 0. {
+        Normal::new(0., sd).expect("valid normal distribution").sample(rng)
+    } else {
+        0.
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////